@@ -1,6 +1,10 @@
+import builtins
 import sys
+from decimal import Decimal as PyDecimal
+from fractions import Fraction
 from types import GenericAlias
-from typing import _S, _T, Callable, Iterable, Iterator, MutableSequence, Optional, Any, Self, SupportsIndex, overload
+from typing_extensions import Buffer
+from typing import _S, _T, Callable, Iterable, Iterator, Literal, MutableSequence, Optional, Any, Self, SupportsIndex, overload
 
 class Vector:
     pass
@@ -15,8 +19,341 @@ class i8:
     def value(self, new_value: int) -> None: ...
 
 
+class i256:
+    def __init__(self, value: builtins.int | str | None = None) -> None: ...
+    @staticmethod
+    def wrapping_from(value: builtins.int) -> i256: ...
+    @property
+    def value(self) -> builtins.int: ...
+    def to_hex(self) -> str: ...
+    @staticmethod
+    def from_hex(s: str) -> i256: ...
+    def __format__(self, spec: str) -> str: ...
+    def __hash__(self) -> builtins.int: ...
+    def __bool__(self) -> bool: ...
+    def __int__(self) -> builtins.int: ...
+    def __index__(self) -> builtins.int: ...
+    def __float__(self) -> builtins.float: ...
+    def __eq__(self, other: object, /) -> bool: ...
+    def __ne__(self, other: object, /) -> bool: ...
+    def __lt__(self, other: object, /) -> bool: ...
+    def __le__(self, other: object, /) -> bool: ...
+    def __gt__(self, other: object, /) -> bool: ...
+    def __ge__(self, other: object, /) -> bool: ...
+    def __add__(self, other: i256 | builtins.int, /) -> i256: ...
+    def __radd__(self, other: i256 | builtins.int, /) -> i256: ...
+    def checked_add(self, other: i256 | builtins.int) -> i256: ...
+    def wrapping_add(self, other: i256 | builtins.int) -> i256: ...
+    def overflowing_add(self, other: i256 | builtins.int) -> tuple[i256, bool]: ...
+    def saturating_add(self, other: i256 | builtins.int) -> i256: ...
+    def __sub__(self, other: i256 | builtins.int, /) -> i256: ...
+    def __rsub__(self, other: i256 | builtins.int, /) -> i256: ...
+    def checked_sub(self, other: i256 | builtins.int) -> i256: ...
+    def wrapping_sub(self, other: i256 | builtins.int) -> i256: ...
+    def overflowing_sub(self, other: i256 | builtins.int) -> tuple[i256, bool]: ...
+    def saturating_sub(self, other: i256 | builtins.int) -> i256: ...
+    def __mul__(self, other: i256 | builtins.int, /) -> i256: ...
+    def __rmul__(self, other: i256 | builtins.int, /) -> i256: ...
+    def checked_mul(self, other: i256 | builtins.int) -> i256: ...
+    def wrapping_mul(self, other: i256 | builtins.int) -> i256: ...
+    def overflowing_mul(self, other: i256 | builtins.int) -> tuple[i256, bool]: ...
+    def saturating_mul(self, other: i256 | builtins.int) -> i256: ...
+    def __pow__(self, exponent: builtins.int, modulo: None = None, /) -> i256: ...
+    def __rpow__(self, other: i256 | builtins.int, modulo: None = None, /) -> i256: ...
+    def checked_pow(self, exponent: builtins.int) -> i256: ...
+    def wrapping_pow(self, exponent: builtins.int) -> i256: ...
+    def overflowing_pow(self, exponent: builtins.int) -> tuple[i256, bool]: ...
+    def saturating_pow(self, exponent: builtins.int) -> i256: ...
+    def __truediv__(self, other: i256 | builtins.int, /) -> builtins.float: ...
+    def __rtruediv__(self, other: i256 | builtins.int, /) -> builtins.float: ...
+    def __floordiv__(self, other: i256 | builtins.int, /) -> i256: ...
+    def __rfloordiv__(self, other: i256 | builtins.int, /) -> i256: ...
+    def checked_floordiv(self, other: i256 | builtins.int) -> i256: ...
+    def wrapping_floordiv(self, other: i256 | builtins.int) -> i256: ...
+    def overflowing_floordiv(self, other: i256 | builtins.int) -> tuple[i256, bool]: ...
+    def __mod__(self, other: i256 | builtins.int, /) -> i256: ...
+    def __rmod__(self, other: i256 | builtins.int, /) -> i256: ...
+    def __divmod__(self, other: i256 | builtins.int, /) -> tuple[i256, i256]: ...
+    def __rdivmod__(self, other: i256 | builtins.int, /) -> tuple[i256, i256]: ...
+    def checked_rem(self, other: i256 | builtins.int) -> i256: ...
+    def wrapping_rem(self, other: i256 | builtins.int) -> i256: ...
+    def overflowing_rem(self, other: i256 | builtins.int) -> tuple[i256, bool]: ...
+    def __neg__(self) -> i256: ...
+    def checked_neg(self) -> i256: ...
+    def wrapping_neg(self) -> i256: ...
+    def overflowing_neg(self) -> tuple[i256, bool]: ...
+    def __pos__(self) -> i256: ...
+    def __abs__(self) -> i256: ...
+    def __invert__(self) -> i256: ...
+    def __and__(self, other: i256 | builtins.int, /) -> i256: ...
+    def __rand__(self, other: i256 | builtins.int, /) -> i256: ...
+    def __or__(self, other: i256 | builtins.int, /) -> i256: ...
+    def __ror__(self, other: i256 | builtins.int, /) -> i256: ...
+    def __xor__(self, other: i256 | builtins.int, /) -> i256: ...
+    def __rxor__(self, other: i256 | builtins.int, /) -> i256: ...
+    def __lshift__(self, other: i256 | builtins.int, /) -> i256: ...
+    def __rlshift__(self, other: i256 | builtins.int, /) -> i256: ...
+    def checked_shl(self, shift: builtins.int) -> i256: ...
+    def wrapping_shl(self, shift: builtins.int) -> i256: ...
+    def overflowing_shl(self, shift: builtins.int) -> tuple[i256, bool]: ...
+    def __rshift__(self, other: i256 | builtins.int, /) -> i256: ...
+    def __rrshift__(self, other: i256 | builtins.int, /) -> i256: ...
+    def checked_shr(self, shift: builtins.int) -> i256: ...
+    def wrapping_shr(self, shift: builtins.int) -> i256: ...
+    def overflowing_shr(self, shift: builtins.int) -> tuple[i256, bool]: ...
+    def count_ones(self) -> builtins.int: ...
+    def count_zeros(self) -> builtins.int: ...
+    def leading_zeros(self) -> builtins.int: ...
+    def trailing_zeros(self) -> builtins.int: ...
+    def bit_length(self) -> builtins.int: ...
+    def rotate_left(self, n: builtins.int) -> i256: ...
+    def rotate_right(self, n: builtins.int) -> i256: ...
+    def swap_bytes(self) -> i256: ...
+    def to_be_bytes(self) -> bytes: ...
+    def to_le_bytes(self) -> bytes: ...
+    @staticmethod
+    def from_be_bytes(bytes: bytes) -> i256: ...
+    @staticmethod
+    def from_le_bytes(bytes: bytes) -> i256: ...
+    def to_bytes(self, byteorder: Literal["little", "big"] = "big") -> bytes: ...
+    @staticmethod
+    def from_bytes(bytes: bytes, byteorder: Literal["little", "big"] = "big") -> i256: ...
+    @staticmethod
+    def zero() -> i256: ...
+    @staticmethod
+    def one() -> i256: ...
+    @staticmethod
+    def min_value() -> i256: ...
+    @staticmethod
+    def max_value() -> i256: ...
+    def is_positive(self) -> bool: ...
+    def is_negative(self) -> bool: ...
+
+
+class u256:
+    def __init__(self, value: builtins.int | str | None = None) -> None: ...
+    @staticmethod
+    def wrapping_from(value: builtins.int) -> u256: ...
+    @property
+    def value(self) -> builtins.int: ...
+    def to_hex(self) -> str: ...
+    @staticmethod
+    def from_hex(s: str) -> u256: ...
+    def __format__(self, spec: str) -> str: ...
+    def __hash__(self) -> builtins.int: ...
+    def __bool__(self) -> bool: ...
+    def __int__(self) -> builtins.int: ...
+    def __index__(self) -> builtins.int: ...
+    def __float__(self) -> builtins.float: ...
+    def __eq__(self, other: object, /) -> bool: ...
+    def __ne__(self, other: object, /) -> bool: ...
+    def __lt__(self, other: object, /) -> bool: ...
+    def __le__(self, other: object, /) -> bool: ...
+    def __gt__(self, other: object, /) -> bool: ...
+    def __ge__(self, other: object, /) -> bool: ...
+    def __add__(self, other: u256 | builtins.int, /) -> u256: ...
+    def __radd__(self, other: u256 | builtins.int, /) -> u256: ...
+    def checked_add(self, other: u256 | builtins.int) -> u256: ...
+    def wrapping_add(self, other: u256 | builtins.int) -> u256: ...
+    def overflowing_add(self, other: u256 | builtins.int) -> tuple[u256, bool]: ...
+    def saturating_add(self, other: u256 | builtins.int) -> u256: ...
+    def __sub__(self, other: u256 | builtins.int, /) -> u256: ...
+    def __rsub__(self, other: u256 | builtins.int, /) -> u256: ...
+    def checked_sub(self, other: u256 | builtins.int) -> u256: ...
+    def wrapping_sub(self, other: u256 | builtins.int) -> u256: ...
+    def overflowing_sub(self, other: u256 | builtins.int) -> tuple[u256, bool]: ...
+    def saturating_sub(self, other: u256 | builtins.int) -> u256: ...
+    def __mul__(self, other: u256 | builtins.int, /) -> u256: ...
+    def __rmul__(self, other: u256 | builtins.int, /) -> u256: ...
+    def checked_mul(self, other: u256 | builtins.int) -> u256: ...
+    def wrapping_mul(self, other: u256 | builtins.int) -> u256: ...
+    def overflowing_mul(self, other: u256 | builtins.int) -> tuple[u256, bool]: ...
+    def saturating_mul(self, other: u256 | builtins.int) -> u256: ...
+    def __pow__(self, exponent: builtins.int, modulo: None = None, /) -> u256: ...
+    def __rpow__(self, other: u256 | builtins.int, modulo: None = None, /) -> u256: ...
+    def checked_pow(self, exponent: builtins.int) -> u256: ...
+    def wrapping_pow(self, exponent: builtins.int) -> u256: ...
+    def overflowing_pow(self, exponent: builtins.int) -> tuple[u256, bool]: ...
+    def saturating_pow(self, exponent: builtins.int) -> u256: ...
+    def __truediv__(self, other: u256 | builtins.int, /) -> builtins.float: ...
+    def __rtruediv__(self, other: u256 | builtins.int, /) -> builtins.float: ...
+    def __floordiv__(self, other: u256 | builtins.int, /) -> u256: ...
+    def __rfloordiv__(self, other: u256 | builtins.int, /) -> u256: ...
+    def checked_floordiv(self, other: u256 | builtins.int) -> u256: ...
+    def wrapping_floordiv(self, other: u256 | builtins.int) -> u256: ...
+    def overflowing_floordiv(self, other: u256 | builtins.int) -> tuple[u256, bool]: ...
+    def __mod__(self, other: u256 | builtins.int, /) -> u256: ...
+    def __rmod__(self, other: u256 | builtins.int, /) -> u256: ...
+    def __divmod__(self, other: u256 | builtins.int, /) -> tuple[u256, u256]: ...
+    def __rdivmod__(self, other: u256 | builtins.int, /) -> tuple[u256, u256]: ...
+    def checked_rem(self, other: u256 | builtins.int) -> u256: ...
+    def wrapping_rem(self, other: u256 | builtins.int) -> u256: ...
+    def overflowing_rem(self, other: u256 | builtins.int) -> tuple[u256, bool]: ...
+    def __neg__(self) -> u256: ...
+    def checked_neg(self) -> u256: ...
+    def wrapping_neg(self) -> u256: ...
+    def overflowing_neg(self) -> tuple[u256, bool]: ...
+    def __pos__(self) -> u256: ...
+    def __abs__(self) -> u256: ...
+    def __invert__(self) -> u256: ...
+    def __and__(self, other: u256 | builtins.int, /) -> u256: ...
+    def __rand__(self, other: u256 | builtins.int, /) -> u256: ...
+    def __or__(self, other: u256 | builtins.int, /) -> u256: ...
+    def __ror__(self, other: u256 | builtins.int, /) -> u256: ...
+    def __xor__(self, other: u256 | builtins.int, /) -> u256: ...
+    def __rxor__(self, other: u256 | builtins.int, /) -> u256: ...
+    def __lshift__(self, other: u256 | builtins.int, /) -> u256: ...
+    def __rlshift__(self, other: u256 | builtins.int, /) -> u256: ...
+    def checked_shl(self, shift: builtins.int) -> u256: ...
+    def wrapping_shl(self, shift: builtins.int) -> u256: ...
+    def overflowing_shl(self, shift: builtins.int) -> tuple[u256, bool]: ...
+    def __rshift__(self, other: u256 | builtins.int, /) -> u256: ...
+    def __rrshift__(self, other: u256 | builtins.int, /) -> u256: ...
+    def checked_shr(self, shift: builtins.int) -> u256: ...
+    def wrapping_shr(self, shift: builtins.int) -> u256: ...
+    def overflowing_shr(self, shift: builtins.int) -> tuple[u256, bool]: ...
+    def count_ones(self) -> builtins.int: ...
+    def count_zeros(self) -> builtins.int: ...
+    def leading_zeros(self) -> builtins.int: ...
+    def trailing_zeros(self) -> builtins.int: ...
+    def bit_length(self) -> builtins.int: ...
+    def rotate_left(self, n: builtins.int) -> u256: ...
+    def rotate_right(self, n: builtins.int) -> u256: ...
+    def swap_bytes(self) -> u256: ...
+    def to_be_bytes(self) -> bytes: ...
+    def to_le_bytes(self) -> bytes: ...
+    @staticmethod
+    def from_be_bytes(bytes: bytes) -> u256: ...
+    @staticmethod
+    def from_le_bytes(bytes: bytes) -> u256: ...
+    def to_bytes(self, byteorder: Literal["little", "big"] = "big") -> bytes: ...
+    @staticmethod
+    def from_bytes(bytes: bytes, byteorder: Literal["little", "big"] = "big") -> u256: ...
+    @staticmethod
+    def zero() -> u256: ...
+    @staticmethod
+    def one() -> u256: ...
+    @staticmethod
+    def min_value() -> u256: ...
+    @staticmethod
+    def max_value() -> u256: ...
+    def is_positive(self) -> bool: ...
+    def is_negative(self) -> bool: ...
+
+
+class u512:
+    def __init__(self, value: builtins.int | str | None = None) -> None: ...
+    @staticmethod
+    def wrapping_from(value: builtins.int) -> u512: ...
+    @property
+    def value(self) -> builtins.int: ...
+    def to_hex(self) -> str: ...
+    @staticmethod
+    def from_hex(s: str) -> u512: ...
+    def __format__(self, spec: str) -> str: ...
+    def __hash__(self) -> builtins.int: ...
+    def __bool__(self) -> bool: ...
+    def __int__(self) -> builtins.int: ...
+    def __index__(self) -> builtins.int: ...
+    def __float__(self) -> builtins.float: ...
+    def __eq__(self, other: object, /) -> bool: ...
+    def __ne__(self, other: object, /) -> bool: ...
+    def __lt__(self, other: object, /) -> bool: ...
+    def __le__(self, other: object, /) -> bool: ...
+    def __gt__(self, other: object, /) -> bool: ...
+    def __ge__(self, other: object, /) -> bool: ...
+    def __add__(self, other: u512 | builtins.int, /) -> u512: ...
+    def __radd__(self, other: u512 | builtins.int, /) -> u512: ...
+    def checked_add(self, other: u512 | builtins.int) -> u512: ...
+    def wrapping_add(self, other: u512 | builtins.int) -> u512: ...
+    def overflowing_add(self, other: u512 | builtins.int) -> tuple[u512, bool]: ...
+    def saturating_add(self, other: u512 | builtins.int) -> u512: ...
+    def __sub__(self, other: u512 | builtins.int, /) -> u512: ...
+    def __rsub__(self, other: u512 | builtins.int, /) -> u512: ...
+    def checked_sub(self, other: u512 | builtins.int) -> u512: ...
+    def wrapping_sub(self, other: u512 | builtins.int) -> u512: ...
+    def overflowing_sub(self, other: u512 | builtins.int) -> tuple[u512, bool]: ...
+    def saturating_sub(self, other: u512 | builtins.int) -> u512: ...
+    def __mul__(self, other: u512 | builtins.int, /) -> u512: ...
+    def __rmul__(self, other: u512 | builtins.int, /) -> u512: ...
+    def checked_mul(self, other: u512 | builtins.int) -> u512: ...
+    def wrapping_mul(self, other: u512 | builtins.int) -> u512: ...
+    def overflowing_mul(self, other: u512 | builtins.int) -> tuple[u512, bool]: ...
+    def saturating_mul(self, other: u512 | builtins.int) -> u512: ...
+    def __pow__(self, exponent: builtins.int, modulo: None = None, /) -> u512: ...
+    def __rpow__(self, other: u512 | builtins.int, modulo: None = None, /) -> u512: ...
+    def checked_pow(self, exponent: builtins.int) -> u512: ...
+    def wrapping_pow(self, exponent: builtins.int) -> u512: ...
+    def overflowing_pow(self, exponent: builtins.int) -> tuple[u512, bool]: ...
+    def saturating_pow(self, exponent: builtins.int) -> u512: ...
+    def __truediv__(self, other: u512 | builtins.int, /) -> builtins.float: ...
+    def __rtruediv__(self, other: u512 | builtins.int, /) -> builtins.float: ...
+    def __floordiv__(self, other: u512 | builtins.int, /) -> u512: ...
+    def __rfloordiv__(self, other: u512 | builtins.int, /) -> u512: ...
+    def checked_floordiv(self, other: u512 | builtins.int) -> u512: ...
+    def wrapping_floordiv(self, other: u512 | builtins.int) -> u512: ...
+    def overflowing_floordiv(self, other: u512 | builtins.int) -> tuple[u512, bool]: ...
+    def __mod__(self, other: u512 | builtins.int, /) -> u512: ...
+    def __rmod__(self, other: u512 | builtins.int, /) -> u512: ...
+    def __divmod__(self, other: u512 | builtins.int, /) -> tuple[u512, u512]: ...
+    def __rdivmod__(self, other: u512 | builtins.int, /) -> tuple[u512, u512]: ...
+    def checked_rem(self, other: u512 | builtins.int) -> u512: ...
+    def wrapping_rem(self, other: u512 | builtins.int) -> u512: ...
+    def overflowing_rem(self, other: u512 | builtins.int) -> tuple[u512, bool]: ...
+    def __neg__(self) -> u512: ...
+    def checked_neg(self) -> u512: ...
+    def wrapping_neg(self) -> u512: ...
+    def overflowing_neg(self) -> tuple[u512, bool]: ...
+    def __pos__(self) -> u512: ...
+    def __abs__(self) -> u512: ...
+    def __invert__(self) -> u512: ...
+    def __and__(self, other: u512 | builtins.int, /) -> u512: ...
+    def __rand__(self, other: u512 | builtins.int, /) -> u512: ...
+    def __or__(self, other: u512 | builtins.int, /) -> u512: ...
+    def __ror__(self, other: u512 | builtins.int, /) -> u512: ...
+    def __xor__(self, other: u512 | builtins.int, /) -> u512: ...
+    def __rxor__(self, other: u512 | builtins.int, /) -> u512: ...
+    def __lshift__(self, other: u512 | builtins.int, /) -> u512: ...
+    def __rlshift__(self, other: u512 | builtins.int, /) -> u512: ...
+    def checked_shl(self, shift: builtins.int) -> u512: ...
+    def wrapping_shl(self, shift: builtins.int) -> u512: ...
+    def overflowing_shl(self, shift: builtins.int) -> tuple[u512, bool]: ...
+    def __rshift__(self, other: u512 | builtins.int, /) -> u512: ...
+    def __rrshift__(self, other: u512 | builtins.int, /) -> u512: ...
+    def checked_shr(self, shift: builtins.int) -> u512: ...
+    def wrapping_shr(self, shift: builtins.int) -> u512: ...
+    def overflowing_shr(self, shift: builtins.int) -> tuple[u512, bool]: ...
+    def count_ones(self) -> builtins.int: ...
+    def count_zeros(self) -> builtins.int: ...
+    def leading_zeros(self) -> builtins.int: ...
+    def trailing_zeros(self) -> builtins.int: ...
+    def bit_length(self) -> builtins.int: ...
+    def rotate_left(self, n: builtins.int) -> u512: ...
+    def rotate_right(self, n: builtins.int) -> u512: ...
+    def swap_bytes(self) -> u512: ...
+    def to_be_bytes(self) -> bytes: ...
+    def to_le_bytes(self) -> bytes: ...
+    @staticmethod
+    def from_be_bytes(bytes: bytes) -> u512: ...
+    @staticmethod
+    def from_le_bytes(bytes: bytes) -> u512: ...
+    def to_bytes(self, byteorder: Literal["little", "big"] = "big") -> bytes: ...
+    @staticmethod
+    def from_bytes(bytes: bytes, byteorder: Literal["little", "big"] = "big") -> u512: ...
+    @staticmethod
+    def zero() -> u512: ...
+    @staticmethod
+    def one() -> u512: ...
+    @staticmethod
+    def min_value() -> u512: ...
+    @staticmethod
+    def max_value() -> u512: ...
+    def is_positive(self) -> bool: ...
+    def is_negative(self) -> bool: ...
+
+
 class int:
-    def __init__(self, value: int) -> None: ...
+    def __init__(self, value: object = 0, base: builtins.int | None = None) -> None: ...
     def __eq__(self, other: object, /) -> bool: ...
     def __ne__(self, other: object, /) -> bool: ...
     def __lt__(self, other: object, /) -> bool: ...
@@ -30,6 +367,25 @@ class int:
     def __floordiv__(self, other: object, /) -> num: ...
     def __mod__(self, other: object, /) -> num: ...
     def __pow__(self, other: object, /) -> num: ...
+    def __radd__(self, other: object, /) -> int: ...
+    def __rsub__(self, other: object, /) -> int: ...
+    def __rmul__(self, other: object, /) -> int: ...
+    def __rtruediv__(self, other: object, /) -> builtins.float: ...
+    def __rfloordiv__(self, other: object, /) -> int: ...
+    def __rmod__(self, other: object, /) -> int: ...
+    def __divmod__(self, other: object, /) -> tuple[int, int]: ...
+    def __rdivmod__(self, other: object, /) -> tuple[int, int]: ...
+    def __rpow__(self, other: object, modulo: object = None, /) -> int: ...
+    def __and__(self, other: object, /) -> int: ...
+    def __rand__(self, other: object, /) -> int: ...
+    def __or__(self, other: object, /) -> int: ...
+    def __ror__(self, other: object, /) -> int: ...
+    def __xor__(self, other: object, /) -> int: ...
+    def __rxor__(self, other: object, /) -> int: ...
+    def __lshift__(self, other: object, /) -> int: ...
+    def __rlshift__(self, other: object, /) -> int: ...
+    def __rshift__(self, other: object, /) -> int: ...
+    def __rrshift__(self, other: object, /) -> int: ...
     def __neg__(self) -> num: ...
     def __pos__(self) -> num: ...
     def __abs__(self) -> num: ...
@@ -39,8 +395,391 @@ class int:
     def __repr__(self) -> str: ...
     def __hash__(self) -> int: ...
     def __index__(self) -> int: ...
+    def bit_length(self) -> builtins.int: ...
+    def gcd(self, other: object, /) -> int: ...
+    def lcm(self, other: object, /) -> int: ...
+    def gcd_ext(self, other: object, /) -> tuple[int, int, int]: ...
+    def pow_mod(self, exponent: object, modulo: object, /) -> int: ...
+    def invert(self, modulo: object, /) -> int: ...
+    def is_probably_prime(self, reps: builtins.int = 25) -> bool: ...
+    def next_prime(self) -> int: ...
+    def jacobi(self, n: object, /) -> builtins.int: ...
+    def legendre(self, p: object, /) -> builtins.int: ...
+    def kronecker(self, n: object, /) -> builtins.int: ...
+    def sqrt_rem(self) -> tuple[int, int]: ...
+    def root(self, n: builtins.int, /) -> int: ...
+    def is_perfect_square(self) -> bool: ...
+    def is_perfect_power(self) -> bool: ...
+    @staticmethod
+    def factorial(n: builtins.int, /) -> int: ...
+    @staticmethod
+    def binomial(n: object, k: builtins.int, /) -> int: ...
+    @staticmethod
+    def fibonacci(n: builtins.int, /) -> int: ...
+    @staticmethod
+    def lucas(n: builtins.int, /) -> int: ...
     
 
+class Round:
+    Nearest: Round
+    Zero: Round
+    Up: Round
+    Down: Round
+    Away: Round
+
+
+class float:
+    def __init__(self, value: object = None, prec: builtins.int = 53, round: Round = Round.Nearest) -> None: ...
+    @property
+    def prec(self) -> builtins.int: ...
+    @property
+    def round(self) -> Round: ...
+    @property
+    def real(self) -> float: ...
+    @property
+    def imag(self) -> float: ...
+    def conjugate(self) -> float: ...
+    def with_prec(self, prec: builtins.int, round: Round | None = None) -> float: ...
+    def to_string(self, digits: builtins.int | None = None) -> str: ...
+    def to_int(self, round: Round | None = None) -> int: ...
+    def as_integer_ratio(self) -> tuple[builtins.int, builtins.int]: ...
+    def is_nan(self) -> bool: ...
+    def is_infinite(self) -> bool: ...
+    def is_finite(self) -> bool: ...
+    def __eq__(self, other: object, /) -> bool: ...
+    def __ne__(self, other: object, /) -> bool: ...
+    def __lt__(self, other: object, /) -> bool: ...
+    def __le__(self, other: object, /) -> bool: ...
+    def __gt__(self, other: object, /) -> bool: ...
+    def __ge__(self, other: object, /) -> bool: ...
+    def __add__(self, other: object, /) -> float: ...
+    def __radd__(self, other: object, /) -> float: ...
+    def __sub__(self, other: object, /) -> float: ...
+    def __rsub__(self, other: object, /) -> float: ...
+    def __mul__(self, other: object, /) -> float: ...
+    def __rmul__(self, other: object, /) -> float: ...
+    def __truediv__(self, other: object, /) -> float: ...
+    def __rtruediv__(self, other: object, /) -> float: ...
+    def __pow__(self, other: object, modulo: None = None, /) -> float: ...
+    def __rpow__(self, other: object, modulo: None = None, /) -> float: ...
+    def __neg__(self) -> float: ...
+    def __pos__(self) -> float: ...
+    def __abs__(self) -> float: ...
+    def __bool__(self) -> bool: ...
+    def __int__(self) -> builtins.int: ...
+    def __trunc__(self) -> builtins.int: ...
+    def __floor__(self) -> builtins.int: ...
+    def __ceil__(self) -> builtins.int: ...
+    @overload
+    def __round__(self, ndigits: None = None) -> builtins.int: ...
+    @overload
+    def __round__(self, ndigits: builtins.int) -> float: ...
+    def __float__(self) -> builtins.float: ...
+    def sqrt(self) -> float: ...
+    def cbrt(self) -> float: ...
+    def root(self, k: builtins.int, /) -> float: ...
+    def exp(self) -> float: ...
+    def exp2(self) -> float: ...
+    def exp10(self) -> float: ...
+    def expm1(self) -> float: ...
+    def ln(self) -> float: ...
+    def log2(self) -> float: ...
+    def log10(self) -> float: ...
+    def log1p(self) -> float: ...
+    def sin(self) -> float: ...
+    def cos(self) -> float: ...
+    def tan(self) -> float: ...
+    def asin(self) -> float: ...
+    def acos(self) -> float: ...
+    def atan(self) -> float: ...
+    def atan2(self, x: object, /) -> float: ...
+    def sinh(self) -> float: ...
+    def cosh(self) -> float: ...
+    def tanh(self) -> float: ...
+    def asinh(self) -> float: ...
+    def acosh(self) -> float: ...
+    def atanh(self) -> float: ...
+    def hypot(self, other: object, /) -> float: ...
+    def gamma(self) -> float: ...
+    def lgamma(self) -> float: ...
+    def erf(self) -> float: ...
+    def erfc(self) -> float: ...
+    def floor(self) -> float: ...
+    def ceil(self) -> float: ...
+    def trunc(self) -> float: ...
+    def round_even(self) -> float: ...
+    @staticmethod
+    def pi(prec: builtins.int = 53, round: Round = Round.Nearest) -> float: ...
+    @staticmethod
+    def e(prec: builtins.int = 53, round: Round = Round.Nearest) -> float: ...
+    @staticmethod
+    def ln2(prec: builtins.int = 53, round: Round = Round.Nearest) -> float: ...
+    @staticmethod
+    def euler(prec: builtins.int = 53, round: Round = Round.Nearest) -> float: ...
+
+
+class Complex:
+    def __init__(self, real: object = None, imag: object = None, kind: Literal["f32", "f64", "mp"] | type | None = None, prec: builtins.int | None = None) -> None: ...
+    @staticmethod
+    def from_polar(r: object, theta: object, kind: Literal["f32", "f64", "mp"] | type | None = None, prec: builtins.int | None = None) -> Complex: ...
+    @property
+    def kind(self) -> Literal["f32", "f64", "mp"]: ...
+    @property
+    def prec(self) -> builtins.int: ...
+    def cast(self, kind: Literal["f32", "f64", "mp"] | type, prec: builtins.int | None = None) -> Complex: ...
+    @property
+    def real(self) -> builtins.float | float: ...
+    @property
+    def imag(self) -> builtins.float | float: ...
+    def to_string(self, digits: builtins.int | None = None) -> str: ...
+    def __format__(self, spec: str) -> str: ...
+    def __complex__(self) -> builtins.complex: ...
+    def __hash__(self) -> builtins.int: ...
+    def __bool__(self) -> bool: ...
+    def __eq__(self, other: object, /) -> bool: ...
+    def __ne__(self, other: object, /) -> bool: ...
+    def __add__(self, other: object, /) -> Complex: ...
+    def __radd__(self, other: object, /) -> Complex: ...
+    def __sub__(self, other: object, /) -> Complex: ...
+    def __rsub__(self, other: object, /) -> Complex: ...
+    def __mul__(self, other: object, /) -> Complex: ...
+    def __rmul__(self, other: object, /) -> Complex: ...
+    def __truediv__(self, other: object, /) -> Complex: ...
+    def __rtruediv__(self, other: object, /) -> Complex: ...
+    def __pow__(self, other: object, modulo: None = None, /) -> Complex: ...
+    def __rpow__(self, other: object, modulo: None = None, /) -> Complex: ...
+    def __neg__(self) -> Complex: ...
+    def __pos__(self) -> Complex: ...
+    def __abs__(self) -> builtins.float | float: ...
+    def arg(self) -> builtins.float | float: ...
+    def polar(self) -> tuple[builtins.float | float, builtins.float | float]: ...
+    def conj(self) -> Complex: ...
+    def exp(self) -> Complex: ...
+    def ln(self) -> Complex: ...
+    def log(self, base: object = None) -> Complex: ...
+    def log10(self) -> Complex: ...
+    def sqrt(self) -> Complex: ...
+
+
+class Rational:
+    def __init__(self, numerator: object = None, denominator: object = None) -> None: ...
+    @staticmethod
+    def from_float(value: builtins.float, /) -> Rational: ...
+    @property
+    def numer(self) -> int: ...
+    @property
+    def denom(self) -> int: ...
+    @property
+    def numerator(self) -> builtins.int: ...
+    @property
+    def denominator(self) -> builtins.int: ...
+    def as_integer_ratio(self) -> tuple[builtins.int, builtins.int]: ...
+    def to_fraction(self) -> Fraction: ...
+    def limit_denominator(self, max_denominator: builtins.int = 1000000) -> Rational: ...
+    def __hash__(self) -> builtins.int: ...
+    def __bool__(self) -> bool: ...
+    def __float__(self) -> builtins.float: ...
+    def __int__(self) -> builtins.int: ...
+    def __trunc__(self) -> builtins.int: ...
+    def __floor__(self) -> builtins.int: ...
+    def __ceil__(self) -> builtins.int: ...
+    @overload
+    def __round__(self, ndigits: None = None) -> builtins.int: ...
+    @overload
+    def __round__(self, ndigits: builtins.int) -> Rational: ...
+    def __eq__(self, other: object, /) -> bool: ...
+    def __ne__(self, other: object, /) -> bool: ...
+    def __lt__(self, other: object, /) -> bool: ...
+    def __le__(self, other: object, /) -> bool: ...
+    def __gt__(self, other: object, /) -> bool: ...
+    def __ge__(self, other: object, /) -> bool: ...
+    def __add__(self, other: object, /) -> Rational: ...
+    def __radd__(self, other: object, /) -> Rational: ...
+    def __sub__(self, other: object, /) -> Rational: ...
+    def __rsub__(self, other: object, /) -> Rational: ...
+    def __mul__(self, other: object, /) -> Rational: ...
+    def __rmul__(self, other: object, /) -> Rational: ...
+    def __truediv__(self, other: object, /) -> Rational: ...
+    def __rtruediv__(self, other: object, /) -> Rational: ...
+    def __floordiv__(self, other: object, /) -> Rational: ...
+    def __rfloordiv__(self, other: object, /) -> Rational: ...
+    def __mod__(self, other: object, /) -> Rational: ...
+    def __rmod__(self, other: object, /) -> Rational: ...
+    def __divmod__(self, other: object, /) -> tuple[Rational, Rational]: ...
+    def __pow__(self, other: object, /) -> Rational: ...
+    def __rpow__(self, other: object, /) -> Rational: ...
+    def __neg__(self) -> Rational: ...
+    def __pos__(self) -> Rational: ...
+    def __abs__(self) -> Rational: ...
+
+
+class Rounding:
+    HalfEven: Rounding
+    HalfUp: Rounding
+    HalfDown: Rounding
+    Down: Rounding
+    Up: Rounding
+    Ceiling: Rounding
+    Floor: Rounding
+
+
+class Decimal:
+    def __init__(self, value: object = None, scale: builtins.int | None = None, rounding: Rounding | str | None = None) -> None: ...
+    @staticmethod
+    def parse(s: str, separator: str = ",") -> Decimal: ...
+    @property
+    def mantissa(self) -> builtins.int: ...
+    @property
+    def scale(self) -> builtins.int: ...
+    def to_decimal(self) -> PyDecimal: ...
+    def quantize(self, scale: builtins.int | Decimal, rounding: Rounding | str | None = None) -> Decimal: ...
+    def mul(self, other: object, scale: builtins.int | None = None, rounding: Rounding | str | None = None) -> Decimal: ...
+    def div(self, other: object, scale: builtins.int | None = None, rounding: Rounding | str | None = None) -> Decimal: ...
+    def __format__(self, spec: str) -> str: ...
+    def __hash__(self) -> builtins.int: ...
+    def __bool__(self) -> bool: ...
+    def __float__(self) -> builtins.float: ...
+    def __int__(self) -> builtins.int: ...
+    @overload
+    def __round__(self, ndigits: None = None) -> builtins.int: ...
+    @overload
+    def __round__(self, ndigits: builtins.int) -> Decimal: ...
+    def __eq__(self, other: object, /) -> bool: ...
+    def __ne__(self, other: object, /) -> bool: ...
+    def __lt__(self, other: object, /) -> bool: ...
+    def __le__(self, other: object, /) -> bool: ...
+    def __gt__(self, other: object, /) -> bool: ...
+    def __ge__(self, other: object, /) -> bool: ...
+    def __add__(self, other: object, /) -> Decimal: ...
+    def __radd__(self, other: object, /) -> Decimal: ...
+    def __sub__(self, other: object, /) -> Decimal: ...
+    def __rsub__(self, other: object, /) -> Decimal: ...
+    def __mul__(self, other: object, /) -> Decimal: ...
+    def __rmul__(self, other: object, /) -> Decimal: ...
+    def __truediv__(self, other: object, /) -> Decimal: ...
+    def __rtruediv__(self, other: object, /) -> Decimal: ...
+    def __neg__(self) -> Decimal: ...
+    def __pos__(self) -> Decimal: ...
+    def __abs__(self) -> Decimal: ...
+
+
+class Fixed:
+    def __init__(self, value: object, int_bits: builtins.int, frac_bits: builtins.int, signed: bool = True, rounding: Rounding | str | None = None, saturate: bool = False) -> None: ...
+    @staticmethod
+    def from_bits(bits: builtins.int, int_bits: builtins.int, frac_bits: builtins.int, signed: bool = True) -> Fixed: ...
+    def to_bits(self) -> builtins.int: ...
+    def convert(self, int_bits: builtins.int, frac_bits: builtins.int, signed: bool = True, rounding: Rounding | str | None = None, saturate: bool = False) -> Fixed: ...
+    @property
+    def int_bits(self) -> builtins.int: ...
+    @property
+    def frac_bits(self) -> builtins.int: ...
+    @property
+    def signed(self) -> bool: ...
+    @property
+    def width(self) -> builtins.int: ...
+    def to_float(self) -> builtins.float: ...
+    def __float__(self) -> builtins.float: ...
+    def __int__(self) -> builtins.int: ...
+    def __hash__(self) -> builtins.int: ...
+    def __bool__(self) -> bool: ...
+    def __eq__(self, other: object, /) -> bool: ...
+    def __ne__(self, other: object, /) -> bool: ...
+    def __lt__(self, other: object, /) -> bool: ...
+    def __le__(self, other: object, /) -> bool: ...
+    def __gt__(self, other: object, /) -> bool: ...
+    def __ge__(self, other: object, /) -> bool: ...
+    def __add__(self, other: Fixed | builtins.int, /) -> Self: ...
+    def __radd__(self, other: builtins.int, /) -> Self: ...
+    def __sub__(self, other: Fixed | builtins.int, /) -> Self: ...
+    def __rsub__(self, other: builtins.int, /) -> Self: ...
+    def __mul__(self, other: Fixed | builtins.int, /) -> Self: ...
+    def __rmul__(self, other: builtins.int, /) -> Self: ...
+    def __truediv__(self, other: Fixed | builtins.int, /) -> Self: ...
+    def __rtruediv__(self, other: builtins.int, /) -> Self: ...
+    def checked_add(self, other: Fixed | builtins.int) -> Self | None: ...
+    def wrapping_add(self, other: Fixed | builtins.int) -> Self: ...
+    def saturating_add(self, other: Fixed | builtins.int) -> Self: ...
+    def overflowing_add(self, other: Fixed | builtins.int) -> tuple[Self, bool]: ...
+    def checked_sub(self, other: Fixed | builtins.int) -> Self | None: ...
+    def wrapping_sub(self, other: Fixed | builtins.int) -> Self: ...
+    def saturating_sub(self, other: Fixed | builtins.int) -> Self: ...
+    def overflowing_sub(self, other: Fixed | builtins.int) -> tuple[Self, bool]: ...
+    def mul(self, other: Fixed | builtins.int, rounding: Rounding | str | None = None) -> Self: ...
+    def checked_mul(self, other: Fixed | builtins.int, rounding: Rounding | str | None = None) -> Self | None: ...
+    def wrapping_mul(self, other: Fixed | builtins.int, rounding: Rounding | str | None = None) -> Self: ...
+    def saturating_mul(self, other: Fixed | builtins.int, rounding: Rounding | str | None = None) -> Self: ...
+    def overflowing_mul(self, other: Fixed | builtins.int, rounding: Rounding | str | None = None) -> tuple[Self, bool]: ...
+    def div(self, other: Fixed | builtins.int, rounding: Rounding | str | None = None) -> Self: ...
+    def checked_div(self, other: Fixed | builtins.int, rounding: Rounding | str | None = None) -> Self | None: ...
+    def wrapping_div(self, other: Fixed | builtins.int, rounding: Rounding | str | None = None) -> Self: ...
+    def saturating_div(self, other: Fixed | builtins.int, rounding: Rounding | str | None = None) -> Self: ...
+    def overflowing_div(self, other: Fixed | builtins.int, rounding: Rounding | str | None = None) -> tuple[Self, bool]: ...
+    def __neg__(self) -> Self: ...
+    def wrapping_neg(self) -> Self: ...
+    def saturating_neg(self) -> Self: ...
+    def __pos__(self) -> Self: ...
+    def __abs__(self) -> Self: ...
+    def saturating_abs(self) -> Self: ...
+
+
+class Q15(Fixed):
+    def __init__(self, value: object = None, rounding: Rounding | str | None = None, saturate: bool = False) -> None: ...
+    @staticmethod
+    def from_bits(bits: builtins.int) -> Q15: ...  # type: ignore[override]
+    @staticmethod
+    def min_value() -> Q15: ...
+    @staticmethod
+    def max_value() -> Q15: ...
+
+
+class Q31(Fixed):
+    def __init__(self, value: object = None, rounding: Rounding | str | None = None, saturate: bool = False) -> None: ...
+    @staticmethod
+    def from_bits(bits: builtins.int) -> Q31: ...  # type: ignore[override]
+    @staticmethod
+    def min_value() -> Q31: ...
+    @staticmethod
+    def max_value() -> Q31: ...
+
+
+class Q7_8(Fixed):
+    def __init__(self, value: object = None, rounding: Rounding | str | None = None, saturate: bool = False) -> None: ...
+    @staticmethod
+    def from_bits(bits: builtins.int) -> Q7_8: ...  # type: ignore[override]
+    @staticmethod
+    def min_value() -> Q7_8: ...
+    @staticmethod
+    def max_value() -> Q7_8: ...
+
+
+class ModInt:
+    def __init__(self, value: object, modulus: object) -> None: ...
+    @property
+    def value(self) -> builtins.int: ...
+    @property
+    def modulus(self) -> builtins.int: ...
+    def inverse(self) -> ModInt: ...
+    def to_int(self) -> int: ...
+    @staticmethod
+    def crt(*residues: ModInt) -> ModInt: ...
+    def __hash__(self) -> builtins.int: ...
+    def __bool__(self) -> bool: ...
+    def __int__(self) -> builtins.int: ...
+    def __eq__(self, other: object, /) -> bool: ...
+    def __ne__(self, other: object, /) -> bool: ...
+    def __add__(self, other: object, /) -> ModInt: ...
+    def __radd__(self, other: object, /) -> ModInt: ...
+    def __sub__(self, other: object, /) -> ModInt: ...
+    def __rsub__(self, other: object, /) -> ModInt: ...
+    def __mul__(self, other: object, /) -> ModInt: ...
+    def __rmul__(self, other: object, /) -> ModInt: ...
+    def __truediv__(self, other: object, /) -> ModInt: ...
+    def __rtruediv__(self, other: object, /) -> ModInt: ...
+    def __pow__(self, exponent: object, modulo: None = None, /) -> ModInt: ...
+    def __neg__(self) -> ModInt: ...
+    def __pos__(self) -> ModInt: ...
+
+
 class num:
     def __init__(self, value: int) -> None: ...
     def __eq__(self, other: object, /) -> bool: ...
@@ -65,6 +804,16 @@ class num:
     def __repr__(self) -> str: ...
     def __hash__(self) -> int: ...
     def __index__(self) -> int: ...
+    def __rpow__(self, other: object, modulo: object = None, /) -> num: ...
+    def __rdivmod__(self, other: object, /) -> tuple[num, num]: ...
+    def __lshift__(self, other: object, /) -> num: ...
+    def __rlshift__(self, other: object, /) -> num: ...
+    def __rshift__(self, other: object, /) -> num: ...
+    def __rrshift__(self, other: object, /) -> num: ...
+    @property
+    def is_small(self) -> bool: ...
+    def bit_length(self) -> builtins.int: ...
+    def to_int(self) -> int: ...
 
 class list(MutableSequence[_T]):
 
@@ -73,7 +822,15 @@ class list(MutableSequence[_T]):
 
     @overload
     def __init__(self, iterable: Iterable[_T], /) -> None: ...
-    
+
+    @overload
+    def __init__(self, *values: Any, _type: Any = None, coerce: bool = False) -> None: ...
+
+    @classmethod
+    def from_iter(cls, iterable: Iterable[_T], /, _type: Any = None, coerce: bool = False) -> Self: ...
+    @classmethod
+    def from_buffer(cls, buffer: Buffer, /, _type: Any = None) -> Self: ...
+    def __buffer__(self, flags: int, /) -> memoryview: ...
     def copy(self) -> Self[_T]: ...
     def append(self, object: _T, /) -> None: ...
     def extend(self, iterable: Iterable[_T], /) -> None: ...
@@ -83,6 +840,7 @@ class list(MutableSequence[_T]):
     def index(self, value: _T, start: SupportsIndex = 0, stop: SupportsIndex = sys.maxsize, /) -> int: ...
     def count(self, value: _T, /) -> int: ...
     def insert(self, index: SupportsIndex, object: _T, /) -> None: ...
+    def insert_many(self, index: SupportsIndex, iterable: Iterable[_T], /) -> None: ...
     def remove(self, value: _T, /) -> None: ...
     # Signature of `list.sort` should be kept inline with `collections.UserList.sort()`
     # and multiprocessing.managers.ListProxy.sort()
@@ -93,6 +851,83 @@ class list(MutableSequence[_T]):
     def sort(self: list[SupportsRichComparisonT], *, key: None = None, reverse: bool = False) -> None: ...
     @overload
     def sort(self, *, key: Callable[[_T], SupportsRichComparison], reverse: bool = False) -> None: ...
+    def sort_unstable(self, *, key: Callable[[_T], SupportsRichComparison] | None = None, reverse: bool = False) -> None: ...
+    def sort_by_key(self, key: Callable[[_T], SupportsRichComparison], *, reverse: bool = False) -> None: ...
+    def sort_by_cached_key(self, key: Callable[[_T], SupportsRichComparison], *, reverse: bool = False) -> None: ...
+    def sorted(self, *, key: Callable[[_T], SupportsRichComparison] | None = None, reverse: bool = False) -> Self: ...
+    def is_sorted(self, *, key: Callable[[_T], SupportsRichComparison] | None = None, reverse: bool = False) -> bool: ...
+    def par_sort(self, *, reverse: bool = False) -> None: ...
+    def par_sort_unstable(self, *, reverse: bool = False) -> None: ...
+    def par_sum(self) -> builtins.int | builtins.float: ...
+    def par_min(self) -> _T: ...
+    def par_max(self) -> _T: ...
+    def par_map(self, op: str, operand: Any = None) -> Self: ...
+    def par_filter(self, op: str, value: Any) -> Self: ...
+    def par_dedup(self) -> None: ...
+    # Element-wise arithmetic, for Lists of Rustique primitives. Operators
+    # keep their Python list meaning, so `+` concatenates, `*` repeats and the
+    # other arithmetic and bitwise operators are unsupported. `floordiv` and
+    # `rem` truncate for integers, like the primitives' `//` and `%`, and
+    # follow Python's `//` and `%` for floats.
+    def add(self, other: Any, /) -> Self: ...
+    def sub(self, other: Any, /) -> Self: ...
+    def mul(self, other: Any, /) -> Self: ...
+    def truediv(self, other: Any, /) -> list[Any]: ...
+    def floordiv(self, other: Any, /) -> Self: ...
+    def rem(self, other: Any, /) -> Self: ...
+    def bitand(self, other: Any, /) -> Self: ...
+    def bitor(self, other: Any, /) -> Self: ...
+    def bitxor(self, other: Any, /) -> Self: ...
+    def shl(self, other: Any, /) -> Self: ...
+    def shr(self, other: Any, /) -> Self: ...
+    def wrapping_add(self, other: Any, /) -> Self: ...
+    def wrapping_sub(self, other: Any, /) -> Self: ...
+    def wrapping_mul(self, other: Any, /) -> Self: ...
+    def wrapping_floordiv(self, other: Any, /) -> Self: ...
+    def wrapping_rem(self, other: Any, /) -> Self: ...
+    def wrapping_shl(self, other: Any, /) -> Self: ...
+    def wrapping_shr(self, other: Any, /) -> Self: ...
+    def saturating_add(self, other: Any, /) -> Self: ...
+    def saturating_sub(self, other: Any, /) -> Self: ...
+    def saturating_mul(self, other: Any, /) -> Self: ...
+    def mask(self, op: str, other: Any, /) -> list[bool]: ...
+    def sum(self, *, accumulator: type | None = None) -> Any: ...
+    def product(self, *, accumulator: type | None = None) -> Any: ...
+    def min(self) -> _T: ...
+    def max(self) -> _T: ...
+    def argmin(self) -> builtins.int: ...
+    def argmax(self) -> builtins.int: ...
+    def mean(self) -> builtins.float: ...
+    def variance(self, ddof: builtins.int = 0) -> builtins.float: ...
+    def std(self, ddof: builtins.int = 0) -> builtins.float: ...
+    def median(self) -> builtins.float: ...
+    def quantile(self, q: builtins.float) -> builtins.float: ...
+    def histogram(self, bins: builtins.int = 10, range: tuple[builtins.float, builtins.float] | None = None) -> tuple[list[Any], list[Any]]: ...
+    def map(self, f: Callable[[_T], _S], /) -> list[_S]: ...
+    def filter(self, predicate: Callable[[_T], Any], /) -> Self: ...
+    def filter_map(self, f: Callable[[_T], _S | None], /) -> list[_S]: ...
+    def flat_map(self, f: Callable[[_T], Iterable[_S]], /) -> list[_S]: ...
+    def fold(self, init: _S, f: Callable[[_S, _T], _S], /) -> _S: ...
+    def reduce(self, f: Callable[[_T, _T], _T], /) -> _T | None: ...
+    def scan(self, init: _S, f: Callable[[_S, _T], _S | None], /) -> list[_S]: ...
+    def take_while(self, predicate: Callable[[_T], Any], /) -> Self: ...
+    def skip_while(self, predicate: Callable[[_T], Any], /) -> Self: ...
+    def enumerate(self) -> list[tuple[builtins.int, _T]]: ...
+    def zip(self, other: Iterable[_S], /) -> list[tuple[_T, _S]]: ...
+    def chunks(self, size: builtins.int, /) -> list[Self]: ...
+    def chunks_exact(self, size: builtins.int, /) -> list[Self]: ...
+    def windows(self, size: builtins.int, /) -> list[Self]: ...
+    def chunk_by(self, predicate: Callable[[_T, _T], Any], /) -> list[Self]: ...
+    def group_by(self, predicate: Callable[[_T, _T], Any], /) -> list[Self]: ...
+    def partition(self, predicate: Callable[[_T], Any], /) -> tuple[Self, Self]: ...
+    def position(self, predicate: Callable[[_T], Any], /) -> builtins.int | None: ...
+    def rposition(self, predicate: Callable[[_T], Any], /) -> builtins.int | None: ...
+    def any(self, predicate: Callable[[_T], Any], /) -> builtins.bool: ...
+    def all(self, predicate: Callable[[_T], Any], /) -> builtins.bool: ...
+    def find(self, predicate: Callable[[_T], Any], /) -> _T | None: ...
+    def find_map(self, f: Callable[[_T], _S | None], /) -> _S | None: ...
+    def min_by_key(self, key: Callable[[_T], SupportsRichComparison], /) -> _T | None: ...
+    def max_by_key(self, key: Callable[[_T], SupportsRichComparison], /) -> _T | None: ...
     def __len__(self) -> int: ...
     def __iter__(self) -> Iterator[_T]: ...
     __hash__: ClassVar[None]  # type: ignore[assignment]
@@ -101,6 +936,8 @@ class list(MutableSequence[_T]):
     @overload
     def __getitem__(self, s: slice, /) -> list[_T]: ...
     @overload
+    def __getitem__(self, mask: list[bool], /) -> list[_T]: ...
+    @overload
     def __setitem__(self, key: SupportsIndex, value: _T, /) -> None: ...
     @overload
     def __setitem__(self, key: slice, value: Iterable[_T], /) -> None: ...
@@ -116,10 +953,53 @@ class list(MutableSequence[_T]):
     def __imul__(self, value: SupportsIndex, /) -> Self: ...
     def __contains__(self, key: object, /) -> bool: ...
     def __reversed__(self) -> Iterator[_T]: ...
+    @overload
     def __gt__(self, value: list[_T], /) -> bool: ...
+    @overload
+    def __gt__(self, value: Any, /) -> list[bool]: ...
+    @overload
     def __ge__(self, value: list[_T], /) -> bool: ...
+    @overload
+    def __ge__(self, value: Any, /) -> list[bool]: ...
+    @overload
     def __lt__(self, value: list[_T], /) -> bool: ...
+    @overload
+    def __lt__(self, value: Any, /) -> list[bool]: ...
+    @overload
     def __le__(self, value: list[_T], /) -> bool: ...
+    @overload
+    def __le__(self, value: Any, /) -> list[bool]: ...
     def __eq__(self, value: object, /) -> bool: ...
-    # if sys.version_info >= (3, 9):
-    #     def __class_getitem__(cls, item: Any, /) -> GenericAlias: ...
\ No newline at end of file
+    def __class_getitem__(cls, item: Any, /) -> GenericAlias: ...
+
+class Iter(Iterator[_T]):
+    def __init__(self, iterable: Iterable[_T], /) -> None: ...
+    def __iter__(self) -> Self: ...
+    def __next__(self) -> _T: ...
+    @overload
+    def map(self, f: Callable[[_T], _S], /) -> Iter[_S]: ...
+    @overload
+    def map(self, f: str, operand: Any = None, /) -> Iter[_T]: ...
+    @overload
+    def filter(self, predicate: Callable[[_T], Any], /) -> Iter[_T]: ...
+    @overload
+    def filter(self, predicate: str, value: Any, /) -> Iter[_T]: ...
+    def step_by(self, step: builtins.int, /) -> Iter[_T]: ...
+    def chain(self, other: Iterable[_T], /) -> Iter[_T]: ...
+    def zip(self, other: Iterable[_S], /) -> Iter[tuple[_T, _S]]: ...
+    def take(self, n: builtins.int, /) -> Iter[_T]: ...
+    def skip(self, n: builtins.int, /) -> Iter[_T]: ...
+    def peekable(self) -> Iter[_T]: ...
+    def peek(self) -> _T | None: ...
+    def cycle(self) -> Iter[_T]: ...
+    def flatten(self) -> Iter[Any]: ...
+    def inspect(self, f: Callable[[_T], Any], /) -> Iter[_T]: ...
+    def rev(self) -> Iter[_T]: ...
+    def collect(self, target: Any = None, /) -> Any: ...
+    def count(self) -> builtins.int: ...
+    def last(self) -> _T | None: ...
+    def nth(self, n: builtins.int, /) -> _T | None: ...
+    def sum(self) -> Any: ...
+
+def set_num_threads(threads: builtins.int, /) -> None: ...
+def get_num_threads() -> builtins.int: ...
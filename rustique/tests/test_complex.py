@@ -0,0 +1,143 @@
+import cmath
+import math
+import random
+import struct
+
+import pytest
+import rustique as rs
+
+
+def f32(x):
+    return struct.unpack("f", struct.pack("f", x))[0]
+
+
+def samples(n=200, seed=0):
+    rng = random.Random(seed)
+    return [complex(rng.uniform(-10, 10), rng.uniform(-10, 10)) for _ in range(n)]
+
+
+def close(a, b, tol=1e-14):
+    return cmath.isclose(complex(a), b, rel_tol=tol, abs_tol=tol)
+
+
+def test_f64_arithmetic_matches_complex():
+    values = samples()
+    for a, b in zip(values, reversed(values)):
+        x = rs.Complex(a.real, a.imag)
+        # Addition, subtraction and multiplication have a single IEEE formula
+        assert complex(x + b) == a + b
+        assert complex(b + x) == b + a
+        assert complex(x - b) == a - b
+        assert complex(b - x) == b - a
+        assert complex(x * b) == a * b
+        assert complex(b * x) == b * a
+        assert complex(-x) == -a
+        assert complex(x.conj()) == a.conjugate()
+        # Division uses CPython's algorithm; powers may differ in the last bits
+        assert complex(x / b) == a / b
+        assert complex(b / x) == b / a
+        assert close(x**b, a**b, 1e-12)
+        assert close(x**2, a**2)
+
+
+def test_f64_functions_match_cmath():
+    for a in samples():
+        x = rs.Complex(a.real, a.imag)
+        assert abs(x) == abs(a)
+        assert x.arg() == cmath.phase(a)
+        assert x.polar() == cmath.polar(a)
+        assert complex(x.exp()) == cmath.exp(a)
+        assert close(x.ln(), cmath.log(a))
+        assert close(x.log10(), cmath.log10(a))
+        assert close(x.log(2), cmath.log(a, 2))
+        assert close(x.sqrt(), cmath.sqrt(a))
+    r, phi = 2.0, 0.5
+    assert close(rs.Complex.from_polar(r, phi), cmath.rect(r, phi))
+
+
+def test_f32_rounds_each_operation():
+    for a, b in zip(samples(50, 1), samples(50, 2)):
+        ar, ai, br, bi = f32(a.real), f32(a.imag), f32(b.real), f32(b.imag)
+        x = rs.Complex(a.real, a.imag, kind="f32")
+        y = rs.Complex(b.real, b.imag, kind="f32")
+        assert x.kind == "f32"
+        assert complex(x) == complex(ar, ai)
+        assert complex(x + y) == complex(f32(ar + br), f32(ai + bi))
+        assert complex(x - y) == complex(f32(ar - br), f32(ai - bi))
+        expected = complex(f32(f32(ar * br) - f32(ai * bi)), f32(f32(ar * bi) + f32(ai * br)))
+        assert complex(x * y) == expected
+        assert close(x / y, complex(ar, ai) / complex(br, bi), 1e-6)
+
+
+def test_division_near_float_limit():
+    assert complex(rs.Complex(1e200, 1e200) / (1e200 + 1e200j)) == 1
+    for scale in (1e300, 1e-300, 1e154):
+        for a, b in zip(samples(50, 3), samples(50, 4)):
+            a, b = a * scale, b * scale
+            assert complex(rs.Complex(a.real, a.imag) / b) == a / b
+
+
+def test_mp_precision():
+    x = rs.Complex(1, 2, kind="mp", prec=200)
+    assert (x.kind, x.prec) == ("mp", 200)
+    assert close(x.exp(), cmath.exp(1 + 2j), 1e-15)
+    assert close(x.sqrt(), cmath.sqrt(1 + 2j), 1e-15)
+    assert close(x / 3, (1 + 2j) / 3, 1e-15)
+    third = (rs.Complex(1, 0, kind="mp", prec=200) / 3).real
+    assert third.prec == 200
+    assert abs(third * 3 - 1) < 2.0**-195
+    assert rs.Complex(3, 4, kind="mp").sqrt() == 2 + 1j
+
+
+def test_kinds_combine():
+    x32 = rs.Complex(1, 2, kind="f32")
+    assert (x32 + 1.5).kind == "f32"
+    assert (1j * x32).kind == "f32"
+    assert (x32 + rs.Complex(1, 2)).kind == "f64"
+    assert rs.Complex(1, 2, kind=rs.f32).kind == "f32"
+    assert x32.cast("f64").kind == "f64"
+    assert rs.Complex("1.5", "2") == 1.5 + 2j
+    with pytest.raises(ValueError):
+        rs.Complex(1, 2, kind="xx")
+
+
+def test_errors():
+    with pytest.raises(ZeroDivisionError):
+        rs.Complex(1, 2) / 0
+    with pytest.raises(ZeroDivisionError):
+        1 / rs.Complex(0, 0)
+    with pytest.raises(ZeroDivisionError):
+        rs.Complex(0, 0) ** -1
+    with pytest.raises(TypeError):
+        rs.Complex(1, 2) + "a"
+    with pytest.raises(TypeError):
+        rs.Complex(1, 2) < rs.Complex(2, 3)
+
+
+def test_comparison_hash_and_format():
+    for a in samples(20) + [1.5 + 0j, complex(math.inf, 0), 0j]:
+        x = rs.Complex(a.real, a.imag)
+        assert x == a
+        assert hash(x) == hash(a)
+    assert hash(rs.Complex(1.5, 0)) == hash(1.5)
+    assert rs.Complex(2, 0) == 2
+    assert rs.Complex(1, 2) != 1
+    assert rs.Complex(1, 2) != "1+2j"
+
+
+def test_equal_values_hash_equal():
+    others = [0.1, 0.5, 1.5, 2**60 + 1, 2**60, 0.1 + 0.5j, 16777217, 16777216]
+    for a in others:
+        value = complex(a)
+        for x in (
+            rs.Complex(value.real, value.imag, kind="f32"),
+            rs.Complex(value.real, value.imag),
+            rs.Complex(value.real, value.imag, kind="mp", prec=24),
+        ):
+            for b in others:
+                if x == b:
+                    assert hash(x) == hash(b)
+    assert rs.Complex(0.1, 0, kind="f32") != 0.1
+    assert rs.Complex(0.5, 0, kind="f32") == 0.5
+    assert rs.Complex(16777217, 0, kind="f32") != 16777217
+    assert format(rs.Complex(1, 2), ".3f") == format(1 + 2j, ".3f")
@@ -0,0 +1,153 @@
+import math
+import decimal
+from decimal import Decimal as PyDecimal
+
+import pytest
+import rustique as rs
+
+VALUES = ["0", "1.5", "-2.25", "123456.789", "-0.001", "99999999999999999999.99"]
+MODES = [
+    (rs.Rounding.HalfEven, decimal.ROUND_HALF_EVEN),
+    (rs.Rounding.HalfUp, decimal.ROUND_HALF_UP),
+    (rs.Rounding.HalfDown, decimal.ROUND_HALF_DOWN),
+    (rs.Rounding.Down, decimal.ROUND_DOWN),
+    (rs.Rounding.Up, decimal.ROUND_UP),
+    (rs.Rounding.Ceiling, decimal.ROUND_CEILING),
+    (rs.Rounding.Floor, decimal.ROUND_FLOOR),
+]
+
+
+def py(d):
+    return PyDecimal(str(d))
+
+
+def test_construction():
+    for s in VALUES + ["1e3", "2.5E-4", "+7", "-0.50"]:
+        assert py(rs.Decimal(s)) == PyDecimal(s)
+    assert rs.Decimal("1.50").scale == 2
+    assert rs.Decimal("1.50").mantissa == 150
+    assert str(rs.Decimal(0.1)) == "0.1"
+    assert str(rs.Decimal(42)) == "42"
+    assert str(rs.Decimal(PyDecimal("-3.140"))) == "-3.140"
+    assert str(rs.Decimal(rs.i8(-5))) == "-5"
+    assert str(rs.Decimal("1.005", scale=2, rounding="ROUND_HALF_UP")) == "1.01"
+    assert str(rs.Decimal.parse("1,234.50")) == "1234.50"
+    assert str(rs.Decimal.parse("1_234", "_")) == "1234"
+    assert rs.Decimal("2.5").to_decimal() == PyDecimal("2.5")
+
+
+def test_construction_errors():
+    for s in ["", "abc", "1.2.3", "1e"]:
+        with pytest.raises(ValueError):
+            rs.Decimal(s)
+    with pytest.raises(ValueError):
+        rs.Decimal(PyDecimal("NaN"))
+    with pytest.raises(OverflowError):
+        rs.Decimal("1", scale=39)
+    with pytest.raises(OverflowError):
+        rs.Decimal(2**127)
+    with pytest.raises(TypeError):
+        rs.Decimal(object())
+    with pytest.raises(ValueError):
+        rs.Decimal("1.5", scale=0, rounding="ROUND_SIDEWAYS")
+
+
+@pytest.mark.parametrize("a", VALUES)
+def test_arithmetic_matches_decimal(a):
+    with decimal.localcontext() as ctx:
+        ctx.prec = 100
+        for b in ["1", "-0.5", "6.25", "1000"]:
+            x, y = rs.Decimal(a), rs.Decimal(b)
+            assert py(x + y) == PyDecimal(a) + PyDecimal(b)
+            assert py(x - y) == PyDecimal(a) - PyDecimal(b)
+            assert py(x * y) == PyDecimal(a) * PyDecimal(b)
+            assert py(x / y) == PyDecimal(a) / PyDecimal(b)
+            assert py(x + 2) == PyDecimal(a) + 2
+            assert py(2 - x) == 2 - PyDecimal(a)
+            assert py(PyDecimal(b) * x) == PyDecimal(b) * PyDecimal(a)
+        assert py(-rs.Decimal(a)) == -PyDecimal(a)
+        assert py(abs(rs.Decimal(a))) == abs(PyDecimal(a))
+
+
+@pytest.mark.parametrize("ours, theirs", MODES)
+def test_rounding_modes_match_decimal(ours, theirs):
+    for s in ["2.5", "3.5", "-2.5", "-3.5", "1.25", "-1.251", "0.005", "7.000", "-0.0049"]:
+        for scale in [0, 1, 2]:
+            expected = PyDecimal(s).quantize(PyDecimal(1).scaleb(-scale), rounding=theirs)
+            assert py(rs.Decimal(s).quantize(scale, ours)) == expected
+            assert py(rs.Decimal(s).quantize(scale, theirs)) == expected
+    with decimal.localcontext() as ctx:
+        ctx.rounding = theirs
+        expected = (PyDecimal(2) / PyDecimal(3)).quantize(PyDecimal("0.0001"))
+    assert py(rs.Decimal(2).div(3, 4, ours)) == expected
+    expected = (PyDecimal("1.15") * PyDecimal("-0.5")).quantize(PyDecimal("0.01"), rounding=theirs)
+    assert py(rs.Decimal("1.15").mul(rs.Decimal("-0.5"), 2, ours)) == expected
+
+
+def test_inexact_and_overflow():
+    with pytest.raises(ArithmeticError):
+        rs.Decimal(1) / 3
+    with pytest.raises(ArithmeticError):
+        rs.Decimal("1.25").quantize(1)
+    assert str(rs.Decimal(1) / 8) == "0.125"
+    with pytest.raises(ZeroDivisionError):
+        rs.Decimal(1) / 0
+    with pytest.raises(ZeroDivisionError):
+        1 / rs.Decimal(0)
+    big = rs.Decimal(2**126)
+    with pytest.raises(OverflowError):
+        big + big
+    with pytest.raises(OverflowError):
+        big * 2
+    with pytest.raises(OverflowError):
+        rs.Decimal("1.5") * rs.Decimal("0." + "0" * 37 + "1")
+
+
+def test_strings_are_not_numbers():
+    assert rs.Decimal("1") != "1"
+    assert not rs.Decimal("1") == "1"
+    with pytest.raises(TypeError):
+        rs.Decimal(1) + "2"
+    with pytest.raises(TypeError):
+        "2" + rs.Decimal(1)
+    with pytest.raises(TypeError):
+        rs.Decimal(1) * "2"
+    with pytest.raises(TypeError):
+        rs.Decimal(1) < "2"
+    with pytest.raises(TypeError):
+        rs.Decimal(1).mul("2")
+
+
+def test_mul_checks_scale_first():
+    with pytest.raises(OverflowError):
+        rs.Decimal("1.5").mul(rs.Decimal(2), 2**32 - 1)
+
+
+def test_comparison_and_hash():
+    for s in VALUES + ["1.50", "-7"]:
+        assert hash(rs.Decimal(s)) == hash(PyDecimal(s))
+        assert rs.Decimal(s) == PyDecimal(s)
+    assert rs.Decimal("1.50") == rs.Decimal("1.5")
+    assert rs.Decimal("0.5") == 0.5
+    assert rs.Decimal("0.1") != 0.1
+    assert rs.Decimal("0.1") < 0.1000001
+    assert rs.Decimal(2) > 1
+    assert rs.Decimal(1) != float("nan")
+    assert not rs.Decimal(1) < float("nan")
+    for s in VALUES:
+        assert rs.Decimal(s) < math.inf
+        assert rs.Decimal(s) > -math.inf
+        assert rs.Decimal(s) != math.inf
+    assert {rs.Decimal("2.0"): "x"}[2] == "x"
+
+
+def test_conversions_and_formatting():
+    for s in VALUES:
+        assert float(rs.Decimal(s)) == float(PyDecimal(s))
+        assert int(rs.Decimal(s)) == int(PyDecimal(s))
+        assert round(rs.Decimal(s)) == round(PyDecimal(s))
+        assert py(round(rs.Decimal(s), 1)) == round(PyDecimal(s), 1)
+    assert format(rs.Decimal("1234567.891"), ",.2f") == format(PyDecimal("1234567.891"), ",.2f")
+    assert format(rs.Decimal("-1234.5"), "_") == "-1_234.5"
+    with pytest.raises(ValueError):
+        format(rs.Decimal(1), "x")
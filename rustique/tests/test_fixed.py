@@ -0,0 +1,162 @@
+import math
+import struct
+from fractions import Fraction
+
+import pytest
+import rustique as rs
+
+# Class, fractional bits and the struct code of the backing integer
+FORMATS = [(rs.Q15, 15, "h"), (rs.Q31, 31, "i"), (rs.Q7_8, 8, "h")]
+
+
+def wrap(bits, code):
+    # Reinterpret the low bits as the backing integer, like a Rust `as` cast
+    size = struct.calcsize(code)
+    return struct.unpack("<" + code, (bits & (2 ** (8 * size) - 1)).to_bytes(size, "little"))[0]
+
+
+def fits(bits, code):
+    try:
+        struct.pack("<" + code, bits)
+        return True
+    except struct.error:
+        return False
+
+
+def trunc_div(a, b):
+    q = abs(a) // abs(b)
+    return q if (a < 0) == (b < 0) else -q
+
+
+def raw_samples(code):
+    top = 2 ** (8 * struct.calcsize(code) - 1)
+    return [0, 1, -1, 3, -7, 1000, -12345 % top, top // 2, -top // 2, top - 1, -top, -top + 1]
+
+
+@pytest.mark.parametrize("cls, frac, code", FORMATS)
+def test_bits_round_trip_through_struct(cls, frac, code):
+    for bits in raw_samples(code):
+        x = cls.from_bits(bits)
+        assert struct.pack("<" + code, x.to_bits()) == struct.pack("<" + code, bits)
+        assert Fraction(str(x)) == Fraction(bits, 2**frac)
+        assert float(x) == bits / 2**frac
+        unsigned = struct.unpack("<" + code.upper(), struct.pack("<" + code, bits))[0]
+        assert cls.from_bits(unsigned).to_bits() == bits
+    with pytest.raises(OverflowError):
+        cls.from_bits(2 ** (8 * struct.calcsize(code)))
+    with pytest.raises(OverflowError):
+        cls.from_bits(-(2 ** (8 * struct.calcsize(code) - 1)) - 1)
+
+
+@pytest.mark.parametrize("cls, frac, code", FORMATS)
+def test_arithmetic_is_bit_exact(cls, frac, code):
+    samples = raw_samples(code)
+    for a in samples:
+        for b in samples:
+            x, y = cls.from_bits(a), cls.from_bits(b)
+            for name, exact in [
+                ("add", a + b),
+                ("sub", a - b),
+                # Products shift right arithmetically, i.e. round to -inf
+                ("mul", (a * b) >> frac),
+            ]:
+                assert getattr(x, "wrapping_" + name)(y).to_bits() == wrap(exact, code)
+                value, overflow = getattr(x, "overflowing_" + name)(y)
+                assert (value.to_bits(), overflow) == (wrap(exact, code), not fits(exact, code))
+                checked = getattr(x, "checked_" + name)(y)
+                if fits(exact, code):
+                    assert checked.to_bits() == exact
+                    assert getattr(x, "__" + name + "__")(y).to_bits() == exact
+                else:
+                    assert checked is None
+                    with pytest.raises(OverflowError):
+                        getattr(x, "__" + name + "__")(y)
+            if b:
+                # Quotients truncate toward zero like integer division
+                exact = trunc_div(a << frac, b)
+                assert x.wrapping_div(y).to_bits() == wrap(exact, code)
+                if fits(exact, code):
+                    assert (x / y).to_bits() == exact
+                else:
+                    with pytest.raises(OverflowError):
+                        x / y
+            else:
+                with pytest.raises(ZeroDivisionError):
+                    x / y
+                assert x.checked_div(y) is None
+
+
+@pytest.mark.parametrize("cls, frac, code", FORMATS)
+def test_saturation(cls, frac, code):
+    lo, hi = cls.min_value(), cls.max_value()
+    top = 2 ** (8 * struct.calcsize(code) - 1)
+    assert (lo.to_bits(), hi.to_bits()) == (-top, top - 1)
+    assert hi.saturating_add(hi).to_bits() == hi.to_bits()
+    assert lo.saturating_sub(hi).to_bits() == lo.to_bits()
+    assert lo.saturating_neg().to_bits() == hi.to_bits()
+    assert lo.saturating_abs().to_bits() == hi.to_bits()
+    assert lo.wrapping_neg().to_bits() == lo.to_bits()
+    assert lo.saturating_mul(lo).to_bits() == hi.to_bits()
+    with pytest.raises(OverflowError):
+        -lo
+    with pytest.raises(OverflowError):
+        abs(lo)
+    assert cls(10**6, saturate=True).to_bits() == hi.to_bits()
+    assert cls(-(10**6), saturate=True).to_bits() == lo.to_bits()
+    assert cls(float("inf"), saturate=True).to_bits() == hi.to_bits()
+    with pytest.raises(OverflowError):
+        cls(10**6)
+    with pytest.raises(ValueError):
+        cls(float("nan"))
+
+
+def test_conversion_rounding():
+    # 0.1 lies between two Q15 steps; nearest is 3277 / 2**15
+    assert rs.Q15(0.1).to_bits() == round(0.1 * 2**15)
+    assert rs.Q15(0.1, rounding=rs.Rounding.Floor).to_bits() == math.floor(0.1 * 2**15)
+    assert rs.Q15(0.1, rounding="ROUND_CEILING").to_bits() == math.ceil(0.1 * 2**15)
+    # Ties go to even by default
+    assert rs.Q7_8(Fraction(3, 512)).to_bits() == 2
+    assert rs.Q7_8(Fraction(5, 512)).to_bits() == 2
+    assert rs.Q7_8(Fraction(5, 512), rounding="ROUND_HALF_UP").to_bits() == 3
+    assert rs.Q15(-1).to_bits() == -(2**15)
+    with pytest.raises(OverflowError):
+        rs.Q15(1)
+    assert rs.Q31(Fraction(1, 2)).to_bits() == 2**30
+    q31 = rs.Q31(0.123456789)
+    assert q31.convert(0, 15).to_bits() == round(q31.to_bits() / 2**16)
+    assert rs.Q15(rs.Q7_8(0.5)).to_bits() == 2**14
+
+
+def test_generic_formats_and_errors():
+    u = rs.Fixed(2.75, 4, 4, signed=False)
+    assert (u.to_bits(), u.width, u.signed) == (44, 8, False)
+    assert struct.pack("<B", u.to_bits()) == b"\x2c"
+    assert rs.Fixed.from_bits(0xFF, 4, 4, signed=False).to_bits() == 255
+    with pytest.raises(OverflowError):
+        rs.Fixed(-1, 4, 4, signed=False)
+    with pytest.raises(ValueError):
+        rs.Fixed(1, 3, 3)
+    with pytest.raises(ValueError, match="Cannot mix"):
+        rs.Q15(0.5) + rs.Q7_8(0.5)
+    with pytest.raises(TypeError):
+        rs.Q15(0.5) + 0.25
+    with pytest.raises(TypeError):
+        rs.Q15(0.5).wrapping_add(0.25)
+    assert isinstance(rs.Q15(0.5) * rs.Q15(0.5), rs.Q15)
+    assert (rs.Q7_8(1.5) * 2).to_bits() == 3 * 2**8
+    assert (3 / rs.Q7_8(2)).to_bits() == 384
+    assert repr(rs.Q15(0.5)) == "Q15(0.5)"
+
+
+def test_comparison_and_hash():
+    for bits in raw_samples("h"):
+        x = rs.Q15.from_bits(bits)
+        assert hash(x) == hash(Fraction(bits, 2**15))
+        assert x == Fraction(bits, 2**15)
+        assert x == bits / 2**15
+    assert rs.Q15(0.5) == rs.Q7_8(0.5)
+    assert rs.Q15(0.25) < rs.Q7_8(0.5)
+    assert rs.Q7_8(2) == 2
+    assert rs.Q15(0.5) != float("nan")
+    assert rs.Q15(0.5) < float("inf")
@@ -0,0 +1,161 @@
+import math
+import numbers
+from decimal import Decimal, localcontext
+from fractions import Fraction
+
+import pytest
+import rustique as rs
+
+VALUES = [0.0, 1.0, -2.5, 0.1, 1e300, -3.75e-310, 12345.678]
+
+
+def exact(x):
+    return Fraction(*x.as_integer_ratio())
+
+
+def test_construction():
+    assert float(rs.float()) == 0.0
+    assert float(rs.float(0.1)) == 0.1
+    assert float(rs.float(7)) == 7.0
+    assert float(rs.float("0.1")) == 0.1
+    assert rs.float(2**200 + 1, prec=256).as_integer_ratio() == (2**200 + 1, 1)
+    assert rs.float("0.1", prec=200).as_integer_ratio() != (0.1).as_integer_ratio()
+    assert rs.float(1, prec=100).prec == 100
+    assert rs.float("inf").is_infinite()
+    assert rs.float("nan").is_nan()
+    with pytest.raises(ValueError):
+        rs.float("abc")
+    with pytest.raises(ValueError):
+        rs.float(1, prec=0)
+    with pytest.raises(TypeError):
+        rs.float(object())
+
+
+@pytest.mark.parametrize("a", VALUES)
+def test_arithmetic_matches_float(a):
+    # At 53 bits with round-to-nearest every operation is IEEE double arithmetic
+    for b in [1.0, -3.0, 0.1, 7, 2.5e-8]:
+        x = rs.float(a)
+        assert float(x + b) == a + b
+        assert float(b + x) == b + a
+        assert float(x - b) == a - b
+        assert float(b - x) == b - a
+        assert float(x * b) == a * b
+        assert float(b * x) == b * a
+        assert float(x / b) == a / b
+        if a:
+            assert float(b / x) == b / a
+    assert float(-rs.float(a)) == -a
+    assert float(abs(rs.float(a))) == abs(a)
+    assert float(rs.float(2.0) ** 0.5) == 2.0**0.5
+    assert float(2 ** rs.float(0.5)) == 2**0.5
+
+
+def test_correct_rounding_at_high_precision():
+    third = rs.float(1, prec=200) / 3
+    with localcontext() as ctx:
+        ctx.prec = 80
+        expected = Decimal(1) / Decimal(3)
+        assert abs(Decimal(third.to_string(60)) - expected) < Decimal(10) ** -60
+    num, den = third.as_integer_ratio()
+    assert abs(Fraction(num, den) - Fraction(1, 3)) <= Fraction(1, 2**201)
+
+    down = rs.float(1, prec=10, round=rs.Round.Down) / 3
+    up = rs.float(1, prec=10, round=rs.Round.Up) / 3
+    assert exact(down) < Fraction(1, 3) < exact(up)
+
+
+def test_foreign_operands_defer_to_reflection():
+    half = Fraction(1, 2)
+    # Fraction falls back to Python float arithmetic for registered numbers.Real
+    assert isinstance(rs.float(1), numbers.Real)
+    assert rs.float(1) + half == 1.5
+    assert rs.float(3) * half == 1.5
+    assert rs.float(1) - half == 0.5
+    with pytest.raises(TypeError):
+        rs.float(1) + Decimal(1)
+    with pytest.raises(TypeError):
+        rs.float(1) + "1"
+    with pytest.raises(TypeError):
+        "1" * rs.float(2)
+    with pytest.raises(TypeError):
+        rs.float(1) - None
+    with pytest.raises(TypeError):
+        pow(rs.float(2), 2, 3)
+
+
+def test_division_by_zero():
+    with pytest.raises(ZeroDivisionError):
+        rs.float(1) / 0
+    with pytest.raises(ZeroDivisionError):
+        1 / rs.float(0)
+    with pytest.raises(TypeError):
+        "x" / rs.float(0)
+
+
+def test_functions_match_math():
+    for name, fn in [("sqrt", math.sqrt), ("exp", math.exp), ("ln", math.log), ("sin", math.sin),
+                     ("atan", math.atan), ("erf", math.erf), ("gamma", math.gamma)]:
+        assert math.isclose(float(getattr(rs.float(0.75), name)()), fn(0.75), rel_tol=1e-15)
+    assert float(rs.float(3).hypot(4)) == 5.0
+    assert float(rs.float(1).atan2(-1)) == math.atan2(1, -1)
+    assert float(rs.float.pi()) == math.pi
+    assert float(rs.float.e()) == math.e
+    assert float(rs.float(-2.5).floor()) == -3.0
+    assert float(rs.float(2.5).round_even()) == 2.0
+    with pytest.raises(TypeError):
+        rs.float(1).hypot("x")
+
+
+def test_comparison_and_hash():
+    for a in VALUES + [math.inf, -math.inf]:
+        assert hash(rs.float(a)) == hash(a)
+        assert rs.float(a) == a
+    x = rs.float("0.1", prec=120)
+    assert hash(x) == hash(exact(x))
+    assert rs.float(2**70, prec=80) == 2**70
+    assert hash(rs.float(2**70, prec=80)) == hash(2**70)
+    assert rs.float(1) < 1.5
+    assert rs.float("nan") != rs.float("nan")
+    assert not rs.float("nan") == 0
+    assert rs.float(1) != "1"
+    assert {rs.float(0.5): "x"}[0.5] == "x"
+    assert not Fraction(1, 2) == rs.float(0.25)
+
+
+def test_conversions():
+    assert int(rs.float(-3.9)) == -3
+    assert int(rs.float(-3.5).to_int()) == -4
+    assert int(rs.float(2.5).to_int(rs.Round.Zero)) == 2
+    assert rs.float(0.375).as_integer_ratio() == (0.375).as_integer_ratio()
+    with pytest.raises(OverflowError):
+        int(rs.float("inf"))
+    with pytest.raises(ValueError):
+        int(rs.float("nan"))
+    with pytest.raises(ValueError):
+        rs.float("nan").as_integer_ratio()
+
+
+def test_rounding_protocol():
+    for a in VALUES + [2.5, -2.5, 3.5, -0.5]:
+        x = rs.float(a)
+        assert math.trunc(x) == math.trunc(a)
+        assert math.floor(x) == math.floor(a)
+        assert math.ceil(x) == math.ceil(a)
+        assert round(x) == round(a)
+        for n in (-2, 0, 1, 3):
+            assert round(x, n) == round(a, n)
+    assert round(rs.float(2.5, round=rs.Round.Up)) == 3
+    assert round(rs.float(-1201, round=rs.Round.Away), -2) == -1300
+    assert round(rs.float("inf"), 2) == math.inf
+    with pytest.raises(OverflowError):
+        math.floor(rs.float("-inf"))
+    with pytest.raises(ValueError):
+        round(rs.float("nan"))
+
+
+def test_pow_rejects_modulo():
+    with pytest.raises(TypeError):
+        pow(rs.float(2), 3, 5)
+    with pytest.raises(TypeError):
+        pow(2, rs.float(3), 5)
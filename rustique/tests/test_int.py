@@ -0,0 +1,150 @@
+import math
+
+import pytest
+import rustique as rs
+
+VALUES = [0, 1, -1, 7, -7, 2**64 + 3, -(2**100) + 12345, 10**40 + 7]
+
+
+def test_construction():
+    assert int(rs.int()) == 0
+    assert int(rs.int(42)) == 42
+    assert int(rs.int(-3.9)) == -3
+    assert int(rs.int("1_000")) == 1000
+    assert int(rs.int("ff", 16)) == 255
+    assert int(rs.int("-zz", 36)) == -1295
+    assert int(rs.int(rs.i8(-5))) == -5
+    assert int(rs.int(2**200)) == 2**200
+    with pytest.raises(ValueError):
+        rs.int("12", 1)
+    with pytest.raises(ValueError):
+        rs.int("abc")
+    with pytest.raises(OverflowError):
+        rs.int(float("inf"))
+    with pytest.raises(TypeError):
+        rs.int(5, 10)
+    with pytest.raises(TypeError):
+        rs.int(5.0, 2)
+    with pytest.raises(TypeError):
+        rs.int(base=2)
+    with pytest.raises(TypeError):
+        rs.int(object())
+
+
+@pytest.mark.parametrize("a", VALUES)
+def test_arithmetic_matches_int(a):
+    for b in [1, -1, 3, -5, 2**70 + 1]:
+        x = rs.int(a)
+        assert int(x + b) == a + b
+        assert int(b + x) == b + a
+        assert int(x - b) == a - b
+        assert int(b - x) == b - a
+        assert int(x * b) == a * b
+        assert int(b * x) == b * a
+        assert int(x // b) == a // b
+        assert int(x % b) == a % b
+        assert tuple(map(int, divmod(x, b))) == divmod(a, b)
+        assert x / b == a / b
+        assert int(x & b) == a & b
+        assert int(x | b) == a | b
+        assert int(x ^ b) == a ^ b
+        if a:
+            assert int(b // x) == b // a
+            assert int(b % x) == b % a
+            assert tuple(map(int, divmod(b, x))) == divmod(b, a)
+            assert b / x == b / a
+        assert int(b & x) == b & a
+        assert int(b | x) == b | a
+        assert int(b ^ x) == b ^ a
+    assert int(-rs.int(a)) == -a
+    assert int(abs(rs.int(a))) == abs(a)
+    assert int(~rs.int(a)) == ~a
+
+
+def test_powers_and_shifts():
+    assert int(rs.int(3) ** 40) == 3**40
+    assert int(2 ** rs.int(100)) == 2**100
+    assert int(pow(rs.int(3), 200, 1000003)) == pow(3, 200, 1000003)
+    # Results take the sign of the modulus, like Python's pow
+    for base, exponent, modulus in [(3, 2, -5), (-3, 3, -7), (10, 0, -3), (5, 1, -5), (3, -1, -5), (7, 3, 5)]:
+        expected = pow(base, exponent, modulus)
+        assert int(rs.int(base).pow_mod(exponent, modulus)) == expected
+        assert int(pow(rs.int(base), exponent, modulus)) == expected
+        assert int(pow(base, rs.int(exponent), modulus)) == expected
+        if exponent == -1:
+            assert int(rs.int(base).invert(modulus)) == expected
+    assert int(rs.int(1) << 100) == 1 << 100
+    assert int(1 << rs.int(100)) == 1 << 100
+    assert int(rs.int(-(2**100)) >> 3) == -(2**100) >> 3
+    assert int(2**100 >> rs.int(90)) == 2**10
+    with pytest.raises(ValueError):
+        rs.int(2) ** -1
+    with pytest.raises(ValueError):
+        1 << rs.int(-1)
+    with pytest.raises(ValueError):
+        rs.int(1) >> -1
+
+
+def test_division_errors():
+    for op in (lambda a, b: a // b, lambda a, b: a % b, divmod, lambda a, b: a / b):
+        with pytest.raises(ZeroDivisionError):
+            op(rs.int(1), 0)
+        with pytest.raises(ZeroDivisionError):
+            op(1, rs.int(0))
+    with pytest.raises(OverflowError):
+        rs.int(10**400) / 1
+    with pytest.raises(OverflowError):
+        float(rs.int(10**400))
+
+
+def test_comparison_and_hash():
+    for a in VALUES:
+        assert hash(rs.int(a)) == hash(a)
+        assert rs.int(a) == a
+        assert rs.int(a) == float(a) or abs(a) > 2**53
+    assert rs.int(1) < 1.5
+    assert rs.int(2) > rs.int(1)
+    assert rs.int(1) != float("nan")
+    assert not rs.int(1) == float("nan")
+    assert rs.int(1) != "1"
+    assert {rs.int(5): "x"}[5] == "x"
+    with pytest.raises(TypeError):
+        rs.int(1) + "1"
+
+
+def test_number_theory():
+    assert int(rs.int(12).gcd(18)) == math.gcd(12, 18)
+    assert int(rs.int(4).lcm(6)) == math.lcm(4, 6)
+    g, s, t = rs.int(240).gcd_ext(46)
+    assert int(g) == 2 and 240 * int(s) + 46 * int(t) == 2
+    assert int(rs.int(3).invert(11)) == pow(3, -1, 11)
+    with pytest.raises(ValueError, match="not invertible"):
+        rs.int(2).invert(4)
+    with pytest.raises(ValueError, match="not invertible"):
+        rs.int(2).pow_mod(-1, 4)
+    with pytest.raises(ZeroDivisionError):
+        rs.int(2).pow_mod(3, 0)
+    assert rs.int(2**61 - 1).is_probably_prime()
+    assert not rs.int(2**61 + 1).is_probably_prime()
+    assert int(rs.int(100).next_prime()) == 101
+    assert rs.int(2).jacobi(15) == 1
+    assert rs.int(2).legendre(7) == 1
+    assert rs.int(3).kronecker(8) == -1
+    with pytest.raises(ValueError):
+        rs.int(2).jacobi(4)
+    with pytest.raises(ValueError):
+        rs.int(2).legendre(9)
+    s, r = rs.int(10**20 + 5).sqrt_rem()
+    assert int(s) == math.isqrt(10**20 + 5) and int(r) == 5
+    with pytest.raises(ValueError):
+        rs.int(-1).sqrt_rem()
+    assert int(rs.int(-27).root(3)) == -3
+    with pytest.raises(ValueError):
+        rs.int(-4).root(2)
+    assert rs.int(144).is_perfect_square()
+    assert rs.int(3**7).is_perfect_power()
+    assert int(rs.int.factorial(25)) == math.factorial(25)
+    assert int(rs.int.binomial(50, 20)) == math.comb(50, 20)
+    assert int(rs.int.fibonacci(90)) == 2880067194370816120
+    assert int(rs.int.lucas(10)) == 123
+    assert rs.int(2**100).bit_length() == (2**100).bit_length()
@@ -0,0 +1,140 @@
+import typing
+
+import pytest
+import rustique as rs
+from rustique import Iter
+from rustique.collections.list import List
+
+
+def test_lazy_adaptors():
+    calls = []
+    it = Iter(range(10)).inspect(calls.append).map(lambda x: x * x).filter(lambda x: x % 2)
+    assert calls == []
+    assert next(it) == 1
+    assert calls == [0, 1]
+    assert it.collect() == List(9, 25, 49, 81)
+
+    assert Iter("abcde").step_by(2).collect() == List("a", "c", "e")
+    assert Iter([1, 2]).chain(Iter([3])).chain([4]).collect() == List(1, 2, 3, 4)
+    assert Iter([1, 2, 3]).zip("ab").collect(list) == [(1, "a"), (2, "b")]
+    assert Iter(range(10)).skip(3).take(2).collect() == List(3, 4)
+    assert Iter([[1, 2], [], (3,)]).flatten().collect() == List(1, 2, 3)
+    assert Iter([1, 2]).cycle().take(5).collect() == List(1, 2, 1, 2, 1)
+    assert Iter([]).cycle().collect() == List()
+    assert list(Iter(x for x in "ab")) == ["a", "b"]
+
+
+def test_rev():
+    assert Iter(range(4)).rev().collect() == List(3, 2, 1, 0)
+    assert Iter([1, 2, 3]).map(str).rev().collect() == List("3", "2", "1")
+    assert Iter([1, 2]).chain([3, 4]).rev().collect() == List(4, 3, 2, 1)
+    assert Iter([1, 2, 3]).rev().rev().collect() == List(1, 2, 3)
+    with pytest.raises(TypeError):
+        Iter(x for x in [1]).rev()
+    with pytest.raises(TypeError):
+        Iter([1, 2]).take(1).rev()
+
+
+def test_peekable():
+    it = Iter([1, 2]).peekable()
+    assert it.peek() == 1
+    assert it.peek() == 1
+    assert next(it) == 1
+    assert it.peek() == 2
+    assert it.map(lambda x: x * 10).collect() == List(20)
+
+    empty = Iter([]).peekable()
+    assert empty.peek() is None
+    assert list(empty) == []
+    with pytest.raises(TypeError):
+        Iter([1]).peek()
+
+
+def test_collectors():
+    assert Iter(range(5)).collect(List[int]) == List[int](0, 1, 2, 3, 4)
+    assert Iter("ab").collect(set) == {"a", "b"}
+    # Lists are built from the items, not around a list holding them
+    for target in [List[object], List[typing.Any], List[list]]:
+        collected = Iter([[1], [2]]).collect(target)
+        assert isinstance(collected, target) and list(collected) == [[1], [2]]
+    assert list(Iter([1, 2]).collect(List[object])) == [1, 2]
+
+    class Sub(List):
+        pass
+
+    collected = Iter([1, 2]).collect(Sub)
+    assert type(collected) is Sub and list(collected) == [1, 2]
+    assert Iter(range(5)).count() == 5
+    assert Iter(range(5)).last() == 4
+    assert Iter([]).last() is None
+    assert Iter(range(5)).sum() == 10
+    assert Iter([1.5, 2]).sum() == 3.5
+    assert Iter([]).sum() == 0
+
+    it = Iter(range(5))
+    assert it.nth(1) == 1
+    assert it.nth(0) == 2
+    assert it.collect() == List(3, 4)
+    assert Iter([1]).nth(3) is None
+
+    with pytest.raises(TypeError):
+        Iter(["a", 1]).sum()
+
+
+def test_moved():
+    it = Iter([1, 2])
+    mapped = it.map(str)
+    with pytest.raises(ValueError, match="moved"):
+        it.count()
+    assert mapped.count() == 2
+    with pytest.raises(ValueError, match="moved"):
+        mapped.collect()
+
+
+def test_errors_propagate():
+    def fail(x):
+        raise KeyError(x)
+
+    with pytest.raises(KeyError):
+        Iter([1, 2]).map(fail).skip(1).collect()
+    with pytest.raises(KeyError):
+        Iter([1, 2, 3]).map(fail).step_by(2).count()
+    with pytest.raises(TypeError):
+        Iter([1, 2]).flatten().collect()
+    with pytest.raises(TypeError):
+        Iter(5)
+
+
+def test_native_chains():
+    a = List[rs.i32](range(1, 7), coerce=True)
+    result = Iter(a).map("mul", 10).filter(">", 20).collect()
+    assert result == List[rs.i32]([30, 40, 50, 60], coerce=True)
+    assert Iter(a).rev().step_by(2).collect() == List[rs.i32]([6, 4, 2], coerce=True)
+    assert Iter(a).skip(4).chain(Iter(a).take(1)).collect() == List[rs.i32]([5, 6, 1], coerce=True)
+    assert Iter(a).collect(List[rs.i32]) == a
+    assert Iter(a).map("add", 1).sum() == 27
+    assert Iter(a).filter("<=", 2).count() == 2
+    assert memoryview(List[rs.i32]([Iter(a).map("square").last()])).tolist() == [36]
+
+    it = Iter(a)
+    assert memoryview(List[rs.i32]([it.nth(4)])).tolist() == [5]
+    assert memoryview(it.collect()).tolist() == [6]
+
+    with pytest.raises(OverflowError, match="i8 overflow in map"):
+        Iter(List[rs.i8]([100], coerce=True)).map("mul", 2).collect()
+    with pytest.raises(TypeError):
+        Iter(a).map("add")
+    with pytest.raises(ValueError):
+        Iter(a).map("frobnicate", 1)
+    with pytest.raises(TypeError, match="requires an Iter over a List"):
+        Iter([1, 2]).map("add", 1)
+
+
+def test_mixed_chains():
+    a = List[rs.i32]([1, 2, 3], coerce=True)
+    # Python callables and Python sources leave unboxed items behind
+    tail = Iter(a).map("add", 1).chain([7]).collect()
+    assert memoryview(List[rs.i32](tail[:3])).tolist() == [2, 3, 4]
+    assert tail[3] == 7
+    assert not isinstance(tail, List[rs.i32])
+    assert Iter(a).filter("<", 3).map(lambda x: 0).collect() == List(0, 0)
@@ -1,2 +1,1107 @@
+import sys
+
 import pytest
-from rustique.collections.list import List
\ No newline at end of file
+from collections.abc import MutableSequence
+from typing import Any, Literal
+import rustique as rs
+from rustique.collections.list import List
+
+# Adapted from CPython's Lib/test/list_tests.py
+
+
+def test_init():
+    assert List() == []
+    assert List([0, 1, 2]) == [0, 1, 2]
+    assert List(range(3)) == [0, 1, 2]
+    assert List(iter(range(3))) == [0, 1, 2]
+    assert List({1}) == [1]
+    assert List(x for x in "abc") == ["a", "b", "c"]
+    assert List((1, 2)) == [1, 2]
+    assert List(List(1, 2)) == [1, 2]
+    assert List.from_iter(range(3)) == [0, 1, 2]
+
+
+def test_init_single_element():
+    # Any iterable but a str is unpacked
+    assert List("abc") == ["abc"]
+    assert List(b"ab") == [97, 98]
+    assert List({1: 2}) == [1]
+    assert List(5) == [5]
+    assert List(None) == [None]
+    assert List.from_iter("abc") == ["a", "b", "c"]
+    assert list(List[Any]([1, 2])) == [1, 2]
+    assert list(List[object]((1, 2))) == [1, 2]
+    assert list(List[str]("abc")) == ["abc"]
+    # A lone tuple is unpacked even when it would be a valid element
+    assert list(List[tuple[int, int]](((1, 2),))) == [(1, 2)]
+    with pytest.raises(TypeError):
+        List[tuple[int, int]]((1, 2))
+
+
+def test_mutable_sequence():
+    assert isinstance(List(), MutableSequence)
+
+
+def test_repr():
+    assert repr(List()) == "List()"
+    assert repr(List(1, "a")) == "List(1, 'a')"
+
+
+def test_repr_propagates_errors():
+    class BadRepr:
+        def __repr__(self):
+            raise ZeroDivisionError
+
+    with pytest.raises(ZeroDivisionError):
+        repr(List(BadRepr(), BadRepr()))
+
+
+def test_getitem():
+    a = List(0, 1, 2, 3)
+    assert a[0] == 0
+    assert a[-1] == 3
+    assert a[-4] == 0
+    with pytest.raises(IndexError):
+        a[4]
+    with pytest.raises(IndexError):
+        a[-5]
+    with pytest.raises(TypeError):
+        a["a"]
+    assert a[1:3] == [1, 2]
+    assert a[::-1] == [3, 2, 1, 0]
+    assert a[10:] == []
+
+
+def test_setitem():
+    a = List(0, 1)
+    a[0] = 5
+    a[-1] = 6
+    assert a == [5, 6]
+    with pytest.raises(IndexError):
+        a[2] = 0
+    with pytest.raises(IndexError):
+        a[-3] = 0
+    a[1:1] = [1, 2, 3]
+    assert a == [5, 1, 2, 3, 6]
+    a[::2] = "abc"
+    assert a == ["a", 1, "b", 3, "c"]
+    with pytest.raises(ValueError):
+        a[::2] = [1]
+    a[:] = a
+    assert a == ["a", 1, "b", 3, "c"]
+
+
+def test_delitem():
+    a = List(0, 1)
+    del a[1]
+    assert a == [0]
+    del a[-1]
+    assert a == []
+    a = List(0, 1)
+    with pytest.raises(IndexError):
+        del a[2]
+    with pytest.raises(IndexError):
+        del a[-3]
+
+
+def test_delslice():
+    a = List(range(10))
+    del a[1:3]
+    assert a == [0, 3, 4, 5, 6, 7, 8, 9]
+    del a[::2]
+    assert a == [3, 5, 7, 9]
+    del a[::-2]
+    assert a == [3, 7]
+    del a[5:]
+    assert a == [3, 7]
+    del a[:]
+    assert a == []
+
+
+def test_append_extend():
+    a = List()
+    a.append(0)
+    a.append(1)
+    a.extend([2, 3])
+    a.extend(a)
+    assert a == [0, 1, 2, 3, 0, 1, 2, 3]
+    a = List()
+    a.extend(iter("ab"))
+    a.extend({"c": 1})
+    assert a == ["a", "b", "c"]
+    with pytest.raises(TypeError):
+        a.extend(None)
+
+
+def test_extend_any_iterable():
+    a = List()
+    a.extend(List(1, 2))
+    a.extend((3,))
+    a.extend(x for x in [4, 5])
+    a.extend(range(6, 8))
+    a.extend({8})
+    assert a == [1, 2, 3, 4, 5, 6, 7, 8]
+    b = List[int].from_iter(x * x for x in range(4))
+    assert list(b) == [0, 1, 4, 9]
+    b.extend(iter([16]))
+    assert list(b) == [0, 1, 4, 9, 16]
+    with pytest.raises(TypeError):
+        List[int].from_iter(["x"])
+
+
+def test_insert():
+    a = List(0, 1, 2)
+    a.insert(0, -2)
+    a.insert(1, -1)
+    a.insert(2, 0)
+    assert a == [-2, -1, 0, 0, 1, 2]
+    b = List(a)
+    b.insert(-2, "foo")
+    b.insert(-200, "left")
+    b.insert(200, "right")
+    assert b == ["left", -2, -1, 0, 0, "foo", 1, 2, "right"]
+
+
+def test_pop():
+    a = List(-1, 0, 1)
+    assert a.pop() == 1
+    assert a == [-1, 0]
+    assert a.pop(0) == -1
+    assert a == [0]
+    with pytest.raises(IndexError):
+        a.pop(5)
+    assert a.pop(-1) == 0
+    with pytest.raises(IndexError):
+        a.pop()
+
+
+def test_remove():
+    a = List(0, 0, 1)
+    a.remove(1)
+    assert a == [0, 0]
+    a.remove(0)
+    assert a == [0]
+    a.remove(0)
+    assert a == []
+    with pytest.raises(ValueError):
+        a.remove(0)
+
+
+def test_remove_propagates_errors():
+    class BadExc(Exception):
+        pass
+
+    class BadCmp:
+        def __eq__(self, other):
+            if other == 2:
+                raise BadExc()
+            return False
+
+    a = List(0, 1, 2, 3)
+    with pytest.raises(BadExc):
+        a.remove(BadCmp())
+
+
+class AlwaysEq:
+    def __eq__(self, other):
+        return True
+
+
+class NeverEq:
+    def __eq__(self, other):
+        return False
+
+
+class BadExc(Exception):
+    pass
+
+
+class BadCmp:
+    def __eq__(self, other):
+        if other == 2:
+            raise BadExc()
+        return False
+
+
+def test_count():
+    a = List(0, 1, 2, 0, 1, 2, 0, 1, 2)
+    assert a.count(0) == 3
+    assert a.count(1) == 3
+    assert a.count(3) == 0
+    assert a.count(AlwaysEq()) == 9
+    assert List(AlwaysEq(), AlwaysEq()).count(1) == 2
+    assert List(AlwaysEq(), AlwaysEq()).count(NeverEq()) == 2
+    assert List(NeverEq(), NeverEq()).count(AlwaysEq()) == 0
+    with pytest.raises(TypeError):
+        a.count()
+    with pytest.raises(BadExc):
+        a.count(BadCmp())
+
+
+def test_index():
+    u = List(0, 1)
+    assert u.index(0) == 0
+    assert u.index(1) == 1
+    with pytest.raises(ValueError):
+        u.index(2)
+
+    u = List(-2, -1, 0, 0, 1, 2)
+    assert u.count(0) == 2
+    assert u.index(0) == 2
+    assert u.index(0, 2) == 2
+    assert u.index(-2, -10) == 0
+    assert u.index(0, 3) == 3
+    assert u.index(0, 3, 4) == 3
+    with pytest.raises(ValueError):
+        u.index(2, 0, -10)
+    with pytest.raises(TypeError):
+        u.index()
+    with pytest.raises(BadExc):
+        List(0, 1, 2).index(BadCmp())
+
+    a = List(-2, -1, 0, 0, 1, 2)
+    assert a.index(0, -4) == 2
+    assert a.index(0, -3) == 3
+    assert a.index(0, -3, -2) == 3
+    assert a.index(0, -4 * sys.maxsize, 4 * sys.maxsize) == 2
+    with pytest.raises(ValueError):
+        a.index(0, 4 * sys.maxsize, -4 * sys.maxsize)
+    with pytest.raises(TypeError):
+        a.index(0, "1")
+    assert List(NeverEq(), AlwaysEq()).index(0) == 1
+
+
+def test_count_and_index_wrong_type():
+    # A value that could never be an element is simply not found
+    a = List[int](1, 2, 1)
+    assert a.count("x") == 0
+    assert a.count(None) == 0
+    assert a.count(1) == 2
+    with pytest.raises(ValueError):
+        a.index("x")
+    b = List[rs.i8]([1, 2, 1], coerce=True)
+    assert b.count(300) == 0
+    assert b.count(None) == 0
+    assert b.count(1) == 2
+    assert b.index(2) == 1
+    with pytest.raises(ValueError):
+        b.index(300)
+    with pytest.raises(ValueError):
+        b.index(None)
+
+
+def test_contains():
+    a = List(0, 1, 2)
+    assert 0 in a
+    assert 3 not in a
+    nan = float("nan")
+    assert nan in List(nan)
+
+
+def test_reversed():
+    a = List(range(5))
+    assert list(reversed(a)) == [4, 3, 2, 1, 0]
+    assert list(reversed(List())) == []
+
+
+def test_iter_modification():
+    a = List(1, 2, 3)
+    with pytest.raises(RuntimeError):
+        for x in a:
+            a.append(x)
+    with pytest.raises(RuntimeError):
+        for x in reversed(a):
+            a.pop()
+    a = List(1, 2, 3)
+    it = iter(a)
+    next(it)
+    a[0] = 5
+    assert next(it) == 2
+
+
+def test_exhausted_iterator():
+    a = List(1, 2)
+    for make in [iter, reversed]:
+        it = make(a)
+        assert len(list(it)) == len(a)
+        a.append(3)
+        assert list(it) == []
+        assert it.__length_hint__() == 0
+        with pytest.raises(StopIteration):
+            next(it)
+    # An exhausted iterator no longer keeps the list alive
+    before = sys.getrefcount(a)
+    it = iter(a)
+    assert sys.getrefcount(a) == before + 1
+    list(it)
+    assert sys.getrefcount(a) == before
+
+
+def test_compare():
+    assert List() < List(0)
+    assert List(0) > List()
+    assert List(1, 2) < List(1, 2, 3)
+    assert List(1, 2) <= List(1, 2)
+    assert List(1, 3) > List(1, 2, 3)
+    assert List(1, 2) != List(1, 2, 3)
+    assert not (List(1, 2) == List(1, 2, 3))
+    assert List(1, 2) >= List(1)
+
+
+def test_reverse():
+    a = List(-2, -1, 0, 1, 2)
+    a.reverse()
+    assert a == [2, 1, 0, -1, -2]
+
+
+def test_clear_copy():
+    a = List(1, 2, 3)
+    b = a.copy()
+    a.clear()
+    assert a == []
+    assert b == [1, 2, 3]
+
+
+def test_typed():
+    a = List[int](1, 2)
+    with pytest.raises(TypeError):
+        a.append("x")
+    with pytest.raises(TypeError):
+        a.extend([3, "x"])
+    assert list(a) == [1, 2]
+    with pytest.raises(TypeError):
+        a[0:1] = [3, "x"]
+    assert list(a) == [1, 2]
+
+
+def test_bulk_mutations_are_atomic():
+    a = List[int](1, 2)
+    with pytest.raises(TypeError):
+        a.extend(x for x in [3, 4, "x", 5])
+    with pytest.raises(TypeError):
+        a.insert_many(1, [3, "x"])
+    with pytest.raises(TypeError):
+        a += [3, None]
+    with pytest.raises(TypeError):
+        a[::2] = ["x"]
+    assert list(a) == [1, 2]
+    a.insert_many(1, (7, 8))
+    assert list(a) == [1, 7, 8, 2]
+
+
+def test_add():
+    assert List(1, 2) + List(3) == [1, 2, 3]
+    assert List(1) + [2] == [1, 2]
+    assert [1] + List(2) == [1, 2]
+    with pytest.raises(TypeError):
+        List(1) + (2,)
+    assert repr(List[int](1) + [2]) == "List[int](1, 2)"
+    assert repr(List[int](1) + List[bool](True)) == "List[int](1, True)"
+    with pytest.raises(TypeError):
+        List[int](1) + ["x"]
+    with pytest.raises(TypeError):
+        List[int](1) + List[str]("x")
+
+
+def test_iadd():
+    a = List(1)
+    b = a
+    a += (2, 3)
+    assert a is b
+    assert a == [1, 2, 3]
+
+
+def test_repeat():
+    a = List(0, 1)
+    assert a * 2 == [0, 1, 0, 1]
+    assert 2 * a == [0, 1, 0, 1]
+    assert a * 0 == []
+    assert a * -1 == []
+    b = a
+    a *= 3
+    assert a is b
+    assert a == [0, 1, 0, 1, 0, 1]
+    with pytest.raises(MemoryError):
+        a * (2 ** 62)
+
+
+def test_sort():
+    a = List(3, 1, 2)
+    a.sort()
+    assert a == [1, 2, 3]
+    a.sort(reverse=True)
+    assert a == [3, 2, 1]
+    b = List("b", "A", "a", "B")
+    b.sort(key=str.lower)
+    assert b == ["A", "a", "b", "B"]
+    b.sort(key=str.lower, reverse=True)
+    assert b == ["b", "B", "A", "a"]
+    c = List(2.5, -1.0, 3.0, 0.0)
+    c.sort_unstable()
+    assert c == [-1.0, 0.0, 2.5, 3.0]
+
+
+def test_sort_generic_objects():
+    class Obj:
+        def __init__(self, v):
+            self.v = v
+
+        def __lt__(self, other):
+            return self.v < other.v
+
+    a = List(Obj(2), Obj(1), Obj(2), Obj(0))
+    first_two = a[0]
+    a.sort()
+    assert [o.v for o in a] == [0, 1, 2, 2]
+    assert a[2] is first_two
+
+
+def test_sort_stability():
+    pairs = List((i % 3, i) for i in range(30))
+    pairs.sort(key=lambda p: p[0])
+    assert list(pairs) == sorted(((i % 3, i) for i in range(30)), key=lambda p: p[0])
+    pairs.sort_by_key(lambda p: -p[0])
+    assert list(pairs) == sorted(((i % 3, i) for i in range(30)), key=lambda p: -p[0])
+
+
+def test_sort_propagates_errors():
+    a = List(1, "a", 2)
+    with pytest.raises(TypeError):
+        a.sort()
+    assert a == [1, "a", 2]
+
+    def bad_key(x):
+        raise ZeroDivisionError
+
+    with pytest.raises(ZeroDivisionError):
+        a.sort_by_cached_key(bad_key)
+
+
+def test_sorted_and_is_sorted():
+    a = List(3, 1, 2)
+    assert a.sorted() == [1, 2, 3]
+    assert a == [3, 1, 2]
+    assert not a.is_sorted()
+    assert a.sorted().is_sorted()
+    assert a.sorted(reverse=True).is_sorted(reverse=True)
+    assert List().is_sorted()
+
+
+@pytest.mark.parametrize("element_type, good, bad", [
+    (list[int], [[1, 2], []], [[1, "x"], (1,)]),
+    (int | str, [1, "a"], [1.0, None]),
+    ("Optional[float]", [1.0, None], [1]),
+    ("Literal['a', 1]", ["a", 1], ["b", True]),
+    (tuple[int, str], [(1, "a")], [(1, 2), (1,)]),
+    (tuple[int, ...], [(), (1, 2)], [(1, "x")]),
+    (dict[str, int], [{"a": 1}], [{"a": "b"}, {1: 1}]),
+    ("List[List[int]]", [[[1, 2]], []], [[[1, "x"]], [1]]),
+    ("Sequence[int]", [[1, 2], (3,), range(2)], ["abc", ("a",), [1, "x"], {1}]),
+    ("AbstractSet[str]", [{"a"}, frozenset()], [{1}, {"b": 1}.keys() | {2}]),
+])
+def test_nested_typing(element_type, good, bad):
+    import typing
+    if isinstance(element_type, str):
+        element_type = eval(element_type, vars(typing))
+    a = List[element_type](good)
+    assert list(a) == good
+    for item in bad:
+        with pytest.raises(TypeError):
+            a.append(item)
+    assert list(a) == good
+
+
+def test_nested_rustique_lists():
+    inner = List[int](1, 2)
+    outer = List[List[int]](inner, List(3))
+    assert len(outer) == 2
+    with pytest.raises(TypeError):
+        outer.append(List("x"))
+
+
+def test_subscription_is_cached():
+    assert List[int] is List[int]
+    assert List[int] == List[int]
+    assert List[int] != List[str]
+    assert repr(List[int]) == "List[int]"
+    assert repr(List[List[int]]) == "List[List[int]]"
+
+
+def test_subscription_cache_is_bounded():
+    kept = List[Literal["kept"]]
+    aliases = []
+    for i in range(1000):
+        aliases.append(List[Literal[i]])
+        assert List[Literal["kept"]] is kept
+    # Old specialisations are evicted but still compare equal
+    assert List[Literal[999]] is aliases[-1]
+    assert List[Literal[0]] is not aliases[0]
+    assert List[Literal[0]] == aliases[0]
+
+
+def test_subscription_introspection():
+    import typing
+    assert List[int].__origin__ is List
+    assert List[int].__args__ == (int,)
+    assert typing.get_origin(List[int]) is List
+    assert typing.get_args(List[int | None]) == (int | None,)
+
+    def f(x: List[int]) -> None:
+        pass
+
+    assert typing.get_type_hints(f)["x"] is List[int]
+
+
+def test_subscription_isinstance():
+    a = List[int](1, 2)
+    assert type(a) is List
+    assert isinstance(a, List)
+    assert isinstance(a, List[int])
+    assert not isinstance(a, List[str])
+    assert not isinstance(List(1), List[int])
+    assert issubclass(List[int], List)
+    assert issubclass(List[int], List[int])
+    assert not issubclass(List[int], List[str])
+
+
+def test_subscription_from_iter():
+    a = List[int].from_iter(range(3))
+    assert isinstance(a, List[int])
+    assert list(a) == [0, 1, 2]
+
+
+def test_coerce():
+    a = List[rs.i8](1, 2, 3, coerce=True)
+    assert all(isinstance(x, rs.i8) for x in a)
+    a.append(4)
+    a.insert(0, 0)
+    a[1] = 5
+    assert all(isinstance(x, rs.i8) for x in a)
+    assert a.count(5) == 1
+    assert a.index(5) == 1
+    assert isinstance(a, List[rs.i8])
+
+    with pytest.raises(OverflowError, match="300 at index 2"):
+        List[rs.i8](1, 2, 300, coerce=True)
+    with pytest.raises(OverflowError, match="at index 5"):
+        a.append(300)
+    with pytest.raises(TypeError):
+        List[rs.i8](1)
+
+    b = List[float].from_iter([1, 2], coerce=True)
+    assert [type(x) for x in b] == [float, float]
+    with pytest.raises(TypeError):
+        List[int | str](1, coerce=True)
+    with pytest.raises(ValueError):
+        List(1, coerce=True)
+
+
+def test_unboxed_primitives():
+    a = List[rs.i8]([3, -1, 2], coerce=True)
+    values = lambda lst: [x.value for x in lst]
+    assert values(a) == [3, -1, 2]
+    assert a[-1].value == 2
+    assert isinstance(a[1:], List[rs.i8])
+    assert values(a[::-1]) == [2, -1, 3]
+
+    a.append(7)
+    a.insert(0, 0)
+    a.extend([1, 1])
+    a.insert_many(1, (5, 6))
+    assert values(a) == [0, 5, 6, 3, -1, 2, 7, 1, 1]
+    a[0] = 4
+    a[1:3] = [9]
+    a[::2] = [0, 0, 0, 0]
+    assert values(a) == [0, 9, 0, -1, 0, 7, 0, 1]
+    del a[0]
+    del a[1::2]
+    assert values(a) == [9, -1, 7, 1]
+    assert a.pop().value == 1
+    a.remove(7)
+    assert values(a) == [9, -1]
+
+    b = List[rs.i8].from_iter(range(5), coerce=True) * 2
+    assert b.count(rs.i8(4)) == 2
+    assert b.index(rs.i8(3), 4) == 8
+    assert 2 in b and rs.i8(2) in b and 9 not in b
+    b.sort(reverse=True)
+    assert values(b) == [4, 4, 3, 3, 2, 2, 1, 1, 0, 0]
+    assert b.is_sorted(reverse=True)
+    assert values(b.sorted(key=lambda x: x.value % 3)) == [3, 3, 0, 0, 4, 4, 1, 1, 2, 2]
+    b.reverse()
+    assert b == List[rs.i8](0, 0, 1, 1, 2, 2, 3, 3, 4, 4, coerce=True)
+    assert b < List[rs.i8](0, 1, coerce=True)
+
+    c = a + List[rs.i8](8, coerce=True)
+    assert values(c) == [9, -1, 8]
+    assert values(List(c)) == [9, -1, 8]
+    # Items are stored unboxed, so each read returns a fresh copy
+    assert a[0] is not a[0]
+    item = rs.i8(4)
+    a.append(item)
+    item.value = 5
+    assert a[-1].value == 4
+    a[-1].value = 6
+    assert a[-1].value == 4
+    with pytest.raises(TypeError):
+        a.append(None)
+
+
+@pytest.mark.parametrize("name, data", [
+    ("u64", [3, 1, 2]),
+    ("f64", [2.5, -1.0, 0.0]),
+    ("f32", [2.5, -1.0, 0.0]),
+    ("bool", [True, False, True]),
+    ("char", ["c", "a", "b"]),
+])
+def test_unboxed_storage_kinds(name, data):
+    cls = getattr(rs, name)
+    a = List[cls](data, coerce=True)
+    assert len(a) == 3
+    assert all(isinstance(x, cls) for x in a)
+    assert a.count(a[0]) == data.count(data[0])
+    a.sort()
+    assert a == List[cls](sorted(data), coerce=True)
+    assert a.index(a[-1]) == sorted(data).index(sorted(data)[-1])
+
+
+def test_buffer_export():
+    import array
+    import io
+    import struct
+    a = List[rs.f64]([1.5, -2.0, 3.25], coerce=True)
+    m = memoryview(a)
+    assert (m.format, m.itemsize, m.shape, m.readonly) == ("d", 8, (3,), False)
+    assert m.tolist() == [1.5, -2.0, 3.25]
+    assert struct.unpack_from("3d", a) == (1.5, -2.0, 3.25)
+    assert array.array("d", bytes(a)).tolist() == [1.5, -2.0, 3.25]
+    m[0] = 9.0
+    assert a.index(rs.f64(9.0)) == 0
+    with pytest.raises(BufferError):
+        a.append(1.0)
+    with pytest.raises(BufferError):
+        a.sort()
+    m.release()
+    a.append(1.0)
+    assert len(a) == 4
+
+    b = List[rs.u8](bytes(4), coerce=True)
+    assert io.BytesIO(b"\x01\x02\x03\x04").readinto(b) == 4
+    assert [x for x in memoryview(b)] == [1, 2, 3, 4]
+
+    assert memoryview(List[rs.bool]([True, False], coerce=True)).readonly
+    with pytest.raises(BufferError):
+        memoryview(List(1, 2))
+    with pytest.raises(BufferError):
+        memoryview(List[rs.i128]([1], coerce=True))
+
+
+@pytest.mark.parametrize("code, name", [
+    ("b", "i8"), ("B", "u8"), ("h", "i16"), ("H", "u16"), ("i", "i32"), ("I", "u32"),
+    ("q", "i64"), ("Q", "u64"), ("f", "f32"), ("d", "f64"),
+])
+def test_buffer_formats(code, name):
+    import array
+    source = array.array(code, [1, 2, 3])
+    a = List.from_buffer(source)
+    assert isinstance(a, List[getattr(rs, name)])
+    assert memoryview(a).format == code
+    assert memoryview(a).tolist() == source.tolist()
+    assert bytes(a) == source.tobytes()
+
+
+def test_buffer_import():
+    import array
+    a = List[rs.i64].from_buffer(array.array("q", range(5)))
+    assert memoryview(a).tolist() == [0, 1, 2, 3, 4]
+    b = List.from_buffer(memoryview(bytes(range(6)))[::2])
+    assert memoryview(b).tolist() == [0, 2, 4]
+    c = List.from_buffer(memoryview(b"\x00\x01").cast("?"))
+    assert isinstance(c, List[rs.bool]) and len(c) == 2
+    with pytest.raises(ValueError):
+        List.from_buffer(memoryview(b"\x02").cast("?"))
+    with pytest.raises(BufferError):
+        List[rs.f64].from_buffer(array.array("q", [1]))
+    with pytest.raises(TypeError):
+        List.from_buffer(array.array("q", [1]), _type=int)
+    with pytest.raises(TypeError):
+        List.from_buffer([1, 2])
+
+
+
+def test_par_sort():
+    a = List[rs.i32]([5, -1, 3, 3, 0], coerce=True)
+    a.par_sort()
+    assert a == List[rs.i32]([-1, 0, 3, 3, 5], coerce=True)
+    a.par_sort_unstable(reverse=True)
+    assert a == List[rs.i32]([5, 3, 3, 0, -1], coerce=True)
+    with pytest.raises(ValueError, match="NaN"):
+        List[rs.f64]([1.0, float("nan")], coerce=True).par_sort()
+    with pytest.raises(TypeError):
+        List(3, 1, 2).par_sort()
+
+
+def test_par_methods_while_exported():
+    a = List[rs.i8]([3, 1, 2], coerce=True)
+    with memoryview(a) as view:
+        with pytest.raises(BufferError):
+            a.par_sort()
+        with pytest.raises(BufferError):
+            a.par_sort_unstable()
+        with pytest.raises(BufferError):
+            a.par_dedup()
+        assert view.tolist() == [3, 1, 2]
+        # Methods that only read work on a copy
+        assert a.par_sum() == 6
+        assert a.par_max().value == 3
+        assert [x.value for x in a.par_map("mul", 2)] == [6, 2, 4]
+    a.par_sort()
+    assert [x.value for x in a] == [1, 2, 3]
+
+
+def test_par_reductions():
+    a = List[rs.i64].from_iter(range(10_000), coerce=True)
+    assert a.par_sum() == sum(range(10_000))
+    assert List[rs.u8].from_iter([255] * 1000, coerce=True).par_sum() == 255_000
+    assert List[rs.bool]([True, False, True], coerce=True).par_sum() == 2
+    assert List[rs.i64](a.par_min(), a.par_max()) == List[rs.i64]([0, 9_999], coerce=True)
+    with pytest.raises(ValueError):
+        List[rs.i64]().par_min()
+    with pytest.raises(TypeError):
+        List[rs.char](["a"], coerce=True).par_sum()
+
+
+def test_par_sum_deterministic():
+    import math
+    floats = [0.1 * i for i in range(50_000)]
+    a = List[rs.f64].from_iter(floats, coerce=True)
+    totals = set()
+    for threads in (1, 2, 3, 8):
+        rs.set_num_threads(threads)
+        assert rs.get_num_threads() == threads
+        totals.add(a.par_sum())
+    rs.set_num_threads(0)
+    assert len(totals) == 1
+    assert math.isclose(totals.pop(), math.fsum(floats))
+
+
+def test_par_map():
+    i16 = lambda *values: List[rs.i16](values, coerce=True)
+    a = i16(1, -2, 3)
+    doubled = a.par_map("mul", 2)
+    assert doubled == i16(2, -4, 6) and isinstance(doubled, List[rs.i16])
+    assert a.par_map("abs") == i16(1, 2, 3)
+    assert a == i16(1, -2, 3)
+    roots = List[rs.f64]([4.0, 9.0], coerce=True).par_map("sqrt")
+    assert roots == List[rs.f64]([2.0, 3.0], coerce=True)
+    with pytest.raises(OverflowError, match="index 2"):
+        List[rs.i8]([1, 2, 100], coerce=True).par_map("mul", 2)
+    with pytest.raises(ZeroDivisionError):
+        a.par_map("div", 0)
+    with pytest.raises(TypeError):
+        a.par_map("sqrt")
+    with pytest.raises(TypeError):
+        a.par_map("add")
+    with pytest.raises(ValueError):
+        a.par_map("frobnicate")
+
+
+def test_par_filter_and_dedup():
+    u32 = lambda *values: List[rs.u32](values, coerce=True)
+    a = u32(5, 1, 1, 7, 7, 7, 2)
+    assert a.par_filter(">", 1) == u32(5, 7, 7, 7, 2)
+    assert a.par_filter("==", 7) == u32(7, 7, 7)
+    a.par_dedup()
+    assert a == u32(5, 1, 7, 2)
+    with pytest.raises(ValueError):
+        a.par_filter("~", 1)
+    with pytest.raises(TypeError):
+        List(1, 1).par_dedup()
+
+
+def test_elementwise_arithmetic():
+    values = lambda lst: memoryview(lst).tolist()
+    a = List[rs.i8]([1, 2, 100], coerce=True)
+    assert values(a.add(1)) == [2, 3, 101] and isinstance(a.add(1), List[rs.i8])
+    assert values(a.sub([1, 1, 1])) == [0, 1, 99]
+    assert values(a.mul(List[rs.i8]([1, 0, 1], coerce=True))) == [1, 0, 100]
+    assert values(a.floordiv(3)) == [0, 0, 33]
+    assert values(a.rem(3)) == [1, 2, 1]
+    assert values(List[rs.i8]([-7], coerce=True).floordiv(2)) == [-3]
+    assert values(a.bitand(1)) == [1, 0, 0]
+    assert values(a.bitor(8)) == [9, 10, 108]
+    assert values(a.bitxor(1)) == [0, 3, 101]
+    assert values(a.shr(1)) == [0, 1, 50]
+    assert values(List[rs.u8]([1, 3], coerce=True).shl(2)) == [4, 12]
+    quotient = a.truediv(2)
+    assert isinstance(quotient, List[rs.f64]) and values(quotient) == [0.5, 1.0, 50.0]
+    floats = List[rs.f64]([1.0, -3.5], coerce=True)
+    assert values(floats.mul(2)) == [2.0, -7.0]
+    assert values(floats.truediv(0)) == [float("inf"), float("-inf")]
+
+    with pytest.raises(OverflowError, match="index 2"):
+        a.add(100)
+    with pytest.raises(OverflowError):
+        a.shl(8)
+    with pytest.raises(ZeroDivisionError):
+        a.floordiv(0)
+    with pytest.raises(ValueError):
+        a.add([1, 2])
+    with pytest.raises(TypeError):
+        a.add(List[rs.i16]([1, 2, 3], coerce=True))
+    with pytest.raises(TypeError, match="bitand"):
+        floats.bitand(1)
+    with pytest.raises(TypeError):
+        List(1, 2).sub(1)
+
+
+def test_elementwise_float_division_follows_python():
+    values = lambda lst: memoryview(lst).tolist()
+    xs = [7.5, -7.5, 0.0, -0.0, 1e300, -1e-300, 0.1, 3.0]
+    for y in [2.0, -2.0, 0.1, -0.3, 1e-300, 3.0]:
+        a = List[rs.f64](xs, coerce=True)
+        assert values(a.floordiv(y)) == [x // y for x in xs]
+        assert values(a.rem(y)) == [x % y for x in xs]
+    assert values(List[rs.f32]([-3.5], coerce=True).floordiv(2)) == [-2.0]
+    assert values(List[rs.f32]([-3.5], coerce=True).rem(2)) == [0.5]
+
+
+def test_operators_are_not_elementwise():
+    a = List[rs.i32]([4, 8], coerce=True)
+    for op in (
+        lambda: a - 1,
+        lambda: 1 - a,
+        lambda: a / 2,
+        lambda: a // 2,
+        lambda: a % 2,
+        lambda: a & 1,
+        lambda: a | 1,
+        lambda: a ^ 1,
+        lambda: a << 1,
+        lambda: a >> 1,
+    ):
+        with pytest.raises(TypeError):
+            op()
+
+
+def test_elementwise_overflow_modes():
+    values = lambda lst: memoryview(lst).tolist()
+    a = List[rs.u8]([1, 200, 255], coerce=True)
+    assert values(a.wrapping_add(100)) == [101, 44, 99]
+    assert values(a.saturating_add(100)) == [101, 255, 255]
+    assert values(a.wrapping_sub(2)) == [255, 198, 253]
+    assert values(a.saturating_sub(2)) == [0, 198, 253]
+    assert values(a.wrapping_mul(2)) == [2, 144, 254]
+    assert values(a.saturating_mul(2)) == [2, 255, 255]
+    assert values(a.wrapping_shl(9)) == [2, 144, 254]
+    assert values(List[rs.i8]([-128], coerce=True).wrapping_floordiv(-1)) == [-128]
+    with pytest.raises(ZeroDivisionError):
+        a.wrapping_rem(0)
+    with pytest.raises(TypeError):
+        List[rs.f32]([1.0], coerce=True).wrapping_add(1)
+
+
+def test_sequence_operators_on_primitives():
+    values = lambda lst: memoryview(lst).tolist()
+    a = List[rs.i32]([1, 2, 3], coerce=True)
+    assert values(a + [4]) == [1, 2, 3, 4]
+    assert values([0] + a) == [0, 1, 2, 3]
+    assert values(a * 2) == [1, 2, 3, 1, 2, 3]
+    assert values(2 * a) == [1, 2, 3, 1, 2, 3]
+    b = a
+    b += [4]
+    b *= 2
+    assert b is a
+    assert values(a) == [1, 2, 3, 4, 1, 2, 3, 4]
+    with pytest.raises(TypeError):
+        a + 1
+    with pytest.raises(TypeError):
+        a * 1.5
+    with pytest.raises(TypeError):
+        a + List[rs.i16]([1], coerce=True)
+
+
+def test_masks():
+    values = lambda lst: memoryview(lst).tolist()
+    a = List[rs.i64]([5, -1, 3, 8], coerce=True)
+    mask = a > 2
+    assert isinstance(mask, List[rs.bool]) and values(mask) == [True, False, True, True]
+    assert values(a[mask]) == [5, 3, 8]
+    assert values(a[(a > 2).bitand(a < 8)]) == [5, 3]
+    assert values(a[(a == 5).bitor(a == -1)]) == [5, -1]
+    assert values(a.mask("<=", [5, 0, 0, 9])) == [True, True, False, True]
+    assert a == List[rs.i64]([5, -1, 3, 8], coerce=True)
+    assert not (a == "x")
+    assert List(1, 2, 3)[List[rs.bool]([True, False, True], coerce=True)] == [1, 3]
+    with pytest.raises(IndexError):
+        a[List[rs.bool]([True], coerce=True)]
+    with pytest.raises(ValueError):
+        a.mask("=~", 1)
+
+
+def test_sum_and_product():
+    a = List[rs.i8]([100, 100, -5], coerce=True)
+    assert a.sum() == 195
+    assert a.product() == -50_000
+    assert a.sum(accumulator=rs.i16) == 195
+    assert a.sum(accumulator=rs.f64) == 195.0
+    with pytest.raises(OverflowError):
+        a.sum(accumulator=rs.i8)
+    with pytest.raises(OverflowError):
+        a.sum(accumulator=rs.u16)
+    with pytest.raises(TypeError):
+        a.sum(accumulator=int)
+    with pytest.raises(TypeError):
+        List[rs.f64]([1.0], coerce=True).sum(accumulator=rs.i64)
+    assert List[rs.u64]([2**63, 2**63], coerce=True).sum() == 2**64
+    assert List[rs.bool]([True, True, False], coerce=True).sum() == 2
+    assert List[rs.f64]([0.1] * 10, coerce=True).sum() == 1.0
+    assert List[rs.f64]([1e100, 1.0, -1e100], coerce=True).sum() == 1.0
+
+
+def test_sum_fallback():
+    from fractions import Fraction
+    assert List(1, 2, 3).sum() == 6
+    assert List(Fraction(1, 3), Fraction(2, 3)).sum() == 1
+    assert List(2, 3).product() == 6
+    assert List().sum() == 0 and List().product() == 1
+    with pytest.raises(TypeError):
+        List("a").sum()
+
+
+def test_extremes():
+    a = List[rs.i32]([3, -1, 7, -1, 7], coerce=True)
+    assert (a.argmin(), a.argmax()) == (1, 2)
+    assert List[rs.i32](a.min(), a.max()) == List[rs.i32]([-1, 7], coerce=True)
+    assert List[rs.char](["b", "a"], coerce=True).argmin() == 1
+    assert List(3, 1, 2).min() == 1 and List(3, 1, 2).max() == 3
+    assert List("b", "a", "c").argmax() == 2
+    with pytest.raises(ValueError):
+        List[rs.f64]([1.0, float("nan")], coerce=True).max()
+    with pytest.raises(ValueError):
+        List().argmin()
+
+
+def test_statistics():
+    import math
+    import statistics
+    data = [2, 4, 4, 4, 5, 5, 7, 9]
+    a = List[rs.u16](data, coerce=True)
+    assert a.mean() == 5.0
+    assert a.variance() == 4.0 and a.std() == 2.0
+    assert math.isclose(a.variance(ddof=1), statistics.variance(data))
+    assert a.median() == 4.5
+    assert List[rs.i8]([3, 1, 2], coerce=True).median() == 2.0
+    assert a.quantile(0) == 2.0 and a.quantile(1) == 9.0
+    assert a.quantile(0.25) == 4.0
+    assert List(1.5, 2.5).mean() == 2.0
+    assert List(1, 2, 3, 4).median() == 2.5
+    with pytest.raises(ValueError):
+        a.quantile(1.5)
+    with pytest.raises(ValueError):
+        List[rs.u16]([1], coerce=True).variance(ddof=1)
+    with pytest.raises(ValueError):
+        List().median()
+    with pytest.raises(ValueError):
+        List[rs.f32]([float("nan")], coerce=True).median()
+    with pytest.raises(TypeError):
+        List[rs.char](["a"], coerce=True).mean()
+    with pytest.raises(TypeError):
+        List("a").mean()
+
+
+def test_histogram():
+    counts, edges = List[rs.f64]([1.0, 2.0, 2.0, 3.0, 10.0], coerce=True).histogram(3)
+    assert isinstance(counts, List[rs.u64]) and isinstance(edges, List[rs.f64])
+    assert memoryview(counts).tolist() == [4, 0, 1]
+    assert memoryview(edges).tolist() == [1.0, 4.0, 7.0, 10.0]
+    counts, edges = List(0, 5, 10, 11).histogram(2, range=(0, 10))
+    assert memoryview(counts).tolist() == [1, 2]
+    assert memoryview(List[rs.i8]([4, 4], coerce=True).histogram(1)[1]).tolist() == [3.5, 4.5]
+    with pytest.raises(ValueError):
+        List(1).histogram(0)
+    with pytest.raises(ValueError):
+        List(1).histogram(2, range=(1, 0))
+
+
+def test_map_filter_and_folds():
+    a = List[int](1, 2, 3, 4)
+    doubled = a.map(lambda x: x * 2)
+    assert doubled == List[int](2, 4, 6, 8)
+    assert isinstance(doubled, List[int])
+    assert not isinstance(a.map(str), List[int])
+    assert a.filter(lambda x: x % 2) == List[int](1, 3)
+    assert isinstance(a.filter(lambda x: x % 2), List[int])
+    assert a.filter_map(lambda x: x * 10 if x > 2 else None) == List[int](30, 40)
+    assert a.flat_map(lambda x: [x] * x) == List[int](1, 2, 2, 3, 3, 3, 4, 4, 4, 4)
+    assert a.fold(0, lambda acc, x: acc + x) == 10
+    assert a.reduce(max) == 4
+    assert List().reduce(max) is None
+    assert a.map(str) == List("1", "2", "3", "4")
+    assert a.scan(0, lambda acc, x: acc + x) == List[int](1, 3, 6, 10)
+    assert a.scan(0, lambda acc, x: None if x > 2 else acc + x) == List[int](1, 3)
+
+    small = List[rs.i8]([1, 2, 3], coerce=True)
+    assert isinstance(small.map(lambda x: x), List[rs.i8])
+    assert isinstance(small.map(lambda x: rs.i16(x.value)), List[rs.i16])
+    assert memoryview(small.map(lambda x: rs.i16(x.value))).tolist() == [1, 2, 3]
+
+
+def test_while_enumerate_zip():
+    a = List[int](1, 2, 5, 1)
+    assert a.take_while(lambda x: x < 3) == List[int](1, 2)
+    assert a.skip_while(lambda x: x < 3) == List[int](5, 1)
+    assert a.take_while(lambda x: True) == a
+    assert a.skip_while(lambda x: True) == List[int]()
+    assert a.enumerate() == List((0, 1), (1, 2), (2, 5), (3, 1))
+    assert a.zip("ab") == List((1, "a"), (2, "b"))
+    assert len(a.zip(range(10))) == 4
+
+
+def test_chunks_windows_and_groups():
+    a = List[int](1, 2, 3, 4, 5)
+    assert a.chunks(2) == List(List[int](1, 2), List[int](3, 4), List[int](5))
+    assert all(isinstance(chunk, List[int]) for chunk in a.chunks(2))
+    assert a.chunks_exact(2) == List(List[int](1, 2), List[int](3, 4))
+    assert a.windows(4) == List(List[int](1, 2, 3, 4), List[int](2, 3, 4, 5))
+    assert a.windows(6) == List()
+    assert List().chunks(3) == List()
+    for method in (a.chunks, a.chunks_exact, a.windows):
+        with pytest.raises(ValueError):
+            method(0)
+
+    b = List(1, 1, 2, 3, 3, 3)
+    assert b.chunk_by(lambda x, y: x == y) == List(List(1, 1), List(2), List(3, 3, 3))
+    assert b.group_by(lambda x, y: x <= y) == List([b])
+    assert List().chunk_by(lambda x, y: True) == List()
+    evens, odds = a.partition(lambda x: x % 2 == 0)
+    assert (evens, odds) == (List[int](2, 4), List[int](1, 3, 5))
+    assert isinstance(evens, List[int])
+
+
+def test_searching():
+    a = List(3, 1, 4, 1, 5)
+    assert a.position(lambda x: x == 1) == 1
+    assert a.rposition(lambda x: x == 1) == 3
+    assert a.position(lambda x: x > 9) is None
+    assert a.any(lambda x: x > 4)
+    assert not a.all(lambda x: x > 1)
+    assert List().all(lambda x: False)
+    assert a.find(lambda x: x > 3) == 4
+    assert a.find(lambda x: x > 9) is None
+    assert a.find_map(lambda x: str(x) if x > 3 else None) == "4"
+
+    calls = []
+    a.any(lambda x: calls.append(x) or x == 1)
+    assert calls == [3, 1]
+
+    words = List("bb", "a", "cc", "d")
+    assert words.min_by_key(len) == "a"
+    assert words.max_by_key(len) == "cc"
+    assert List().min_by_key(len) is None
+
+
+def test_callbacks_may_modify_list():
+    a = List(1, 2, 3)
+    assert a.map(lambda x: a.append(x) or x) == List(1, 2, 3)
+    assert a == List(1, 2, 3, 1, 2, 3)
@@ -0,0 +1,125 @@
+from fractions import Fraction
+
+import pytest
+import rustique as rs
+
+# Odd moduli take the Montgomery path, even ones plain u64 arithmetic, and
+# moduli beyond u64 the big-integer path
+MODULI = [7, 2**61 - 1, 2**64 - 59, 2**32, 2**64 - 2, 10**30 + 57]
+
+
+def invertible(a, m):
+    try:
+        pow(a, -1, m)
+        return True
+    except ValueError:
+        return False
+
+
+@pytest.mark.parametrize("m", MODULI)
+def test_arithmetic_matches_int(m):
+    for a in [0, 1, 3, m - 1, m // 2 + 1, -5, 10 * m + 4]:
+        for b in [1, 2, m - 1, 12345678901234567, -9]:
+            x = rs.ModInt(a, m)
+            assert x.value == a % m
+            assert (x + b).value == (a + b) % m
+            assert (b + x).value == (a + b) % m
+            assert (x - b).value == (a - b) % m
+            assert (b - x).value == (b - a) % m
+            assert (x * b).value == (a * b) % m
+            assert (b * x).value == (a * b) % m
+            assert (x + rs.ModInt(b, m)).value == (a + b) % m
+            assert (-x).value == -a % m
+            if invertible(b, m):
+                assert (x / b).value == a * pow(b, -1, m) % m
+            if invertible(a, m):
+                assert (b / x).value == b * pow(a, -1, m) % m
+
+
+@pytest.mark.parametrize("m", MODULI)
+def test_pow_matches_builtin(m):
+    for base in [0, 2, 3, m - 1, 98765]:
+        for exponent in [0, 1, 2, 65537, 2**64 + 13, 10**40]:
+            assert (rs.ModInt(base, m) ** exponent).value == pow(base, exponent, m)
+    if invertible(3, m):
+        assert (rs.ModInt(3, m) ** -5).value == pow(3, -5, m)
+        assert (rs.ModInt(3, m).inverse()).value == pow(3, -1, m)
+
+
+def test_inverse_errors():
+    with pytest.raises(ValueError):
+        rs.ModInt(2, 4).inverse()
+    with pytest.raises(ValueError):
+        rs.ModInt(2, 4) ** -1
+    with pytest.raises(ValueError):
+        rs.ModInt(1, 6) / 3
+    with pytest.raises(ValueError):
+        rs.ModInt(6, 10**30).inverse()
+    with pytest.raises(ValueError):
+        rs.ModInt(1, 0)
+    with pytest.raises(ValueError):
+        rs.ModInt(1, -7)
+    with pytest.raises(ValueError):
+        pow(rs.ModInt(2, 7), 3, 5)
+
+
+def test_mixed_moduli_and_foreign_types():
+    with pytest.raises(ValueError, match="Cannot mix moduli"):
+        rs.ModInt(1, 7) + rs.ModInt(1, 11)
+    with pytest.raises(TypeError):
+        rs.ModInt(1, 7) + 1.5
+    with pytest.raises(TypeError):
+        "a" * rs.ModInt(2, 7)
+    with pytest.raises(TypeError):
+        rs.ModInt(1, 7) - None
+    with pytest.raises(TypeError):
+        rs.ModInt(1, 7) < rs.ModInt(2, 7)
+    with pytest.raises(TypeError):
+        rs.ModInt(1, 7) / Fraction(1, 2)
+
+    class Reflected:
+        def __radd__(self, other):
+            return "radd"
+
+        def __rmul__(self, other):
+            return "rmul"
+
+    assert rs.ModInt(1, 7) + Reflected() == "radd"
+    assert rs.ModInt(1, 7) * Reflected() == "rmul"
+    assert (rs.ModInt(3, 7) + rs.i8(5)).value == 1
+
+
+def test_equality_and_hash():
+    assert rs.ModInt(3, 7) == rs.ModInt(10, 7)
+    assert rs.ModInt(3, 7) != rs.ModInt(3, 11)
+    assert hash(rs.ModInt(3, 7)) == hash(rs.ModInt(10, 7))
+    # Congruent integers are not equal: hash() could not agree with them all
+    assert rs.ModInt(3, 7) != 3
+    assert rs.ModInt(3, 7) != 10
+    assert not rs.ModInt(3, 7) == 3
+    assert len({rs.ModInt(3, 7), rs.ModInt(10, 7), rs.ModInt(3, 11)}) == 2
+
+
+def test_crt():
+    x = rs.ModInt.crt(rs.ModInt(2, 3), rs.ModInt(3, 5), rs.ModInt(2, 7))
+    assert (x.value, x.modulus) == (23, 105)
+    big = rs.ModInt.crt(rs.ModInt(1, 2**61 - 1), rs.ModInt(2, 2**64 - 59))
+    assert big.value % (2**61 - 1) == 1 and big.value % (2**64 - 59) == 2
+    assert big.modulus == (2**61 - 1) * (2**64 - 59)
+    # Moduli sharing a factor must agree on it
+    y = rs.ModInt.crt(rs.ModInt(3, 4), rs.ModInt(5, 6))
+    assert (y.value, y.modulus) == (11, 12)
+    with pytest.raises(ValueError, match="Inconsistent"):
+        rs.ModInt.crt(rs.ModInt(0, 4), rs.ModInt(1, 6))
+    with pytest.raises(ValueError):
+        rs.ModInt.crt()
+
+
+def test_conversions():
+    x = rs.ModInt(-1, 2**100)
+    assert int(x) == 2**100 - 1
+    assert int(x.to_int()) == 2**100 - 1
+    assert str(rs.ModInt(10, 7)) == "3"
+    assert repr(rs.ModInt(10, 7)) == "ModInt(3, 7)"
+    assert not rs.ModInt(7, 7)
+    assert rs.ModInt(1, 7)
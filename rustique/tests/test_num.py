@@ -0,0 +1,96 @@
+import pytest
+import rustique as rs
+
+SMALL = [0, 1, -1, 12345, -98765, 2**62, -(2**63)]
+BIG = [2**63, -(2**63) - 1, 2**200 + 17]
+
+
+def test_construction():
+    assert int(rs.num()) == 0
+    assert int(rs.num("123")) == 123
+    assert int(rs.num(-7.9)) == -7
+    assert int(rs.num(rs.int(2**100))) == 2**100
+    with pytest.raises(ValueError):
+        rs.num("x")
+    with pytest.raises(TypeError):
+        rs.num(object())
+
+
+def test_promotion_and_demotion():
+    a = rs.num(2**62)
+    assert a.is_small
+    b = a * 4
+    assert not b.is_small
+    assert int(b) == 2**64
+    assert (b // 4).is_small
+    assert not (rs.num(-(2**63)) * -1).is_small
+    assert int(-rs.num(-(2**63))) == 2**63
+    assert int(abs(rs.num(-(2**63)))) == 2**63
+    assert int(rs.num(3) << 70) == 3 << 70
+    assert int(rs.num(-1) >> 100) == -1
+
+
+@pytest.mark.parametrize("a", SMALL + BIG)
+def test_arithmetic_matches_int(a):
+    for b in [1, -1, 7, -3, 2**64 + 5]:
+        x = rs.num(a)
+        assert int(x + b) == a + b
+        assert int(b + x) == b + a
+        assert int(x - b) == a - b
+        assert int(b - x) == b - a
+        assert int(x * b) == a * b
+        assert int(b * x) == b * a
+        assert int(x // b) == a // b
+        assert int(x % b) == a % b
+        assert tuple(map(int, divmod(x, b))) == divmod(a, b)
+        assert x / b == a / b
+        assert int(x & b) == a & b
+        assert int(b | x) == b | a
+        assert int(b ^ x) == b ^ a
+        if a:
+            assert int(b // x) == b // a
+            assert int(b % x) == b % a
+            assert tuple(map(int, divmod(b, x))) == divmod(b, a)
+    assert int(~rs.num(a)) == ~a
+    assert rs.num(a).bit_length() == a.bit_length()
+    assert hash(rs.num(a)) == hash(a)
+
+
+def test_reflected_powers_and_shifts():
+    assert int(2 ** rs.num(3)) == 8
+    assert int(3 ** rs.num(80)) == 3**80
+    assert int(rs.num(2) ** 100) == 2**100
+    assert int(pow(rs.num(3), 100, 97)) == pow(3, 100, 97)
+    for base, exponent, modulus in [(3, 2, -5), (-3, 3, -7), (5, 1, -5), (3, -1, -5), (2**70, 3, -(2**65))]:
+        assert int(pow(rs.num(base), exponent, modulus)) == pow(base, exponent, modulus)
+        assert int(pow(base, rs.num(exponent), modulus)) == pow(base, exponent, modulus)
+    assert int(1 << rs.num(4)) == 16
+    assert int(1 << rs.num(80)) == 1 << 80
+    assert int(1024 >> rs.num(3)) == 128
+    with pytest.raises(ValueError):
+        1 << rs.num(-1)
+    with pytest.raises(ValueError):
+        2 ** rs.num(-1)
+
+
+def test_errors():
+    for op in (lambda a, b: a // b, lambda a, b: a % b, divmod, lambda a, b: a / b):
+        with pytest.raises(ZeroDivisionError):
+            op(rs.num(1), 0)
+        with pytest.raises(ZeroDivisionError):
+            op(1, rs.num(0))
+    with pytest.raises(ZeroDivisionError):
+        pow(rs.num(2), 3, 0)
+    with pytest.raises(OverflowError):
+        float(rs.num(10**400))
+    with pytest.raises(TypeError):
+        rs.num(1) + "1"
+
+
+def test_comparison():
+    assert rs.num(1) == 1
+    assert rs.num(2**100) == rs.int(2**100)
+    assert rs.num(1) < 1.5
+    assert rs.num(1) != float("nan")
+    assert rs.num(2**70) > rs.num(2**69)
+    assert rs.num(1) != "1"
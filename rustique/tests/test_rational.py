@@ -0,0 +1,127 @@
+import math
+import numbers
+from fractions import Fraction
+
+import pytest
+import rustique as rs
+
+VALUES = [Fraction(0), Fraction(1, 3), Fraction(-7, 4), Fraction(10**30, 7), Fraction(-1, 2**61 - 1)]
+
+
+def frac(r):
+    return Fraction(r.numerator, r.denominator)
+
+
+def test_construction_matches_fraction():
+    for literal in ["3/4", "-7", "1.25", "6.02e23", "-1_000.5", " 2E-3 ", "0.000", "+5/10"]:
+        assert frac(rs.Rational(literal)) == Fraction(literal)
+    assert frac(rs.Rational(6, -4)) == Fraction(6, -4)
+    assert frac(rs.Rational(0.1)) == Fraction(0.1)
+    assert frac(rs.Rational(Fraction(2, 3))) == Fraction(2, 3)
+    assert frac(rs.Rational(rs.Rational(1, 3), 2)) == Fraction(1, 6)
+    assert frac(rs.Rational(rs.i8(-5))) == -5
+    assert frac(rs.Rational("1e100000")) == 10**100000
+    long_fraction = "0." + "0" * 200_000 + "1"
+    assert frac(rs.Rational(long_fraction)) == Fraction(1, 10**200_001)
+
+
+def test_construction_errors():
+    for literal in ["", "abc", "1/", "1.2.3", "e5", "1e", "1.-5", "--1"]:
+        with pytest.raises(ValueError):
+            rs.Rational(literal)
+    with pytest.raises(ZeroDivisionError):
+        rs.Rational(1, 0)
+    with pytest.raises(ValueError):
+        rs.Rational(float("nan"))
+    with pytest.raises(OverflowError):
+        rs.Rational(float("inf"))
+    with pytest.raises(TypeError):
+        rs.Rational(object())
+
+
+def test_exponent_is_bounded():
+    with pytest.raises(ValueError, match="Exponent"):
+        rs.Rational("1e999999999")
+    with pytest.raises(ValueError, match="Exponent"):
+        rs.Rational("1e-100001")
+    with pytest.raises(ValueError):
+        rs.Rational("1e99999999999999999999999")
+    with pytest.raises(ValueError):
+        rs.Rational("1e-9223372036854775808")
+
+
+@pytest.mark.parametrize("a", VALUES)
+def test_arithmetic_matches_fraction(a):
+    for b in [Fraction(1), Fraction(-2, 3), Fraction(5, 2**64), 3]:
+        x = rs.Rational(a)
+        assert frac(x + b) == a + b
+        assert frac(b + x) == b + a
+        assert frac(x - b) == a - b
+        assert frac(b - x) == b - a
+        assert frac(x * b) == a * b
+        assert frac(b * x) == b * a
+        assert frac(x / b) == a / b
+        assert frac(x // b) == a // b
+        assert frac(x % b) == a % b
+        q, r = divmod(x, b)
+        assert (frac(q), frac(r)) == divmod(a, b)
+        if a:
+            assert frac(b / x) == b / a
+            assert frac(b // x) == b // a
+            assert frac(b % x) == b % a
+    assert frac(-rs.Rational(a)) == -a
+    assert frac(abs(rs.Rational(a))) == abs(a)
+    assert frac(rs.Rational(a) ** 3) == a**3
+    if a:
+        assert frac(rs.Rational(a) ** -2) == a**-2
+
+
+def test_rounding_and_conversions():
+    for a in VALUES + [Fraction(5, 2), Fraction(-5, 2), Fraction(7, 2)]:
+        x = rs.Rational(a)
+        assert float(x) == float(a)
+        assert int(x) == int(a)
+        assert math.floor(x) == math.floor(a)
+        assert math.ceil(x) == math.ceil(a)
+        assert math.trunc(x) == math.trunc(a)
+        assert round(x) == round(a)
+        assert frac(round(x, 2)) == round(a, 2)
+        assert x.as_integer_ratio() == a.as_integer_ratio()
+        assert x.to_fraction() == a
+    pi = Fraction(math.pi)
+    for m in [1, 10, 113, 10**6]:
+        assert frac(rs.Rational(pi).limit_denominator(m)) == pi.limit_denominator(m)
+    with pytest.raises(ValueError):
+        rs.Rational(1, 3).limit_denominator(0)
+
+
+def test_float_powers_and_errors():
+    assert rs.Rational(4) ** 0.5 == 2.0
+    assert rs.Rational(1, 4) ** Fraction(1, 2) == 0.5
+    with pytest.raises(ZeroDivisionError):
+        rs.Rational(0) ** -1
+    with pytest.raises(ZeroDivisionError):
+        rs.Rational(1) / 0
+    with pytest.raises(ZeroDivisionError):
+        1 // rs.Rational(0)
+    with pytest.raises(TypeError):
+        pow(rs.Rational(2), 2, 3)
+    with pytest.raises(TypeError):
+        rs.Rational(1) + "1"
+
+
+def test_comparison_and_hash():
+    for a in VALUES + [Fraction(1, 2**61 - 1) * 3, Fraction(2**61 - 1, 5)]:
+        x = rs.Rational(a)
+        assert hash(x) == hash(a)
+        assert x == a
+        assert a == x
+    assert hash(rs.Rational(5)) == hash(5)
+    assert hash(rs.Rational(0.5)) == hash(0.5)
+    assert rs.Rational(1, 3) < 0.34
+    assert rs.Rational(1, 3) > Fraction(1, 4)
+    assert rs.Rational(1, 2) == 0.5
+    assert rs.Rational(1, 3) != float("nan")
+    assert rs.Rational(1) != "1"
+    assert {rs.Rational(3, 6): "x"}[Fraction(1, 2)] == "x"
+    assert isinstance(rs.Rational(1), numbers.Rational)
@@ -0,0 +1,186 @@
+import struct
+from fractions import Fraction
+
+import pytest
+import rustique as rs
+
+TYPES = [(rs.u256, 256, False), (rs.i256, 256, True), (rs.u512, 512, False)]
+
+
+def bounds(bits, signed):
+    if signed:
+        return -(2 ** (bits - 1)), 2 ** (bits - 1) - 1
+    return 0, 2**bits - 1
+
+
+def samples(bits, signed):
+    lo, hi = bounds(bits, signed)
+    values = [0, 1, 2, 3, 2**64 - 1, 2**64, 2**127 + 5, hi, hi - 1, hi // 3]
+    if signed:
+        values += [-1, -2, -(2**64), lo, lo + 1, lo // 3]
+    return values
+
+
+def wrap(value, bits, signed):
+    value &= 2**bits - 1
+    if signed and value >= 2 ** (bits - 1):
+        value -= 2**bits
+    return value
+
+
+def trunc_rem(a, b):
+    q = abs(a) // abs(b)
+    return a - b * (q if (a < 0) == (b < 0) else -q)
+
+
+def float_div(a, b):
+    # Fraction division is exact and float(Fraction) rounds once to nearest
+    return float(Fraction(a, b))
+
+
+@pytest.mark.parametrize("cls, bits, signed", TYPES)
+def test_arithmetic_matches_int(cls, bits, signed):
+    lo, hi = bounds(bits, signed)
+    values = samples(bits, signed)
+    for a in values:
+        for b in values:
+            x, y = cls(a), cls(b)
+            for op, expected in [("add", a + b), ("sub", a - b), ("mul", a * b)]:
+                method = getattr(x, "checked_" + op)
+                if lo <= expected <= hi:
+                    assert method(y).value == expected
+                    assert getattr(x, "__" + op + "__")(y).value == expected
+                else:
+                    with pytest.raises(OverflowError):
+                        method(y)
+                    with pytest.raises(OverflowError):
+                        getattr(x, "__" + op + "__")(y)
+                assert getattr(x, "wrapping_" + op)(y).value == wrap(expected, bits, signed)
+                value, overflow = getattr(x, "overflowing_" + op)(y)
+                assert (value.value, overflow) == (wrap(expected, bits, signed), not lo <= expected <= hi)
+                assert getattr(x, "saturating_" + op)(y).value == min(max(expected, lo), hi)
+            if b:
+                assert x / y == float_div(a, b)
+                if lo <= a // b <= hi:
+                    assert (x // y).value == a // b
+                    assert x.checked_floordiv(y).value == a // b
+                    assert (x % y).value == a % b
+                    assert tuple(v.value for v in divmod(x, y)) == divmod(a, b)
+                    assert x.checked_rem(y).value == trunc_rem(a, b)
+                else:
+                    with pytest.raises(OverflowError):
+                        x // y
+                    with pytest.raises(OverflowError):
+                        x.checked_floordiv(y)
+
+
+@pytest.mark.parametrize("cls, bits, signed", TYPES)
+def test_float_conversion_rounds_to_nearest(cls, bits, signed):
+    for value in samples(bits, signed) + [2**53 + 1, 2**54 + 3, 2**200 + 2**147 + 1, 2**64 * 3 - 1]:
+        lo, hi = bounds(bits, signed)
+        if lo <= value <= hi:
+            assert float(cls(value)) == float(value)
+    # Quotients whose operands are not exact doubles must still round once
+    a, b = 408407740755353032575834893130694935563376699389215517290997, 597476113267526407154051140696402825
+    assert float_div(a, b) != float(a) / float(b)
+    assert cls(a) / cls(b) == float_div(a, b)
+    assert cls(2**100 + 1) / 1 == float(2**100 + 1)
+
+
+@pytest.mark.parametrize("cls, bits, signed", TYPES)
+def test_checked_methods_raise(cls, bits, signed):
+    lo, hi = bounds(bits, signed)
+    with pytest.raises(OverflowError):
+        cls(hi).checked_add(1)
+    with pytest.raises(OverflowError):
+        cls(lo).checked_sub(1)
+    with pytest.raises(OverflowError):
+        cls(2).checked_pow(bits)
+    assert cls(2).checked_pow(bits - 2).value == 2 ** (bits - 2)
+    with pytest.raises(OverflowError):
+        cls(1).checked_shl(bits)
+    with pytest.raises(OverflowError):
+        cls(1).checked_shr(bits)
+    assert cls(1).checked_shl(bits - 1).value == wrap(2 ** (bits - 1), bits, signed)
+    with pytest.raises(ZeroDivisionError):
+        cls(1).checked_floordiv(0)
+    with pytest.raises(ZeroDivisionError):
+        cls(1).checked_rem(0)
+    if signed:
+        with pytest.raises(OverflowError):
+            cls(lo).checked_neg()
+        with pytest.raises(OverflowError):
+            cls(lo).checked_floordiv(-1)
+        with pytest.raises(OverflowError):
+            cls(lo).checked_rem(-1)
+        assert cls(5).checked_neg().value == -5
+    else:
+        with pytest.raises(OverflowError):
+            cls(1).checked_neg()
+        assert cls(0).checked_neg().value == 0
+
+
+@pytest.mark.parametrize("cls, bits, signed", TYPES)
+def test_construction_and_bytes(cls, bits, signed):
+    lo, hi = bounds(bits, signed)
+    assert cls(hi).value == hi
+    assert cls(str(lo)).value == lo
+    assert cls("0x10").value == 16
+    with pytest.raises(OverflowError):
+        cls(hi + 1)
+    with pytest.raises(OverflowError):
+        cls(lo - 1)
+    with pytest.raises(TypeError):
+        cls(1.5)
+    assert cls.wrapping_from(hi + 1).value == lo
+    for value in samples(bits, signed):
+        x = cls(value)
+        assert x.to_bytes("big") == value.to_bytes(bits // 8, "big", signed=signed)
+        assert x.to_le_bytes() == value.to_bytes(bits // 8, "little", signed=signed)
+        assert cls.from_bytes(x.to_be_bytes()).value == value
+        limbs = struct.unpack("<%dQ" % (bits // 64), x.to_le_bytes())
+        assert sum(limb << (64 * i) for i, limb in enumerate(limbs)) == value % 2**bits
+        assert cls.from_hex(x.to_hex()).value == value
+        assert hash(x) == hash(value)
+        assert x == value and x == cls(value)
+        assert x.bit_length() == value.bit_length()
+    with pytest.raises(ValueError):
+        cls.from_bytes(b"\x00")
+
+
+@pytest.mark.parametrize("cls, bits, signed", TYPES)
+def test_bits_and_shifts(cls, bits, signed):
+    for value in samples(bits, signed):
+        x = cls(value)
+        assert (~x).value == wrap(~value, bits, signed)
+        assert (x & cls(2**64 + 5)).value == value & (2**64 + 5)
+        assert (x ^ 3).value == wrap(value ^ 3, bits, signed)
+        assert (x >> 3).value == value >> 3
+        assert (x << 1).value == wrap(value << 1, bits, signed)
+        assert x.count_ones() == bin(value % 2**bits).count("1")
+    with pytest.raises(OverflowError):
+        cls(1) << bits
+
+
+
+@pytest.mark.parametrize("cls, bits, signed", TYPES)
+def test_reflected_operators(cls, bits, signed):
+    assert (2 ** cls(3)).value == 8
+    assert (1 << cls(3)).value == 8
+    assert (256 >> cls(4)).value == 16
+    assert 1 / cls(4) == 0.25
+    q, r = divmod(7, cls(2))
+    assert (q.value, r.value) == (3, 1)
+    for value in samples(bits, signed):
+        if value:
+            assert 3 / cls(value) == 3 / value
+            q, r = divmod(3, cls(value))
+            assert (q.value, r.value) == divmod(3, value)
+    with pytest.raises(OverflowError):
+        2 ** cls(bits)
+    with pytest.raises(OverflowError):
+        1 << cls(bits)
+    with pytest.raises(ZeroDivisionError):
+        1 / cls(0)
+    with pytest.raises(ValueError):
+        pow(2, cls(3), 5)
@@ -0,0 +1,236 @@
+use std::sync::Arc;
+
+use pyo3::basic::CompareOp;
+use pyo3::exceptions::{PyAttributeError, PyTypeError};
+use pyo3::prelude::*;
+use pyo3::sync::GILOnceCell;
+use pyo3::types::{PyDict, PyTuple, PyType};
+use pyo3::IntoPyObjectExt;
+
+use super::list::List;
+use super::typing::ElementType;
+
+/// Recently used specialisations, oldest first, like `functools.lru_cache`
+static ALIASES: GILOnceCell<Py<PyDict>> = GILOnceCell::new();
+const MAX_ALIASES: usize = 256;
+static GENERIC_ALIAS: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+
+/// `List[T]`: a List class specialised to an element type.
+///
+/// Like `list[int]`, calling it builds an ordinary `List`, here with `_type`
+/// set to `T`. It reports `types.GenericAlias` as its `__class__` so that
+/// `typing.get_origin` and `typing.get_args` understand it. Unlike
+/// `list[int]` it supports `isinstance`, which compares the declared element
+/// type of the list.
+#[pyclass(name = "ListAlias", frozen)]
+pub struct ListAlias {
+    origin: Py<PyType>,
+    item: PyObject,
+    pub(crate) element: Arc<ElementType>,
+}
+
+impl ListAlias {
+    /// The cached specialisation of `cls` for `item`.
+    pub(crate) fn specialise(cls: &Bound<'_, PyType>, item: &Bound<'_, PyAny>) -> PyResult<Py<ListAlias>> {
+        let py = cls.py();
+        // `typing` re-subscripts aliases with their `__args__` tuple
+        let item = &match item.downcast::<PyTuple>() {
+            Ok(args) if args.len() == 1 => args.get_item(0)?,
+            Ok(args) => {
+                return Err(PyTypeError::new_err(format!(
+                    "{} takes exactly one type argument, got {}",
+                    cls.name()?,
+                    args.len()
+                )));
+            }
+            Err(_) => item.clone(),
+        };
+
+        let cache = ALIASES.get_or_init(py, || PyDict::new(py).unbind()).bind(py);
+        let key = PyTuple::new(py, [cls.as_any(), item])?;
+
+        // Unhashable expressions, e.g. `Annotated` with a list as metadata, are not cached
+        let hashable = key.hash().is_ok();
+        if hashable {
+            if let Some(alias) = cache.get_item(&key)? {
+                cache.del_item(&key)?;
+                cache.set_item(&key, &alias)?;
+                return Ok(alias.downcast_into::<ListAlias>()?.unbind());
+            }
+        }
+
+        let alias = Py::new(py, ListAlias {
+            origin: cls.clone().unbind(),
+            item: item.clone().unbind(),
+            element: Arc::new(ElementType::compile(item)?),
+        })?;
+        if hashable {
+            if cache.len() >= MAX_ALIASES {
+                if let Some((oldest, _)) = cache.iter().next() {
+                    cache.del_item(oldest)?;
+                }
+            }
+            cache.set_item(key, &alias)?;
+        }
+        Ok(alias)
+    }
+
+    fn same(&self, py: Python, other: &ListAlias) -> PyResult<bool> {
+        Ok(self.origin.is(&other.origin) && self.element.same(py, &other.element)?)
+    }
+}
+
+#[pymethods]
+impl ListAlias {
+    #[getter]
+    fn __origin__(&self, py: Python) -> Py<PyType> {
+        self.origin.clone_ref(py)
+    }
+
+    #[getter]
+    fn __args__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [self.item.bind(py)])
+    }
+
+    #[getter]
+    fn __parameters__<'py>(&self, py: Python<'py>) -> Bound<'py, PyTuple> {
+        PyTuple::empty(py)
+    }
+
+    #[getter]
+    fn __unpacked__(&self) -> bool {
+        false
+    }
+
+    #[getter]
+    fn __class__<'py>(&self, py: Python<'py>) -> PyResult<&Bound<'py, PyType>> {
+        GENERIC_ALIAS.import(py, "types", "GenericAlias")
+    }
+
+    /// Lets `issubclass(List[int], List)` hold.
+    #[getter]
+    fn __bases__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [self.origin.bind(py)])
+    }
+
+    fn __mro_entries__<'py>(&self, py: Python<'py>, _bases: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, [self.origin.bind(py)])
+    }
+
+    #[pyo3(signature = (*args, **kwargs))]
+    fn __call__(&self, args: &Bound<'_, PyTuple>, kwargs: Option<&Bound<'_, PyDict>>) -> PyResult<PyObject> {
+        let py = args.py();
+        if self.origin.bind(py).is(&py.get_type::<List>()) {
+            let coerce = match kwargs {
+                None => Some(false),
+                Some(kwargs) if kwargs.len() == 1 => kwargs.get_item("coerce")?.map(|c| c.is_truthy()).transpose()?,
+                Some(_) => None,
+            };
+            if let Some(coerce) = coerce {
+                let element = match coerce {
+                    true => Arc::new(self.element.coercing(py)?),
+                    false => self.element.clone(),
+                };
+                let list = List::from_values(args, Some(element))?;
+                return Py::new(py, list)?.into_py_any(py);
+            }
+        }
+
+        let kwargs = match kwargs {
+            Some(kwargs) => kwargs.copy()?,
+            None => PyDict::new(py),
+        };
+        kwargs.set_item("_type", &self.item)?;
+        Ok(self.origin.bind(py).call(args, Some(&kwargs))?.unbind())
+    }
+
+    #[pyo3(signature = (iterable, coerce=false))]
+    fn from_iter<'py>(&self, iterable: &Bound<'py, PyAny>, coerce: bool) -> PyResult<Bound<'py, PyAny>> {
+        let py = iterable.py();
+        let kwargs = PyDict::new(py);
+        kwargs.set_item("_type", &self.item)?;
+        if coerce {
+            kwargs.set_item("coerce", true)?;
+        }
+        self.origin.bind(py).call_method("from_iter", (iterable,), Some(&kwargs))
+    }
+
+    fn from_buffer<'py>(&self, buffer: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        let py = buffer.py();
+        let kwargs = PyDict::new(py);
+        kwargs.set_item("_type", &self.item)?;
+        self.origin.bind(py).call_method("from_buffer", (buffer,), Some(&kwargs))
+    }
+
+    /// A list is an instance when its declared element type is `T`.
+    fn __instancecheck__(&self, instance: &Bound<'_, PyAny>) -> PyResult<bool> {
+        let py = instance.py();
+        if !instance.is_instance(self.origin.bind(py))? {
+            return Ok(false);
+        }
+        let list = instance.downcast::<List>()?.try_borrow()?;
+        match list._type {
+            Some(ref t) => t.same(py, &self.element),
+            None => Ok(false),
+        }
+    }
+
+    fn __subclasscheck__(&self, subclass: &Bound<'_, PyAny>) -> PyResult<bool> {
+        match subclass.downcast::<ListAlias>() {
+            Ok(other) => {
+                let other = other.get();
+                Ok(other.origin.bind(subclass.py()).is_subclass(self.origin.bind(subclass.py()))?
+                    && self.element.same(subclass.py(), &other.element)?)
+            }
+            Err(_) => Ok(false),
+        }
+    }
+
+    /// Forward class attributes such as classmethods to the origin, like `GenericAlias`.
+    fn __getattr__<'py>(&self, py: Python<'py>, name: &str) -> PyResult<Bound<'py, PyAny>> {
+        if name.starts_with("__") && name.ends_with("__") {
+            return Err(PyAttributeError::new_err(name.to_string()));
+        }
+        self.origin.bind(py).getattr(name)
+    }
+
+    /// `List[int] | None`, as a `typing.Union`.
+    fn __or__<'py>(slf: &Bound<'py, Self>, other: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        let union = slf.py().import("typing")?.getattr("Union")?;
+        union.get_item((slf, other))
+    }
+
+    fn __ror__<'py>(slf: &Bound<'py, Self>, other: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        let union = slf.py().import("typing")?.getattr("Union")?;
+        union.get_item((other, slf))
+    }
+
+    fn __repr__(&self, py: Python) -> PyResult<String> {
+        Ok(format!("{}[{}]", self.origin.bind(py).name()?, self.element.name(py)?))
+    }
+
+    fn __richcmp__(&self, py: Python, other: &Bound<'_, PyAny>, op: CompareOp) -> PyResult<PyObject> {
+        let Ok(other) = other.downcast::<ListAlias>() else {
+            return Ok(py.NotImplemented());
+        };
+        match op {
+            CompareOp::Eq => self.same(py, other.get())?.into_py_any(py),
+            CompareOp::Ne => (!self.same(py, other.get())?).into_py_any(py),
+            _ => Ok(py.NotImplemented()),
+        }
+    }
+
+    fn __hash__(&self, py: Python) -> PyResult<isize> {
+        PyTuple::new(py, [self.origin.bind(py).as_any(), self.item.bind(py)])?.hash()
+    }
+
+    fn __reduce__<'py>(&self, py: Python<'py>) -> PyResult<(Bound<'py, PyAny>, (Py<PyType>, PyObject))> {
+        let getitem = py.import("operator")?.getattr("getitem")?;
+        Ok((getitem, (self.origin.clone_ref(py), self.item.clone_ref(py))))
+    }
+}
+
+pub fn register_alias(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<ListAlias>()?;
+    Ok(())
+}
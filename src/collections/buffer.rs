@@ -0,0 +1,265 @@
+use std::ffi::{c_char, c_int, c_void, CStr};
+use std::mem::MaybeUninit;
+use std::ptr;
+
+use pyo3::exceptions::{PyBufferError, PyValueError};
+use pyo3::ffi;
+use pyo3::prelude::*;
+
+use super::list::List;
+use super::storage::{Kind, Storage};
+
+/// The `struct` format code a List of `kind` is exported with.
+fn format(kind: Kind) -> Option<&'static CStr> {
+    match kind {
+        Kind::I8 => Some(c"b"),
+        Kind::I16 => Some(c"h"),
+        Kind::I32 => Some(c"i"),
+        Kind::I64 => Some(c"q"),
+        Kind::ISize => Some(c"n"),
+        Kind::U8 => Some(c"B"),
+        Kind::U16 => Some(c"H"),
+        Kind::U32 => Some(c"I"),
+        Kind::U64 => Some(c"Q"),
+        Kind::USize => Some(c"N"),
+        Kind::F32 => Some(c"f"),
+        Kind::F64 => Some(c"d"),
+        Kind::Bool => Some(c"?"),
+        // UCS-4, as used by NumPy for `<U1`
+        Kind::Char => Some(c"w"),
+        Kind::I128 | Kind::U128 => None,
+    }
+}
+
+/// The sort of number and its size in bytes, so that e.g. a `'l'` buffer
+/// can fill a `List[i64]` where `long` is 64 bits wide.
+fn layout(kind: Kind) -> (u8, usize) {
+    match kind {
+        Kind::I8 | Kind::I16 | Kind::I32 | Kind::I64 | Kind::I128 | Kind::ISize => (b'i', kind_size(kind)),
+        Kind::U8 | Kind::U16 | Kind::U32 | Kind::U64 | Kind::U128 | Kind::USize => (b'u', kind_size(kind)),
+        Kind::F32 | Kind::F64 => (b'f', kind_size(kind)),
+        Kind::Bool => (b'?', 1),
+        Kind::Char => (b'w', 4),
+    }
+}
+
+fn kind_size(kind: Kind) -> usize {
+    match kind {
+        Kind::I8 | Kind::U8 | Kind::Bool => 1,
+        Kind::I16 | Kind::U16 => 2,
+        Kind::I32 | Kind::U32 | Kind::F32 | Kind::Char => 4,
+        Kind::I64 | Kind::U64 | Kind::F64 => 8,
+        Kind::I128 | Kind::U128 => 16,
+        Kind::ISize | Kind::USize => std::mem::size_of::<usize>(),
+    }
+}
+
+/// The kind matching a `struct` format string, or `None` for formats
+/// without one, including those in non-native byte order.
+fn parse_format(format: &CStr, itemsize: usize) -> Option<Kind> {
+    let (order, code) = match format.to_bytes() {
+        [code] => (b'@', *code),
+        [order, code] => (*order, *code),
+        _ => return None,
+    };
+    let native = match order {
+        b'@' | b'=' => true,
+        b'<' => cfg!(target_endian = "little"),
+        b'>' | b'!' => cfg!(target_endian = "big"),
+        _ => false,
+    };
+    if !native {
+        return None;
+    }
+
+    let sized = |kinds: [Kind; 4]| kinds.into_iter().find(|&k| kind_size(k) == itemsize);
+    match code {
+        b'n' if order == b'@' => Some(Kind::ISize),
+        b'N' if order == b'@' => Some(Kind::USize),
+        b'b' | b'h' | b'i' | b'l' | b'q' | b'n' => sized([Kind::I8, Kind::I16, Kind::I32, Kind::I64]),
+        b'B' | b'c' | b'H' | b'I' | b'L' | b'Q' | b'N' => sized([Kind::U8, Kind::U16, Kind::U32, Kind::U64]),
+        b'f' if itemsize == 4 => Some(Kind::F32),
+        b'd' if itemsize == 8 => Some(Kind::F64),
+        b'?' if itemsize == 1 => Some(Kind::Bool),
+        b'w' if itemsize == 4 => Some(Kind::Char),
+        _ => None,
+    }
+}
+
+/// A buffer obtained from another object, released when dropped.
+pub(crate) struct BufferView(Box<ffi::Py_buffer>);
+
+impl BufferView {
+    pub(crate) fn get(obj: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let mut view = Box::new(MaybeUninit::<ffi::Py_buffer>::uninit());
+        // SAFETY: on success `PyObject_GetBuffer` initialises the view
+        unsafe {
+            if ffi::PyObject_GetBuffer(obj.as_ptr(), view.as_mut_ptr(), ffi::PyBUF_FULL_RO) == -1 {
+                return Err(PyErr::fetch(obj.py()));
+            }
+            Ok(BufferView(view.assume_init()))
+        }
+    }
+
+    fn itemsize(&self) -> usize {
+        self.0.itemsize as usize
+    }
+
+    /// The kind of the buffer's items, from its format.
+    pub(crate) fn kind(&self) -> PyResult<Kind> {
+        // A missing format means unsigned bytes
+        let format = match self.0.format.is_null() {
+            true => c"B",
+            // SAFETY: a non-null format is a NUL-terminated string owned by the view
+            false => unsafe { CStr::from_ptr(self.0.format) },
+        };
+        parse_format(format, self.itemsize()).ok_or_else(|| {
+            PyBufferError::new_err(format!(
+                "Unsupported buffer format {:?} with item size {}",
+                format.to_string_lossy(),
+                self.itemsize()
+            ))
+        })
+    }
+
+    /// Copy the buffer into storage of `kind` in C order, in a single pass.
+    pub(crate) fn to_storage(&self, py: Python, kind: Kind) -> PyResult<Storage> {
+        let source = self.kind()?;
+        if layout(source) != layout(kind) {
+            return Err(PyBufferError::new_err(format!(
+                "Buffer items of {} bytes ({:?}) cannot fill a List[{}]",
+                self.itemsize(),
+                format(source).map(|f| f.to_string_lossy()).unwrap_or_default(),
+                kind.class(py).name()?
+            )));
+        }
+        let len = self.0.len as usize / self.itemsize().max(1);
+
+        macro_rules! copy {
+            ($($variant:ident),*) => {
+                match kind {
+                    $(Kind::$variant => Storage::$variant(self.copy(py, len)?),)*
+                    Kind::Bool => {
+                        let bytes: Vec<u8> = self.copy(py, len)?;
+                        if let Some(i) = bytes.iter().position(|&b| b > 1) {
+                            return Err(PyValueError::new_err(format!(
+                                "Invalid bool {} at index {} in buffer", bytes[i], i
+                            )));
+                        }
+                        Storage::Bool(bytes.into_iter().map(|b| b == 1).collect())
+                    }
+                    Kind::Char => {
+                        let codes: Vec<u32> = self.copy(py, len)?;
+                        let chars = codes
+                            .iter()
+                            .enumerate()
+                            .map(|(i, &c)| char::from_u32(c).ok_or_else(|| {
+                                PyValueError::new_err(format!("Invalid char {:#x} at index {} in buffer", c, i))
+                            }))
+                            .collect::<PyResult<_>>()?;
+                        Storage::Char(chars)
+                    }
+                }
+            };
+        }
+        Ok(copy!(I8, I16, I32, I64, I128, ISize, U8, U16, U32, U64, U128, USize, F32, F64))
+    }
+
+    /// The `len` items of the buffer, which must be of `T`'s layout.
+    fn copy<T: Copy>(&self, py: Python, len: usize) -> PyResult<Vec<T>> {
+        let mut items = Vec::<T>::with_capacity(len);
+        let view: *const ffi::Py_buffer = &*self.0;
+        // SAFETY: `items` has room for the buffer's `len * size_of::<T>()`
+        // bytes, which are valid `T`s as the layouts match
+        unsafe {
+            if ffi::PyBuffer_ToContiguous(items.as_mut_ptr().cast(), view as *mut _, self.0.len, b'C' as c_char) == -1 {
+                return Err(PyErr::fetch(py));
+            }
+            items.set_len(len);
+        }
+        Ok(items)
+    }
+}
+
+impl Drop for BufferView {
+    fn drop(&mut self) {
+        // SAFETY: the view was filled by `PyObject_GetBuffer` and the GIL is
+        // held wherever a `BufferView` lives
+        unsafe { ffi::PyBuffer_Release(&mut *self.0) }
+    }
+}
+
+/// Fill `view` with the unboxed items of `slf`, without copying.
+///
+/// `bool` and `char` buffers are read-only, since arbitrary bytes are not
+/// valid values of those types.
+///
+/// # Safety
+///
+/// `view` must be null or point to a `Py_buffer` to fill.
+pub(crate) unsafe fn export(slf: &Bound<'_, List>, view: *mut ffi::Py_buffer, flags: c_int) -> PyResult<()> {
+    if view.is_null() {
+        return Err(PyBufferError::new_err("View is null"));
+    }
+    let mut list = slf.try_borrow_mut()?;
+    let Some(kind) = list.data.kind() else {
+        return Err(PyBufferError::new_err(
+            "Only Lists of Rustique primitives support the buffer protocol",
+        ));
+    };
+    let py = slf.py();
+    let Some(format) = format(kind) else {
+        return Err(PyBufferError::new_err(format!(
+            "List[{}] has no buffer format",
+            kind.class(py).name()?
+        )));
+    };
+    let readonly = matches!(kind, Kind::Bool | Kind::Char);
+    if readonly && (flags & ffi::PyBUF_WRITABLE) == ffi::PyBUF_WRITABLE {
+        return Err(PyBufferError::new_err(format!(
+            "List[{}] buffers are read-only",
+            kind.class(py).name()?
+        )));
+    }
+
+    let (buf, len, itemsize) = list.data.raw();
+    // Shape and strides live until `release`
+    let dims = Box::into_raw(Box::new([len as isize, itemsize as isize])) as *mut isize;
+
+    (*view).obj = slf.clone().into_any().into_ptr();
+    (*view).buf = buf;
+    (*view).len = (len * itemsize) as isize;
+    (*view).readonly = readonly as c_int;
+    (*view).itemsize = itemsize as isize;
+    (*view).format = if (flags & ffi::PyBUF_FORMAT) == ffi::PyBUF_FORMAT {
+        format.as_ptr() as *mut c_char
+    } else {
+        ptr::null_mut()
+    };
+    (*view).ndim = 1;
+    (*view).shape = if (flags & ffi::PyBUF_ND) == ffi::PyBUF_ND {
+        dims
+    } else {
+        ptr::null_mut()
+    };
+    (*view).strides = if (flags & ffi::PyBUF_STRIDES) == ffi::PyBUF_STRIDES {
+        dims.add(1)
+    } else {
+        ptr::null_mut()
+    };
+    (*view).suboffsets = ptr::null_mut();
+    (*view).internal = dims as *mut c_void;
+
+    list.exports += 1;
+    Ok(())
+}
+
+/// Undo `export` for `view`.
+///
+/// # Safety
+///
+/// `view` must have been filled by `export` for `list`.
+pub(crate) unsafe fn release(list: &mut List, view: *mut ffi::Py_buffer) {
+    drop(Box::from_raw((*view).internal as *mut [isize; 2]));
+    list.exports -= 1;
+}
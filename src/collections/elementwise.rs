@@ -0,0 +1,357 @@
+use pyo3::exceptions::{PyOverflowError, PyTypeError, PyValueError, PyZeroDivisionError};
+use pyo3::prelude::*;
+use pyo3::types::PyList;
+
+use super::list::List;
+use super::parallel::Predicate;
+use super::storage::{each_native, Kind, Native, Storage};
+
+/// A binary operator applied item by item.
+#[derive(Clone, Copy)]
+pub(crate) enum BinOp {
+    Add,
+    Sub,
+    Mul,
+    TrueDiv,
+    FloorDiv,
+    Rem,
+    And,
+    Or,
+    Xor,
+    Shl,
+    Shr,
+}
+
+impl BinOp {
+    fn name(self) -> &'static str {
+        match self {
+            BinOp::Add => "add",
+            BinOp::Sub => "sub",
+            BinOp::Mul => "mul",
+            BinOp::TrueDiv => "truediv",
+            BinOp::FloorDiv => "floordiv",
+            BinOp::Rem => "rem",
+            BinOp::And => "bitand",
+            BinOp::Or => "bitor",
+            BinOp::Xor => "bitxor",
+            BinOp::Shl => "shl",
+            BinOp::Shr => "shr",
+        }
+    }
+}
+
+/// What happens to integer results that do not fit the element type.
+#[derive(Clone, Copy)]
+pub(crate) enum Mode {
+    Checked,
+    Wrapping,
+    Saturating,
+}
+
+impl Mode {
+    fn prefix(self) -> &'static str {
+        match self {
+            Mode::Checked => "",
+            Mode::Wrapping => "wrapping_",
+            Mode::Saturating => "saturating_",
+        }
+    }
+}
+
+enum Fault {
+    Overflow,
+    ZeroDivision,
+}
+
+/// Arithmetic on pairs of unboxed items, following the semantics of the
+/// primitive's own operators.
+trait Arith: Native {
+    /// The item type of `/` results.
+    type Quotient: Native;
+
+    fn supports(op: BinOp, mode: Mode) -> bool;
+
+    /// `a <op> b` for any supported `op` but `TrueDiv`.
+    fn binary(op: BinOp, mode: Mode, a: Self, b: Self) -> Result<Self, Fault>;
+
+    fn quotient(a: Self, b: Self) -> Result<Self::Quotient, Fault>;
+}
+
+macro_rules! int_arith {
+    ($($t:ty),*) => {$(
+        impl Arith for $t {
+            type Quotient = f64;
+
+            fn supports(op: BinOp, mode: Mode) -> bool {
+                match mode {
+                    Mode::Checked => true,
+                    Mode::Wrapping => matches!(
+                        op,
+                        BinOp::Add | BinOp::Sub | BinOp::Mul | BinOp::FloorDiv | BinOp::Rem | BinOp::Shl | BinOp::Shr
+                    ),
+                    Mode::Saturating => matches!(op, BinOp::Add | BinOp::Sub | BinOp::Mul),
+                }
+            }
+
+            fn binary(op: BinOp, mode: Mode, a: Self, b: Self) -> Result<Self, Fault> {
+                if matches!(op, BinOp::FloorDiv | BinOp::Rem) && b == 0 {
+                    return Err(Fault::ZeroDivision);
+                }
+                let result = match (op, mode) {
+                    (BinOp::Add, Mode::Checked) => a.checked_add(b),
+                    (BinOp::Add, Mode::Wrapping) => Some(a.wrapping_add(b)),
+                    (BinOp::Add, Mode::Saturating) => Some(a.saturating_add(b)),
+                    (BinOp::Sub, Mode::Checked) => a.checked_sub(b),
+                    (BinOp::Sub, Mode::Wrapping) => Some(a.wrapping_sub(b)),
+                    (BinOp::Sub, Mode::Saturating) => Some(a.saturating_sub(b)),
+                    (BinOp::Mul, Mode::Checked) => a.checked_mul(b),
+                    (BinOp::Mul, Mode::Wrapping) => Some(a.wrapping_mul(b)),
+                    (BinOp::Mul, Mode::Saturating) => Some(a.saturating_mul(b)),
+                    (BinOp::FloorDiv, Mode::Checked) => a.checked_div(b),
+                    (BinOp::FloorDiv, _) => Some(a.wrapping_div(b)),
+                    (BinOp::Rem, Mode::Checked) => a.checked_rem(b),
+                    (BinOp::Rem, _) => Some(a.wrapping_rem(b)),
+                    (BinOp::And, _) => Some(a & b),
+                    (BinOp::Or, _) => Some(a | b),
+                    (BinOp::Xor, _) => Some(a ^ b),
+                    (BinOp::Shl, Mode::Checked) => u32::try_from(b).ok().and_then(|s| a.checked_shl(s)),
+                    (BinOp::Shl, _) => Some(a.wrapping_shl(b as u32)),
+                    (BinOp::Shr, Mode::Checked) => u32::try_from(b).ok().and_then(|s| a.checked_shr(s)),
+                    (BinOp::Shr, _) => Some(a.wrapping_shr(b as u32)),
+                    (BinOp::TrueDiv, _) => unreachable!("`/` goes through `quotient`"),
+                };
+                result.ok_or(Fault::Overflow)
+            }
+
+            fn quotient(a: Self, b: Self) -> Result<f64, Fault> {
+                if b == 0 {
+                    return Err(Fault::ZeroDivision);
+                }
+                Ok(a as f64 / b as f64)
+            }
+        }
+    )*};
+}
+
+int_arith!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! float_arith {
+    ($($t:ty),*) => {$(
+        impl Arith for $t {
+            type Quotient = $t;
+
+            fn supports(op: BinOp, mode: Mode) -> bool {
+                matches!(mode, Mode::Checked)
+                    && matches!(op, BinOp::Add | BinOp::Sub | BinOp::Mul | BinOp::TrueDiv | BinOp::FloorDiv | BinOp::Rem)
+            }
+
+            /// IEEE 754 arithmetic, where `//` and `%` follow Python's float
+            /// `divmod`: the quotient is floored and the remainder takes the
+            /// sign of the divisor. Dividing by zero gives an infinity or NaN
+            /// as `/` does rather than raising.
+            fn binary(op: BinOp, _mode: Mode, a: Self, b: Self) -> Result<Self, Fault> {
+                // Port of CPython's `_float_div_mod`
+                fn div_mod(a: $t, b: $t) -> ($t, $t) {
+                    if b == 0.0 {
+                        return ((a / b).floor(), a % b);
+                    }
+                    let mut rem = a % b;
+                    let mut div = (a - rem) / b;
+                    if rem != 0.0 {
+                        if (b < 0.0) != (rem < 0.0) {
+                            rem += b;
+                            div -= 1.0;
+                        }
+                    } else {
+                        rem = (0.0 as $t).copysign(b);
+                    }
+                    let floor_div = if div != 0.0 {
+                        let floor = div.floor();
+                        if div - floor > 0.5 {
+                            floor + 1.0
+                        } else {
+                            floor
+                        }
+                    } else {
+                        (0.0 as $t).copysign(a / b)
+                    };
+                    (floor_div, rem)
+                }
+
+                Ok(match op {
+                    BinOp::Add => a + b,
+                    BinOp::Sub => a - b,
+                    BinOp::Mul => a * b,
+                    BinOp::FloorDiv => div_mod(a, b).0,
+                    BinOp::Rem => div_mod(a, b).1,
+                    _ => unreachable!("unsupported ops are rejected first"),
+                })
+            }
+
+            fn quotient(a: Self, b: Self) -> Result<Self, Fault> {
+                Ok(a / b)
+            }
+        }
+    )*};
+}
+
+float_arith!(f32, f64);
+
+impl Arith for bool {
+    type Quotient = bool;
+
+    fn supports(op: BinOp, mode: Mode) -> bool {
+        matches!(mode, Mode::Checked) && matches!(op, BinOp::And | BinOp::Or | BinOp::Xor)
+    }
+
+    fn binary(op: BinOp, _mode: Mode, a: Self, b: Self) -> Result<Self, Fault> {
+        Ok(match op {
+            BinOp::And => a & b,
+            BinOp::Or => a | b,
+            BinOp::Xor => a ^ b,
+            _ => unreachable!("unsupported ops are rejected first"),
+        })
+    }
+
+    fn quotient(_: Self, _: Self) -> Result<bool, Fault> {
+        unreachable!("unsupported ops are rejected first")
+    }
+}
+
+impl Arith for char {
+    type Quotient = char;
+
+    fn supports(_: BinOp, _: Mode) -> bool {
+        false
+    }
+
+    fn binary(_: BinOp, _: Mode, _: Self, _: Self) -> Result<Self, Fault> {
+        unreachable!("unsupported ops are rejected first")
+    }
+
+    fn quotient(_: Self, _: Self) -> Result<char, Fault> {
+        unreachable!("unsupported ops are rejected first")
+    }
+}
+
+/// The right-hand side of an element-wise operation.
+enum Operand<T> {
+    Scalar(T),
+    Items(Vec<T>),
+}
+
+impl<T: Copy> Operand<T> {
+    fn get(&self, index: usize) -> T {
+        match self {
+            Operand::Scalar(value) => *value,
+            Operand::Items(items) => items[index],
+        }
+    }
+}
+
+/// Whether `other` is combined with a List as a whole rather than with
+/// each of its items.
+pub(crate) fn is_sequence(other: &Bound<'_, PyAny>) -> bool {
+    other.downcast::<List>().is_ok() || other.downcast::<PyList>().is_ok()
+}
+
+/// `other` as a scalar, or as the items of a list of length `len`.
+fn operand<T: Native>(len: usize, other: &Bound<'_, PyAny>) -> PyResult<Operand<T>> {
+    let py = other.py();
+    let items: Vec<T> = if let Ok(list) = other.downcast::<List>() {
+        let list = list.try_borrow()?;
+        match (T::items(&list.data), list.data.kind()) {
+            (Some(items), _) => items.to_vec(),
+            (None, Some(kind)) => {
+                return Err(PyTypeError::new_err(format!(
+                    "Cannot combine List[{}] and List[{}] item by item",
+                    T::KIND.name(),
+                    kind.name()
+                )))
+            }
+            (None, None) => list.data.objects(py)?.iter().map(|item| T::value(item.bind(py))).collect::<PyResult<_>>()?,
+        }
+    } else if let Ok(list) = other.downcast::<PyList>() {
+        list.iter().map(|item| T::value(&item)).collect::<PyResult<_>>()?
+    } else {
+        return Ok(Operand::Scalar(T::value(other)?));
+    };
+
+    if items.len() != len {
+        return Err(PyValueError::new_err(format!(
+            "Operands have different lengths: {} and {}",
+            len,
+            items.len()
+        )));
+    }
+    Ok(Operand::Items(items))
+}
+
+fn fault_error(kind: Kind, name: &str, fault: Fault, index: usize) -> PyErr {
+    match fault {
+        Fault::Overflow => PyOverflowError::new_err(format!(
+            "{} overflow in element-wise {} at index {}",
+            kind.name(),
+            name,
+            index
+        )),
+        Fault::ZeroDivision => PyZeroDivisionError::new_err(format!("Division by zero at index {}", index)),
+    }
+}
+
+fn unsupported() -> PyErr {
+    PyTypeError::new_err("Element-wise operations require a List of a Rustique primitive")
+}
+
+fn binary_items<T: Arith>(items: &[T], op: BinOp, mode: Mode, other: &Bound<'_, PyAny>) -> PyResult<Storage>
+where
+    Storage: From<Vec<T>> + From<Vec<T::Quotient>>,
+{
+    let name = format!("{}{}", mode.prefix(), op.name());
+    if !T::supports(op, mode) {
+        return Err(PyTypeError::new_err(format!(
+            "{} is not supported for List[{}]",
+            name,
+            T::KIND.name()
+        )));
+    }
+    let rhs = operand::<T>(items.len(), other)?;
+    let pair = |i: usize| (items[i], rhs.get(i));
+    let error = |(i, fault)| fault_error(T::KIND, &name, fault, i);
+
+    if let BinOp::TrueDiv = op {
+        let quotients: Vec<T::Quotient> = (0..items.len())
+            .map(|i| {
+                let (a, b) = pair(i);
+                T::quotient(a, b).map_err(|fault| (i, fault))
+            })
+            .collect::<Result<_, _>>()
+            .map_err(error)?;
+        return Ok(quotients.into());
+    }
+    let results: Vec<T> = (0..items.len())
+        .map(|i| {
+            let (a, b) = pair(i);
+            T::binary(op, mode, a, b).map_err(|fault| (i, fault))
+        })
+        .collect::<Result<_, _>>()
+        .map_err(error)?;
+    Ok(results.into())
+}
+
+/// `data <op> other` item by item, where `other` is a scalar or a list of
+/// the same length.
+pub(crate) fn binary(data: &Storage, op: BinOp, mode: Mode, other: &Bound<'_, PyAny>) -> PyResult<Storage> {
+    each_native!(data, v => binary_items(v, op, mode, other), _ => Err(unsupported()))
+}
+
+fn mask_items<T: Native>(items: &[T], predicate: Predicate, other: &Bound<'_, PyAny>) -> PyResult<Vec<bool>> {
+    let rhs = operand::<T>(items.len(), other)?;
+    Ok(items.iter().enumerate().map(|(i, item)| predicate.test(item, &rhs.get(i))).collect())
+}
+
+/// Whether `item <predicate> other` holds for each item, where `other` is
+/// a scalar or a list of the same length.
+pub(crate) fn mask(data: &Storage, predicate: Predicate, other: &Bound<'_, PyAny>) -> PyResult<Storage> {
+    each_native!(data, v => Ok(mask_items(v, predicate, other)?.into()), _ => Err(unsupported()))
+}
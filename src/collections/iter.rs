@@ -0,0 +1,633 @@
+use std::iter;
+
+use pyo3::exceptions::{PyOverflowError, PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyIterator, PyList, PySequence, PyTuple, PyType};
+use pyo3::IntoPyObjectExt;
+
+use super::alias::ListAlias;
+use super::list::List;
+use super::parallel::{self, Parallel, Predicate};
+use super::stats;
+use super::storage::{each_native, Kind, Storage};
+
+/// Items produced on demand, taking the GIL for each one.
+type Stream = Box<dyn Iterator<Item = PyResult<PyObject>> + Send + Sync>;
+
+/// Unboxed items produced on demand without calling into Python.
+type Unboxed<'a, T> = Box<dyn Iterator<Item = PyResult<T>> + 'a>;
+
+/// What `map` applies to each item.
+enum Func {
+    /// A `par_map` op with its operand, for items of the given kind.
+    Native(String, Option<PyObject>, Kind),
+    Call(PyObject),
+}
+
+/// What `filter` checks each item with.
+enum Test {
+    /// `item <predicate> value`, comparing unboxed values for items of
+    /// the given kind.
+    Compare(Predicate, PyObject, Option<Kind>),
+    Call(PyObject),
+}
+
+impl Test {
+    fn passes(&self, item: &Bound<'_, PyAny>) -> PyResult<bool> {
+        let py = item.py();
+        match self {
+            Test::Compare(predicate, value, Some(kind)) => {
+                let marker = Storage::new(Some(*kind), 0);
+                each_native!(&marker, v => compare_object(v, *predicate, item, value.bind(py)), _ => unreachable!())
+            }
+            Test::Compare(predicate, value, None) => item.rich_compare(value, (*predicate).into())?.is_truthy(),
+            Test::Call(f) => f.bind(py).call1((item,))?.is_truthy(),
+        }
+    }
+}
+
+/// An adaptor chain, built up from its source and only run by `__next__`
+/// or a collector.
+enum Node {
+    /// The items of a List, as they were when the chain was built.
+    Items(Storage),
+    Iterable(PyObject),
+    /// A chain that has started producing items.
+    Running(Stream),
+    Map(Box<Node>, Func),
+    Filter(Box<Node>, Test),
+    Inspect(Box<Node>, PyObject),
+    StepBy(Box<Node>, usize),
+    Chain(Box<Node>, Box<Node>),
+    Zip(Box<Node>, Box<Node>),
+    Take(Box<Node>, usize),
+    Skip(Box<Node>, usize),
+    Cycle(Box<Node>),
+    Flatten(Box<Node>),
+    Rev(Box<Node>),
+}
+
+impl Node {
+    /// A chain over `iterable`, taking over the chain of an `Iter`.
+    fn of(iterable: &Bound<'_, PyAny>) -> PyResult<Node> {
+        if let Ok(it) = iterable.downcast::<Iter>() {
+            return it.try_borrow_mut()?.take_node();
+        }
+        if let Ok(list) = iterable.downcast::<List>() {
+            return Ok(Node::Items(list.try_borrow()?.data.clone()));
+        }
+        // Fail now for objects that are not iterable at all
+        iterable.try_iter()?;
+        Ok(Node::Iterable(iterable.clone().unbind()))
+    }
+
+    /// The kind of every item when the whole chain can run on unboxed items.
+    fn native(&self) -> Option<Kind> {
+        match self {
+            Node::Items(items) => items.kind(),
+            Node::Map(inner, Func::Native(..))
+            | Node::Filter(inner, Test::Compare(_, _, Some(_)))
+            | Node::StepBy(inner, _)
+            | Node::Take(inner, _)
+            | Node::Skip(inner, _)
+            | Node::Rev(inner) => inner.native(),
+            Node::Chain(a, b) => a.native().filter(|&kind| b.native() == Some(kind)),
+            _ => None,
+        }
+    }
+
+    /// The items of the first source of a native chain.
+    fn source(&self) -> &Storage {
+        match self {
+            Node::Items(items) => items,
+            Node::Map(inner, _)
+            | Node::Filter(inner, _)
+            | Node::StepBy(inner, _)
+            | Node::Take(inner, _)
+            | Node::Skip(inner, _)
+            | Node::Rev(inner)
+            | Node::Chain(inner, _) => inner.source(),
+            _ => unreachable!("native chains start from List items"),
+        }
+    }
+
+    /// Whether the chain can run from the back, like a Rust
+    /// `DoubleEndedIterator`.
+    fn double_ended(&self, py: Python) -> bool {
+        match self {
+            Node::Items(_) => true,
+            Node::Iterable(obj) => {
+                let obj = obj.bind(py);
+                obj.hasattr("__reversed__").unwrap_or(false) || obj.downcast::<PySequence>().is_ok()
+            }
+            Node::Map(inner, _) | Node::Filter(inner, _) | Node::Inspect(inner, _) | Node::Rev(inner) => {
+                inner.double_ended(py)
+            }
+            Node::Chain(a, b) => a.double_ended(py) && b.double_ended(py),
+            _ => false,
+        }
+    }
+
+    /// Start producing the items, from the back when `reversed`.
+    fn stream(self, py: Python, reversed: bool) -> PyResult<Stream> {
+        Ok(match self {
+            Node::Items(items) => {
+                let indices: Box<dyn Iterator<Item = usize> + Send + Sync> = if reversed {
+                    Box::new((0..items.len()).rev())
+                } else {
+                    Box::new(0..items.len())
+                };
+                Box::new(indices.map(move |i| Python::with_gil(|py| items.get(py, i))))
+            }
+            Node::Iterable(obj) => {
+                let obj = obj.bind(py);
+                let it = if reversed {
+                    py.import("builtins")?.getattr("reversed")?.call1((obj,))?.try_iter()?
+                } else {
+                    obj.try_iter()?
+                };
+                python_stream(it.unbind())
+            }
+            Node::Running(stream) => stream,
+            Node::Map(inner, Func::Call(f)) => {
+                Box::new(inner.stream(py, reversed)?.map(move |item| Python::with_gil(|py| f.call1(py, (item?,)))))
+            }
+            Node::Map(inner, Func::Native(name, operand, kind)) => Box::new(inner.stream(py, reversed)?.map(move |item| {
+                let item = item?;
+                Python::with_gil(|py| {
+                    let marker = Storage::new(Some(kind), 0);
+                    let operand = operand.as_ref().map(|obj| obj.bind(py));
+                    each_native!(&marker, v => map_object(v, &name, operand, item.bind(py)), _ => unreachable!())
+                })
+            })),
+            Node::Filter(inner, test) => Box::new(inner.stream(py, reversed)?.filter_map(move |item| {
+                let kept = item.and_then(|item| Python::with_gil(|py| Ok(test.passes(item.bind(py))?.then_some(item))));
+                kept.transpose()
+            })),
+            Node::Inspect(inner, f) => Box::new(inner.stream(py, reversed)?.map(move |item| {
+                let item = item?;
+                Python::with_gil(|py| f.call1(py, (item.clone_ref(py),)))?;
+                Ok(item)
+            })),
+            Node::StepBy(inner, step) => Box::new(step_by(inner.stream(py, reversed)?, step)),
+            Node::Chain(a, b) => {
+                let (a, b) = if reversed { (b, a) } else { (a, b) };
+                Box::new(a.stream(py, reversed)?.chain(b.stream(py, reversed)?))
+            }
+            Node::Zip(a, b) => Box::new(a.stream(py, false)?.zip(b.stream(py, false)?).map(|(a, b)| {
+                let (a, b) = (a?, b?);
+                Python::with_gil(|py| Ok(PyTuple::new(py, [a, b])?.into_any().unbind()))
+            })),
+            Node::Take(inner, n) => Box::new(inner.stream(py, reversed)?.take(n)),
+            Node::Skip(inner, n) => Box::new(skip(inner.stream(py, reversed)?, n)),
+            Node::Cycle(inner) => cycle(inner.stream(py, reversed)?),
+            Node::Flatten(inner) => flatten(inner.stream(py, reversed)?),
+            Node::Rev(inner) => inner.stream(py, !reversed)?,
+        })
+    }
+}
+
+fn python_stream(it: Py<PyIterator>) -> Stream {
+    Box::new(iter::from_fn(move || Python::with_gil(|py| it.bind(py).clone().next().map(|item| item.map(Bound::unbind)))))
+}
+
+/// Pass over `n` items, stopping early at the end or at an error.
+fn advance<T>(items: &mut impl Iterator<Item = PyResult<T>>, n: usize) -> PyResult<()> {
+    for _ in 0..n {
+        match items.next() {
+            Some(Err(err)) => return Err(err),
+            Some(Ok(_)) => {}
+            None => break,
+        }
+    }
+    Ok(())
+}
+
+/// Like `Iterator::skip`, without losing errors raised by skipped items.
+fn skip<T>(mut items: impl Iterator<Item = PyResult<T>>, n: usize) -> impl Iterator<Item = PyResult<T>> {
+    let mut pending = Some(n);
+    iter::from_fn(move || {
+        if let Some(n) = pending.take() {
+            if let Err(err) = advance(&mut items, n) {
+                return Some(Err(err));
+            }
+        }
+        items.next()
+    })
+}
+
+/// Like `Iterator::step_by`, without losing errors raised by skipped items.
+fn step_by<T>(mut items: impl Iterator<Item = PyResult<T>>, step: usize) -> impl Iterator<Item = PyResult<T>> {
+    let mut first = true;
+    iter::from_fn(move || {
+        if !std::mem::take(&mut first) {
+            if let Err(err) = advance(&mut items, step - 1) {
+                return Some(Err(err));
+            }
+        }
+        items.next()
+    })
+}
+
+/// The items, then the same items again forever, remembered from the first
+/// pass the way Rust's `cycle` clones its source.
+fn cycle(items: Stream) -> Stream {
+    let mut items = items.fuse();
+    let mut seen: Vec<PyObject> = Vec::new();
+    let mut index = 0;
+    Box::new(iter::from_fn(move || {
+        if let Some(item) = items.next() {
+            if let Ok(ref item) = item {
+                seen.push(Python::with_gil(|py| item.clone_ref(py)));
+            }
+            return Some(item);
+        }
+        if seen.is_empty() {
+            return None;
+        }
+        let item = Python::with_gil(|py| seen[index % seen.len()].clone_ref(py));
+        index += 1;
+        Some(Ok(item))
+    }))
+}
+
+/// The items of each item in turn.
+fn flatten(mut outer: Stream) -> Stream {
+    let mut inner: Option<Py<PyIterator>> = None;
+    Box::new(iter::from_fn(move || {
+        Python::with_gil(|py| loop {
+            if let Some(ref it) = inner {
+                match it.bind(py).clone().next() {
+                    Some(item) => return Some(item.map(Bound::unbind)),
+                    None => inner = None,
+                }
+            }
+            match outer.next()?.and_then(|items| Ok(items.bind(py).try_iter()?.unbind())) {
+                Ok(it) => inner = Some(it),
+                Err(err) => return Some(Err(err)),
+            }
+        })
+    }))
+}
+
+fn map_overflow<T: Parallel>(name: &str) -> PyErr {
+    PyOverflowError::new_err(format!("{} overflow in map('{}')", T::KIND.name(), name))
+}
+
+/// The op `name` applied to one item of a chain that no longer runs unboxed.
+fn map_object<T: Parallel>(_: &[T], name: &str, operand: Option<&Bound<'_, PyAny>>, item: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+    let (op, rhs) = parallel::map_operand::<T>("map", name, operand)?;
+    let value = T::apply(op, T::value(item)?, rhs).ok_or_else(|| map_overflow::<T>(name))?;
+    value.into_object(item.py())
+}
+
+/// `item <predicate> value` for one item of a chain that no longer runs
+/// unboxed.
+fn compare_object<T: Parallel>(_: &[T], predicate: Predicate, item: &Bound<'_, PyAny>, value: &Bound<'_, PyAny>) -> PyResult<bool> {
+    Ok(predicate.test(&T::value(item)?, &T::value(value)?))
+}
+
+fn check_map<T: Parallel>(_: &[T], name: &str, operand: Option<&Bound<'_, PyAny>>) -> PyResult<()> {
+    parallel::map_operand::<T>("map", name, operand).map(|_| ())
+}
+
+/// Produce the items of a native chain without boxing them.
+fn unboxed<'a, T: Parallel + 'a>(py: Python, node: &'a Node, reversed: bool) -> PyResult<Unboxed<'a, T>> {
+    Ok(match node {
+        Node::Items(items) => {
+            let items = T::items(items).expect("native chains hold one kind of item");
+            if reversed {
+                Box::new(items.iter().rev().map(|&x| Ok(x)))
+            } else {
+                Box::new(items.iter().map(|&x| Ok(x)))
+            }
+        }
+        Node::Map(inner, Func::Native(name, operand, _)) => {
+            let (op, rhs) = parallel::map_operand::<T>("map", name, operand.as_ref().map(|obj| obj.bind(py)))?;
+            Box::new(unboxed::<T>(py, inner, reversed)?.map(move |x| T::apply(op, x?, rhs).ok_or_else(|| map_overflow::<T>(name))))
+        }
+        Node::Filter(inner, Test::Compare(predicate, value, _)) => {
+            let (predicate, value) = (*predicate, T::value(value.bind(py))?);
+            Box::new(unboxed::<T>(py, inner, reversed)?.filter(move |x| x.as_ref().map_or(true, |x| predicate.test(x, &value))))
+        }
+        Node::StepBy(inner, step) => Box::new(step_by(unboxed::<T>(py, inner, reversed)?, *step)),
+        Node::Take(inner, n) => Box::new(unboxed::<T>(py, inner, reversed)?.take(*n)),
+        Node::Skip(inner, n) => Box::new(skip(unboxed::<T>(py, inner, reversed)?, *n)),
+        Node::Chain(a, b) => {
+            let (a, b) = if reversed { (b, a) } else { (a, b) };
+            Box::new(unboxed::<T>(py, a, reversed)?.chain(unboxed::<T>(py, b, reversed)?))
+        }
+        Node::Rev(inner) => unboxed::<T>(py, inner, !reversed)?,
+        _ => unreachable!("only native chains run unboxed"),
+    })
+}
+
+/// How a chain is consumed.
+enum Collector<'py> {
+    /// Into a List, or into whatever calling the target on a Python list
+    /// of the items builds.
+    Collect(Option<Bound<'py, PyAny>>),
+    Count,
+    Last,
+    Nth(usize),
+    Sum,
+}
+
+/// Finish collecting `items` into the target of `collect`, or into their sum.
+fn finish(py: Python, items: Storage, collector: &Collector) -> PyResult<PyObject> {
+    match collector {
+        Collector::Sum => stats::reduce(py, &items, None, false),
+        Collector::Collect(None) => List::of_kind(py, items)?.into_py_any(py),
+        Collector::Collect(Some(target)) => {
+            let is_list = match target.downcast::<ListAlias>() {
+                Ok(alias) => {
+                    let element = &alias.get().element;
+                    if element.kind.is_some() && element.kind == items.kind() {
+                        return List::new(items, Some(element.clone())).into_py_any(py);
+                    }
+                    true
+                }
+                Err(_) => match target.downcast::<PyType>() {
+                    Ok(t) => t.is_subclass_of::<List>()?,
+                    Err(_) => false,
+                },
+            };
+            let items = PyList::new(py, items.objects(py)?)?;
+            if is_list {
+                return Ok(target.call_method1("from_iter", (items,))?.unbind());
+            }
+            Ok(target.call1((items,))?.unbind())
+        }
+        Collector::Count | Collector::Last | Collector::Nth(_) => unreachable!("only collect and sum keep every item"),
+    }
+}
+
+fn consume_unboxed<T: Parallel>(_: &[T], py: Python, node: &Node, collector: &Collector) -> PyResult<PyObject>
+where
+    Storage: From<Vec<T>>,
+{
+    let mut items = unboxed::<T>(py, node, false)?;
+    match collector {
+        Collector::Count => items.try_fold(0usize, |n, x| x.map(|_| n + 1))?.into_py_any(py),
+        Collector::Last => match items.try_fold(None, |_, x| x.map(Some))? {
+            Some(x) => x.into_object(py),
+            None => Ok(py.None()),
+        },
+        Collector::Nth(n) => {
+            advance(&mut items, *n)?;
+            match items.next().transpose()? {
+                Some(x) => x.into_object(py),
+                None => Ok(py.None()),
+            }
+        }
+        Collector::Collect(_) | Collector::Sum => finish(py, items.collect::<PyResult<Vec<T>>>()?.into(), collector),
+    }
+}
+
+fn consume_stream(py: Python, mut items: Stream, collector: &Collector) -> PyResult<PyObject> {
+    match collector {
+        Collector::Count => items.try_fold(0usize, |n, x| x.map(|_| n + 1))?.into_py_any(py),
+        Collector::Last => Ok(items.try_fold(None, |_, x| x.map(Some))?.unwrap_or_else(|| py.None())),
+        Collector::Nth(n) => {
+            advance(&mut items, *n)?;
+            Ok(items.next().transpose()?.unwrap_or_else(|| py.None()))
+        }
+        Collector::Collect(_) | Collector::Sum => finish(py, items.collect::<PyResult<Vec<_>>>()?.into(), collector),
+    }
+}
+
+/// Run `node` into `collector`, on unboxed items when the chain allows it.
+fn consume(py: Python, node: Node, collector: &Collector) -> PyResult<PyObject> {
+    if node.native().is_none() {
+        return consume_stream(py, node.stream(py, false)?, collector);
+    }
+    each_native!(node.source(), v => consume_unboxed(v, py, &node, collector), _ => unreachable!())
+}
+
+fn moved() -> PyErr {
+    PyValueError::new_err("Iter was moved into an adaptor or consumed by a collector")
+}
+
+/// A lazy iterator with the adaptors and collectors of Rust's `Iterator`.
+///
+/// Adaptors take over the chain of the `Iter` they are called on, which can
+/// no longer be used afterwards, much like a Rust iterator moved into an
+/// adaptor. Nothing runs until items are requested with `next()` or a
+/// collector. When the source is a List of a Rustique primitive and the chain
+/// only uses built-in ops (`map` with a `par_map` op name, `filter` with a
+/// comparison, `step_by`, `take`, `skip`, `chain` and `rev`), collectors run
+/// it on the unboxed items without calling into Python.
+#[pyclass(name = "Iter")]
+pub struct Iter {
+    node: Option<Node>,
+    peekable: bool,
+    /// The item `peek` read ahead, `Some(None)` at the end.
+    peeked: Option<Option<PyObject>>,
+}
+
+impl Iter {
+    fn from_node(node: Node) -> Self {
+        Iter { node: Some(node), peekable: false, peeked: None }
+    }
+
+    /// The chain, with any peeked item put back in front.
+    fn take_node(&mut self) -> PyResult<Node> {
+        let node = self.node.take().ok_or_else(moved)?;
+        Ok(match self.peeked.take() {
+            Some(Some(item)) => Node::Chain(Box::new(Node::Items(vec![item].into())), Box::new(node)),
+            Some(None) => Node::Items(Vec::<PyObject>::new().into()),
+            None => node,
+        })
+    }
+
+    fn adapt(&mut self, adaptor: impl FnOnce(Box<Node>) -> Node) -> PyResult<Iter> {
+        Ok(Iter::from_node(adaptor(Box::new(self.take_node()?))))
+    }
+
+    /// The stream of a chain that has started running.
+    fn running(&mut self, py: Python) -> PyResult<&mut Stream> {
+        let stream = match self.node.take().ok_or_else(moved)? {
+            Node::Running(stream) => stream,
+            node => node.stream(py, false)?,
+        };
+        let Node::Running(stream) = self.node.insert(Node::Running(stream)) else {
+            unreachable!()
+        };
+        Ok(stream)
+    }
+
+    fn consume(&mut self, py: Python, collector: Collector) -> PyResult<PyObject> {
+        consume(py, self.take_node()?, &collector)
+    }
+}
+
+#[pymethods]
+impl Iter {
+    #[new]
+    fn new(iterable: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(Iter::from_node(Node::of(iterable)?))
+    }
+
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python) -> PyResult<Option<PyObject>> {
+        if let Some(item) = self.peeked.take() {
+            return Ok(item);
+        }
+        self.running(py)?.next().transpose()
+    }
+
+    /// `f(item)` for each item. `f` may also name a `par_map` op, such as
+    /// `"add"` with `operand`, for an Iter over a List of a Rustique
+    /// primitive.
+    #[pyo3(signature = (f, operand=None))]
+    fn map(&mut self, f: &Bound<'_, PyAny>, operand: Option<PyObject>) -> PyResult<Iter> {
+        let py = f.py();
+        let func = if let Ok(name) = f.extract::<String>() {
+            let kind = self.node.as_ref().and_then(Node::native).ok_or_else(|| {
+                PyTypeError::new_err(format!("map('{}') requires an Iter over a List of a Rustique primitive", name))
+            })?;
+            let marker = Storage::new(Some(kind), 0);
+            each_native!(&marker, v => check_map(v, &name, operand.as_ref().map(|obj| obj.bind(py))), _ => unreachable!())?;
+            Func::Native(name, operand, kind)
+        } else if operand.is_some() {
+            return Err(PyTypeError::new_err("map() takes an operand only with a built-in op"));
+        } else {
+            Func::Call(f.clone().unbind())
+        };
+        self.adapt(|node| Node::Map(node, func))
+    }
+
+    /// The items for which `predicate(item)` is true. `predicate` may also
+    /// be a comparison such as `"<"`, checking `item < value`.
+    #[pyo3(signature = (predicate, value=None))]
+    fn filter(&mut self, predicate: &Bound<'_, PyAny>, value: Option<PyObject>) -> PyResult<Iter> {
+        let test = match (predicate.extract::<String>(), value) {
+            (Ok(op), Some(value)) => {
+                let kind = self.node.as_ref().and_then(Node::native);
+                Test::Compare(Predicate::parse(&op)?, value, kind)
+            }
+            (Ok(op), None) => return Err(PyTypeError::new_err(format!("filter('{}') requires a value", op))),
+            (Err(_), Some(_)) => return Err(PyTypeError::new_err("filter() takes a value only with a comparison")),
+            (Err(_), None) => Test::Call(predicate.clone().unbind()),
+        };
+        self.adapt(|node| Node::Filter(node, test))
+    }
+
+    /// The first item, then every `step`-th one after it.
+    fn step_by(&mut self, step: usize) -> PyResult<Iter> {
+        if step == 0 {
+            return Err(PyValueError::new_err("step_by() step must be non-zero"));
+        }
+        self.adapt(|node| Node::StepBy(node, step))
+    }
+
+    /// The items, followed by those of `other`.
+    fn chain(&mut self, other: &Bound<'_, PyAny>) -> PyResult<Iter> {
+        let other = Node::of(other)?;
+        self.adapt(|node| Node::Chain(node, Box::new(other)))
+    }
+
+    /// `(item, other_item)` tuples, up to the end of either.
+    fn zip(&mut self, other: &Bound<'_, PyAny>) -> PyResult<Iter> {
+        let other = Node::of(other)?;
+        self.adapt(|node| Node::Zip(node, Box::new(other)))
+    }
+
+    /// The first `n` items.
+    fn take(&mut self, n: usize) -> PyResult<Iter> {
+        self.adapt(|node| Node::Take(node, n))
+    }
+
+    /// The items after the first `n`.
+    fn skip(&mut self, n: usize) -> PyResult<Iter> {
+        self.adapt(|node| Node::Skip(node, n))
+    }
+
+    /// The same items, with `peek` to look at the next one without
+    /// consuming it.
+    fn peekable(&mut self) -> PyResult<Iter> {
+        Ok(Iter { peekable: true, ..Iter::from_node(self.take_node()?) })
+    }
+
+    /// The next item, which `next()` will still return, or `None` at the end.
+    fn peek(&mut self, py: Python) -> PyResult<Option<PyObject>> {
+        if !self.peekable {
+            return Err(PyTypeError::new_err("peek() requires a peekable Iter"));
+        }
+        if self.peeked.is_none() {
+            let item = self.running(py)?.next().transpose()?;
+            self.peeked = Some(item);
+        }
+        Ok(self.peeked.as_ref().and_then(|item| item.as_ref().map(|item| item.clone_ref(py))))
+    }
+
+    /// The items repeated forever, or nothing when there are none.
+    fn cycle(&mut self) -> PyResult<Iter> {
+        self.adapt(Node::Cycle)
+    }
+
+    /// The items of each item in turn.
+    fn flatten(&mut self) -> PyResult<Iter> {
+        self.adapt(Node::Flatten)
+    }
+
+    /// The same items, calling `f(item)` on each as it passes.
+    fn inspect(&mut self, f: PyObject) -> PyResult<Iter> {
+        self.adapt(|node| Node::Inspect(node, f))
+    }
+
+    /// The items in reverse order, for chains over a List or a reversible
+    /// sequence through `map`, `filter`, `inspect` and `chain`.
+    fn rev(&mut self, py: Python) -> PyResult<Iter> {
+        if !self.node.as_ref().ok_or_else(moved)?.double_ended(py) {
+            return Err(PyTypeError::new_err("rev() requires a double-ended Iter"));
+        }
+        self.adapt(Node::Rev)
+    }
+
+    /// The items in a List, typed by their primitive when the chain runs
+    /// unboxed, or in `target(items)`, such as `List[int]`, for a `target`.
+    #[pyo3(signature = (target=None))]
+    fn collect(&mut self, py: Python, target: Option<Bound<'_, PyAny>>) -> PyResult<PyObject> {
+        self.consume(py, Collector::Collect(target))
+    }
+
+    /// The number of items.
+    fn count(&mut self, py: Python) -> PyResult<PyObject> {
+        self.consume(py, Collector::Count)
+    }
+
+    /// The last item, or `None` when there are none.
+    fn last(&mut self, py: Python) -> PyResult<PyObject> {
+        self.consume(py, Collector::Last)
+    }
+
+    /// The sum of the items, as `List.sum` computes it.
+    fn sum(&mut self, py: Python) -> PyResult<PyObject> {
+        self.consume(py, Collector::Sum)
+    }
+
+    /// The item after the next `n`, or `None` at the end. Unlike the other
+    /// collectors, the Iter can be used for the remaining items afterwards.
+    fn nth(&mut self, py: Python, n: usize) -> PyResult<PyObject> {
+        let node = self.take_node()?;
+        if node.native().is_some() {
+            // Native chains are pure, so rerunning them later is the same
+            let item = each_native!(node.source(), v => consume_unboxed(v, py, &node, &Collector::Nth(n)), _ => unreachable!())?;
+            self.node = Some(Node::Skip(Box::new(node), n.saturating_add(1)));
+            return Ok(item);
+        }
+        self.node = Some(node);
+        let items = self.running(py)?;
+        advance(items, n)?;
+        Ok(items.next().transpose()?.unwrap_or_else(|| py.None()))
+    }
+}
+
+pub fn register_iter(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Iter>()?;
+    Ok(())
+}
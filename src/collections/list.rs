@@ -1,7 +1,25 @@
+use std::borrow::Cow;
+use std::ffi::c_int;
+use std::sync::Arc;
+
 use pyo3::basic::CompareOp;
-use pyo3::exceptions::{PyIndexError, PyTypeError, PyValueError};
+use pyo3::exceptions::{PyBufferError, PyIndexError, PyRuntimeError, PyTypeError, PyValueError};
+use pyo3::ffi;
 use pyo3::prelude::*;
-use pyo3::types::{PyAny, PyList, PySlice, PyTuple, PyType};
+use pyo3::sync::GILOnceCell;
+use pyo3::types::{PyAny, PyDict, PyList, PySlice, PyString, PyTuple, PyType};
+use pyo3::IntoPyObjectExt;
+
+use super::sort::{sort_items, try_merge_sort};
+use super::alias::ListAlias;
+use super::buffer::{self, BufferView};
+use super::elementwise::{self, BinOp, Mode};
+use super::parallel::{self, Predicate};
+use super::stats;
+use super::storage::{Kind, Storage};
+use super::typing::ElementType;
+
+static MUTABLE_SEQUENCE: GILOnceCell<Py<PyType>> = GILOnceCell::new();
 
 fn any_to_list(value: &Bound<'_, PyAny>) -> PyResult<List> {
     if let Ok(list) = value.extract::<List>() {
@@ -10,205 +28,299 @@ fn any_to_list(value: &Bound<'_, PyAny>) -> PyResult<List> {
 
     if let Ok(list) = value.downcast::<PyList>() {
         let data: Vec<PyObject> = list.iter().map(|item| item.into()).collect();
-        return Ok(List { data, _type: None });
+        return Ok(List::new(data.into(), None));
     }
 
     Err(PyTypeError::new_err("Expected Rustique List or Python list"))
 }
 
+fn same_type(py: Python, a: &Option<Arc<ElementType>>, b: &Option<Arc<ElementType>>) -> PyResult<bool> {
+    match (a, b) {
+        (Some(a), Some(b)) => a.same(py, b),
+        (None, None) => Ok(true),
+        _ => Ok(false),
+    }
+}
+
+fn kind(_type: &Option<Arc<ElementType>>) -> Option<Kind> {
+    _type.as_ref().and_then(|t| t.kind)
+}
+
+/// Resolve a possibly negative index, raising `IndexError` when out of range.
+fn normalize_index(len: usize, index: isize) -> PyResult<usize> {
+    let actual = if index < 0 { index + len as isize } else { index };
+    if actual < 0 || actual as usize >= len {
+        return Err(PyIndexError::new_err(format!(
+            "Index out of range: got {}, length is {}",
+            index, len
+        )));
+    }
+    Ok(actual as usize)
+}
+
+/// A slice bound, clamped to the range of `isize` like CPython does.
+fn slice_bound(obj: &Bound<'_, PyAny>) -> PyResult<isize> {
+    let value = unsafe { ffi::PyNumber_AsSsize_t(obj.as_ptr(), std::ptr::null_mut()) };
+    match PyErr::take(obj.py()) {
+        Some(err) if value == -1 => Err(err),
+        _ => Ok(value),
+    }
+}
+
+/// Position at which Python's `list.insert` would place an item.
+fn insert_position(len: usize, index: isize) -> usize {
+    if index < 0 {
+        (len as isize + index).max(0) as usize
+    } else {
+        (index as usize).min(len)
+    }
+}
+
+/// Whether a lone constructor argument holds the elements rather than being
+/// one: any iterable except `str`
+fn unpacks(value: &Bound<'_, PyAny>) -> bool {
+    !value.is_instance_of::<PyString>() && value.try_iter().is_ok()
+}
+
+/// Collect any iterable into storage of validated items.
+///
+/// Rustique Lists, Python lists and tuples are copied straight from their
+/// storage; anything else goes through the iterator protocol, using `len()`
+/// as a size hint when the object provides one. `offset` is added to the
+/// index reported in type errors.
+fn collect_items(
+    py: Python,
+    _type: &Option<Arc<ElementType>>,
+    iterable: &Bound<'_, PyAny>,
+    offset: usize,
+) -> PyResult<Storage> {
+    let element = _type.as_deref();
+    let mut items = Storage::new(kind(_type), iterable.len().unwrap_or(0));
+
+    if let Ok(list) = iterable.downcast::<List>() {
+        let list = list.try_borrow()?;
+        if list.data.kind() == items.kind() && (_type.is_none() || same_type(py, _type, &list._type)?) {
+            return Ok(list.data.clone());
+        }
+        for i in 0..list.data.len() {
+            items.push(element, list.data.get(py, i)?.bind(py), Some(offset + i))?;
+        }
+        return Ok(items);
+    }
+
+    if let Ok(list) = iterable.downcast::<PyList>() {
+        for (i, item) in list.iter().enumerate() {
+            items.push(element, &item, Some(offset + i))?;
+        }
+        return Ok(items);
+    }
+
+    if let Ok(tuple) = iterable.downcast::<PyTuple>() {
+        for (i, item) in tuple.as_slice().iter().enumerate() {
+            items.push(element, item, Some(offset + i))?;
+        }
+        return Ok(items);
+    }
 
+    for (i, item) in iterable.try_iter()?.enumerate() {
+        items.push(element, &item?, Some(offset + i))?;
+    }
+    Ok(items)
+}
+
+
+/// A list of Python objects, optionally restricted to an element type.
+///
+/// Lists of a Rustique primitive such as `List[i8]` store their values
+/// unboxed and create a new Python object whenever an item is read, so
+/// `a[0] is a[0]` does not hold for them and changing a read item does not
+/// change the list. These lists also support the buffer protocol, and
+/// cannot be resized while a buffer is exported.
 #[derive(Clone)]
 #[pyclass(subclass)]
 pub struct List {
-    data: Vec<PyObject>,
-    _type: Option<Py<PyType>>,
+    pub(crate) data: Storage,
+    pub(crate) _type: Option<Arc<ElementType>>,
+    /// Bumped on every structural modification, checked by live iterators
+    pub(crate) version: u64,
+    /// Number of buffers currently exported
+    pub(crate) exports: usize,
 }
 
 #[pymethods]
 impl List {
 
+    /// Create a new list.
+    ///
+    /// `_type` may be a class or any `typing` expression, such as
+    /// `list[int]`, `int | str`, `Optional[float]` or `Literal["a", "b"]`;
+    /// it is compiled once and checked recursively on every insert.
+    ///
+    /// A single iterable positional argument is unpacked like the argument of
+    /// Python's `list()`, except a `str`, which becomes the only element as a
+    /// non-iterable value does; use `from_iter` to split a string into
+    /// characters. With several positional arguments, each one becomes one
+    /// element.
+    ///
+    /// With `coerce=True`, items that are not of the element type are
+    /// converted by it, e.g. `List[i8](1, 2, coerce=True)` stores `i8`s. This
+    /// applies to every later insert too.
     #[new]
-    #[pyo3(signature = (*values, _type=None))]
-    pub fn __new__(values: &Bound<'_, PyTuple>, _type: Option<Bound<'_, PyType>>) -> PyResult<Self> {
-        let mut vec = Vec::new();
-
-        for item in values.as_slice() {
-            // println!("item: {:?}, item_type: {:?}, expected_type: {:?}", item, item.get_type(), _type);
-            if let Some(ref t) = _type {
-                if !item.is_instance(t)? {
-                    return Err(PyTypeError::new_err(format!(
-                        "Expected item of type {}, got {} at index {}",
-                        t.name()?,
-                        item.get_type().name()?,
-                        vec.len(),
-                    )));
-                }
-            }
-            vec.push(Py::from(item.clone()));
+    #[pyo3(signature = (*values, _type=None, coerce=false))]
+    pub fn __new__(values: &Bound<'_, PyTuple>, _type: Option<Bound<'_, PyAny>>, coerce: bool) -> PyResult<Self> {
+        let py = values.py();
+        let _type = match _type {
+            Some(t) if !t.is_none() => Some(ElementType::compile(&t)?),
+            _ if coerce => return Err(PyValueError::new_err("coerce=True requires an element type")),
+            _ => None,
+        };
+        let _type = match _type {
+            Some(t) if coerce => Some(Arc::new(t.coercing(py)?)),
+            t => t.map(Arc::new),
+        };
+        List::from_values(values, _type)
+    }
 
+    /// `List[T]`, a cached specialisation whose calls create lists of `T`.
+    #[classmethod]
+    pub fn __class_getitem__(cls: &Bound<'_, PyType>, item: &Bound<'_, PyAny>) -> PyResult<Py<ListAlias>> {
+        ListAlias::specialise(cls, item)
+    }
+
+    /// Build a list from any iterable, like Rust's `FromIterator`.
+    ///
+    /// Calls `cls()` first so that typed subclasses keep their `_type`.
+    #[classmethod]
+    #[pyo3(signature = (iterable, _type=None, coerce=false))]
+    pub fn from_iter<'py>(
+        cls: &Bound<'py, PyType>,
+        iterable: &Bound<'py, PyAny>,
+        _type: Option<Bound<'py, PyAny>>,
+        coerce: bool,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let py = cls.py();
+        let kwargs = PyDict::new(py);
+        if let Some(t) = _type {
+            kwargs.set_item("_type", t)?;
         }
-        Ok(List { 
-            data: vec,
-            _type: _type.map(|t| t.into()),
-        })
+        if coerce {
+            kwargs.set_item("coerce", true)?;
+        }
+        let instance = cls.call((), Some(&kwargs))?;
+        instance.downcast::<List>()?.borrow_mut().extend_from(py, iterable)?;
+        Ok(instance)
     }
 
-    fn type_validate(&self, py: Python, value: &Bound<'_, PyAny>) -> PyResult<()> {
-        if let Some(ref t) = self._type {
-            if !value.is_instance(t.bind(py))? {
+    /// Build a list of a Rustique primitive from any object supporting the
+    /// buffer protocol, such as `bytes`, `array.array` or a NumPy array,
+    /// copying its memory once.
+    ///
+    /// The element type defaults to the one matching the buffer's format;
+    /// an explicit `_type` must have the same layout.
+    #[classmethod]
+    #[pyo3(signature = (buffer, _type=None))]
+    pub fn from_buffer<'py>(
+        cls: &Bound<'py, PyType>,
+        buffer: &Bound<'py, PyAny>,
+        _type: Option<Bound<'py, PyAny>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let py = cls.py();
+        let view = BufferView::get(buffer)?;
+        let _type = match _type {
+            Some(t) if !t.is_none() => t,
+            _ => view.kind()?.class(py).into_any(),
+        };
+
+        let kwargs = PyDict::new(py);
+        kwargs.set_item("_type", &_type)?;
+        let instance = cls.call((), Some(&kwargs))?;
+        {
+            let mut list = instance.downcast::<List>()?.borrow_mut();
+            let Some(kind) = kind(&list._type) else {
                 return Err(PyTypeError::new_err(format!(
-                    "Expected item of type {}, got {}",
-                    t.bind(py).name()?,
-                    value.get_type().name()?
+                    "from_buffer requires a Rustique primitive element type, got {}",
+                    _type.repr()?
                 )));
-            }
+            };
+            list.check_resizable()?;
+            list.data = view.to_storage(py, kind)?;
+            list.version += 1;
         }
-        Ok(())
+        Ok(instance)
     }
 
-    fn __richcmp__(&self, py: Python, #[pyo3(from_py_with = "any_to_list")] other: List, op: CompareOp) -> PyResult<PyObject> {
-        match op {
-            CompareOp::Eq => {
-                if self.data.len() != other.data.len() {
-                    return Ok(false.into_py(py));
-                }
-
-                if self._type.is_some() && self._type != other._type {
-                    return Ok(false.into_py(py));
-                }
-
-                for (a, b) in self.data.iter().zip(other.data.iter()) {
-                    if a
-                        .bind(py)
-                        .rich_compare(b, CompareOp::Eq)
-                        .and_then(|res| res.is_truthy())
-                        .unwrap_or(false)
-                    {
-                        continue;
-                    } else {
-                        return Ok(false.into_py(py));
-                    }
-                }
-
-                Ok(true.into_py(py))
-            },
-            CompareOp::Ne => {
-                if self.data.len() != other.data.len() {
-                    return Ok(true.into_py(py));
-                }
+    /// Export unboxed items without copying; see `buffer::export`.
+    unsafe fn __getbuffer__(slf: Bound<'_, Self>, view: *mut ffi::Py_buffer, flags: c_int) -> PyResult<()> {
+        buffer::export(&slf, view, flags)
+    }
 
-                for (a, b) in self.data.iter().zip(other.data.iter()) {
-                    if a
-                        .bind(py)
-                        .rich_compare(b, CompareOp::Eq)
-                        .and_then(|res| res.is_truthy())
-                        .unwrap_or(false)
-                    {
-                        continue;
-                    } else {
-                        return Ok(true.into_py(py));
-                    }
-                }
+    unsafe fn __releasebuffer__(&mut self, view: *mut ffi::Py_buffer) {
+        buffer::release(self, view)
+    }
 
-                Ok(false.into_py(py))
-            },
-            CompareOp::Lt => {
-                for (a, b) in self.data.iter().zip(other.data.iter()) {
-                    if a
-                        .bind(py)
-                        .rich_compare(b, CompareOp::Lt)
-                        .and_then(|res| res.is_truthy())
-                        .unwrap_or(false)
-                    {
-                        return Ok(true.into_py(py));
-                    } else if a
-                        .bind(py)
-                        .rich_compare(b, CompareOp::Gt)
-                        .and_then(|res| res.is_truthy())
-                        .unwrap_or(false)
-                    {
-                        return Ok(false.into_py(py));
-                    }
-                }
+    fn type_validate(&self, value: &Bound<'_, PyAny>) -> PyResult<()> {
+        self.accept(value, None).map(|_| ())
+    }
 
-                Ok(false.into_py(py))
-            },
-            CompareOp::Le => {
-                for (a, b) in self.data.iter().zip(other.data.iter()) {
-                    if a
-                        .bind(py)
-                        .rich_compare(b, CompareOp::Lt)
-                        .and_then(|res| res.is_truthy())
-                        .unwrap_or(false)
-                    {
-                        return Ok(true.into_py(py));
-                    } else if a
-                        .bind(py)
-                        .rich_compare(b, CompareOp::Gt)
-                        .and_then(|res| res.is_truthy())
-                        .unwrap_or(false)
-                    {
-                        return Ok(false.into_py(py));
-                    }
-                }
+    /// Compare lexicographically like Python's `list`.
+    ///
+    /// Equality additionally requires matching element types when `self` is
+    /// typed. Exceptions raised by element comparisons propagate.
+    ///
+    /// Lists of a Rustique primitive compared with a scalar give a
+    /// `List[bool]` mask instead, e.g. `lst > 0`; see `mask` for comparing
+    /// two lists item by item.
+    fn __richcmp__(slf: &Bound<'_, Self>, other: &Bound<'_, PyAny>, op: CompareOp) -> PyResult<PyObject> {
+        let py = slf.py();
+        if !elementwise::is_sequence(other) && slf.borrow().data.kind().is_some() {
+            return match elementwise::mask(&slf.borrow().data, op.into(), other) {
+                Ok(mask) => List::of_kind(py, mask)?.into_py_any(py),
+                Err(_) => Ok(py.NotImplemented()),
+            };
+        }
+        let Ok(other) = any_to_list(other) else {
+            return Ok(py.NotImplemented());
+        };
+        let (data, typed_mismatch) = {
+            let list = slf.borrow();
+            (list.data.clone(), list._type.is_some() && !same_type(py, &list._type, &other._type)?)
+        };
 
-                Ok(true.into_py(py))
-            },
-            CompareOp::Gt => {
-                for (a, b) in self.data.iter().zip(other.data.iter()) {
-                    if a
-                        .bind(py)
-                        .rich_compare(b, CompareOp::Gt)
-                        .and_then(|res| res.is_truthy())
-                        .unwrap_or(false)
-                    {
-                        return Ok(true.into_py(py));
-                    } else if a
-                        .bind(py)
-                        .rich_compare(b, CompareOp::Lt)
-                        .and_then(|res| res.is_truthy())
-                        .unwrap_or(false)
-                    {
-                        return Ok(false.into_py(py));
-                    }
-                }
+        if matches!(op, CompareOp::Eq | CompareOp::Ne) && (data.len() != other.data.len() || typed_mismatch) {
+            return matches!(op, CompareOp::Ne).into_py_any(py);
+        }
 
-                Ok(false.into_py(py))
-            },
-            CompareOp::Ge => {
-                for (a, b) in self.data.iter().zip(other.data.iter()) {
-                    if a
-                        .bind(py)
-                        .rich_compare(b, CompareOp::Gt)
-                        .and_then(|res| res.is_truthy())
-                        .unwrap_or(false)
-                    {
-                        return Ok(true.into_py(py));
-                    } else if a
-                        .bind(py)
-                        .rich_compare(b, CompareOp::Lt)
-                        .and_then(|res| res.is_truthy())
-                        .unwrap_or(false)
-                    {
-                        return Ok(false.into_py(py));
-                    }
-                }
+        if let Some(order) = data.compare(&other.data) {
+            return op.matches(order).into_py_any(py);
+        }
 
-                Ok(true.into_py(py))
-            },
+        let (data, other_data) = (data.objects(py)?, other.data.objects(py)?);
+        for (a, b) in data.iter().zip(other_data.iter()) {
+            let (a, b) = (a.bind(py), b.bind(py));
+            if a.is(b) || a.eq(b)? {
+                continue;
+            }
+            return match op {
+                CompareOp::Eq => false.into_py_any(py),
+                CompareOp::Ne => true.into_py_any(py),
+                _ => Ok(a.rich_compare(b, op)?.unbind()),
+            };
         }
+
+        op.matches(data.len().cmp(&other_data.len())).into_py_any(py)
     }
 
     /// Representation of the list
     pub fn __repr__(&self, py: Python) -> PyResult<String> {
         let values: Vec<String> = self
             .data
+            .objects(py)?
             .iter()
-            .map(|item| item.bind(py).repr().unwrap().to_string())
-            .collect();
+            .map(|item| Ok(item.bind(py).repr()?.to_string()))
+            .collect::<PyResult<_>>()?;
 
         let type_annotation = if let Some(ref t) = self._type {
-            format!("[{}]", t.bind(py).name()?)
+            format!("[{}]", t.name(py)?)
         } else {
             String::new()
         };
@@ -222,12 +334,13 @@ impl List {
     pub fn __str__(&self, py: Python) -> PyResult<String> {
         let values: Vec<String> = self
             .data
+            .objects(py)?
             .iter()
-            .map(|item| item.bind(py).str().unwrap().to_string())
-            .collect();
+            .map(|item| Ok(item.bind(py).str()?.to_string()))
+            .collect::<PyResult<_>>()?;
 
         let type_annotation = if let Some(ref t) = self._type {
-            format!("[{}]", t.bind(py).name()?)
+            format!("[{}]", t.name(py)?)
         } else {
             String::new()
         };
@@ -244,101 +357,620 @@ impl List {
 
     pub fn __getitem__(&self, index: &Bound<'_, PyAny>) -> PyResult<PyObject> {
         if let Ok(idx) = index.extract::<isize>() {
-            let actual_idx = if idx < 0 {
-                (self.data.len() as isize + idx) as usize
-            } else {
-                idx as usize
-            };
-
-            if actual_idx >= self.data.len() {
-                return Err(PyIndexError::new_err(format!(
-                    "Index out of range: got {}, length is {}",
-                    idx, self.data.len()
-                )));
-            }
-
-            return Ok(self.data[actual_idx].clone());
+            let actual_idx = normalize_index(self.data.len(), idx)?;
+            return self.data.get(index.py(), actual_idx);
         }
 
         if let Ok(slice) = index.downcast::<PySlice>() {
             let indices = slice.indices(self.data.len() as isize)?;
-
-            let start = indices.start;
-            let stop = indices.stop;
-            let step = indices.step;
-
-            let mut new_data = Vec::new();
-            let mut i = start;
-
-            let positive_step = step > 0;
-            while (positive_step && i < stop) || (!positive_step && i > stop) {
-                new_data.push(self.data[i as usize].clone());
-                i += step;
-            }
+            let new_data = self.data.select(indices.start, indices.step, indices.slicelength as usize);
 
             return Ok(Py::new(
                 index.py(),
-                List {
-                    data: new_data,
-                    _type: self._type.clone(),
-                },
+                List::new(new_data, self._type.clone()),
             )?
             .into_py(index.py()));
         }
 
+        if let Ok(mask) = index.downcast::<List>() {
+            if let Storage::Bool(keep) = &mask.try_borrow()?.data {
+                if keep.len() != self.data.len() {
+                    return Err(PyIndexError::new_err(format!(
+                        "Boolean mask of length {} does not match List of length {}",
+                        keep.len(),
+                        self.data.len()
+                    )));
+                }
+                return List::new(self.data.masked(keep), self._type.clone()).into_py_any(index.py());
+            }
+        }
+
         Err(PyTypeError::new_err(format!(
             "Invalid index type: {}",
             index.get_type().name()?
         )))
     }
 
-    pub fn __setitem__(&mut self, py: Python, index: &Bound<'_, PyAny>, value: &Bound<'_, PyAny>) -> PyResult<()> {
+    pub fn __setitem__(slf: &Bound<'_, Self>, index: &Bound<'_, PyAny>, value: &Bound<'_, PyAny>) -> PyResult<()> {
+        let py = slf.py();
         if let Ok(idx) = index.extract::<isize>() {
-            let actual_idx = if idx < 0 {
-                (self.data.len() as isize + idx) as usize
-            } else {
-                idx as usize
-            };
-
-            if actual_idx >= self.data.len() {
-                return Err(PyIndexError::new_err(format!(
-                    "Index out of range: got {}, length is {}",
-                    idx, self.data.len()
-                )));
-            }
-
-            self.type_validate(py, value)?;
-
-            self.data[actual_idx] = Py::from(value.clone());
-            return Ok(());
+            let mut list = slf.borrow_mut();
+            let list = &mut *list;
+            let actual_idx = normalize_index(list.data.len(), idx)?;
+            return list.data.set(list._type.as_deref(), actual_idx, value);
         }
 
         if let Ok(slice) = index.downcast::<PySlice>() {
-            let indices = slice.indices(self.data.len() as isize)?;
+            // Validate the whole sequence before touching the list
+            let items = List::collect_from(slf, value, 0)?;
+
+            let mut list = slf.borrow_mut();
+            let indices = slice.indices(list.data.len() as isize)?;
 
             let start = indices.start;
             let stop = indices.stop;
             let step = indices.step;
             let slicelen = indices.slicelength;
 
-            let sequence = value.downcast::<PyList>()
-                .map_err(|_| PyTypeError::new_err("Assigned value must be a list or sequence"))?;
+            if step == 1 {
+                list.check_resizable()?;
+                let stop = stop.max(start);
+                list.data.splice(py, start as usize..stop as usize, items)?;
+                list.version += 1;
+                return Ok(());
+            }
 
-            if sequence.len() != slicelen as usize {
+            if items.len() != slicelen as usize {
                 return Err(PyValueError::new_err(format!(
                     "Attempt to assign sequence of size {} to extended slice of size {}",
-                    sequence.len(),
+                    items.len(),
                     slicelen
                 )));
             }
 
-            let mut i = start;
-            for item in sequence.iter() {
-                self.type_validate(py, &item)?;
+            return list.data.assign(py, start, step, items);
+        }
+
+        Err(PyTypeError::new_err(format!(
+            "Invalid index type: {}",
+            index.get_type().name()?
+        )))
+    }
+
+    pub fn __iter__(slf: &Bound<'_, Self>) -> ListIterator {
+        ListIterator {
+            list: Some(slf.clone().unbind()),
+            index: 0,
+            version: slf.borrow().version,
+        }
+    }
+
+    pub fn __reversed__(slf: &Bound<'_, Self>) -> ListReverseIterator {
+        let list = slf.borrow();
+        ListReverseIterator {
+            list: Some(slf.clone().unbind()),
+            index: list.data.len(),
+            version: list.version,
+        }
+    }
+
+    pub fn __contains__(slf: &Bound<'_, Self>, value: &Bound<'_, PyAny>) -> PyResult<bool> {
+        Ok(List::find_equal(slf, value, 0, usize::MAX)?.is_some())
+    }
+
+    /// Concatenate two lists.
+    ///
+    /// The element type of the result is chosen as follows:
+    /// - two untyped lists give an untyped list;
+    /// - equal types, or a type and one of its subclasses, give the more
+    ///   general of the two;
+    /// - a typed and an untyped list (including a Python `list`) give the
+    ///   typed side's type, and the untyped side's items are validated;
+    /// - unrelated types raise `TypeError`.
+    ///
+    /// For Lists of Rustique numbers, `add` adds item by item instead.
+    fn __add__(&self, py: Python, #[pyo3(from_py_with = "any_to_list")] other: List) -> PyResult<List> {
+        List::concat(py, self, &other)
+    }
+
+    fn __radd__(&self, py: Python, #[pyo3(from_py_with = "any_to_list")] other: List) -> PyResult<List> {
+        List::concat(py, &other, self)
+    }
+
+    /// In-place concatenation behaves like `extend`: any iterable is
+    /// accepted and validated against this list's type.
+    pub fn __iadd__(slf: &Bound<'_, Self>, other: &Bound<'_, PyAny>) -> PyResult<()> {
+        List::extend(slf, other)
+    }
+
+    /// Repetition, as for Python's `list`; see `mul` for multiplying item
+    /// by item.
+    fn __mul__(&self, count: isize) -> PyResult<List> {
+        Ok(List::new(self.data.repeat(count)?, self._type.clone()))
+    }
+
+    fn __rmul__(&self, count: isize) -> PyResult<List> {
+        self.__mul__(count)
+    }
+
+    fn __imul__(&mut self, count: isize) -> PyResult<()> {
+        self.check_resizable()?;
+        self.data = self.data.repeat(count)?;
+        self.version += 1;
+        Ok(())
+    }
+
+    /// Arithmetic item by item for Lists of Rustique primitives, against a
+    /// scalar or a list of the same length. Operators keep their meaning for
+    /// Python lists, so each operation is a named method instead. Integer
+    /// results are checked: overflow raises `OverflowError`.
+    pub fn add(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<List> {
+        self.arith(py, BinOp::Add, Mode::Checked, other)
+    }
+
+    pub fn sub(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<List> {
+        self.arith(py, BinOp::Sub, Mode::Checked, other)
+    }
+
+    pub fn mul(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<List> {
+        self.arith(py, BinOp::Mul, Mode::Checked, other)
+    }
+
+    /// Division item by item; integer Lists give a `List[f64]`.
+    pub fn truediv(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<List> {
+        self.arith(py, BinOp::TrueDiv, Mode::Checked, other)
+    }
+
+    /// Quotient item by item. Integers truncate like the primitives' own
+    /// `//`, floats round toward negative infinity like Python's `//`.
+    pub fn floordiv(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<List> {
+        self.arith(py, BinOp::FloorDiv, Mode::Checked, other)
+    }
+
+    /// Remainder item by item, matching `floordiv`: it takes the sign of
+    /// the dividend for integers and of the divisor for floats.
+    pub fn rem(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<List> {
+        self.arith(py, BinOp::Rem, Mode::Checked, other)
+    }
+
+    /// Bitwise and item by item, also used to intersect masks.
+    pub fn bitand(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<List> {
+        self.arith(py, BinOp::And, Mode::Checked, other)
+    }
+
+    pub fn bitor(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<List> {
+        self.arith(py, BinOp::Or, Mode::Checked, other)
+    }
+
+    pub fn bitxor(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<List> {
+        self.arith(py, BinOp::Xor, Mode::Checked, other)
+    }
+
+    pub fn shl(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<List> {
+        self.arith(py, BinOp::Shl, Mode::Checked, other)
+    }
+
+    pub fn shr(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<List> {
+        self.arith(py, BinOp::Shr, Mode::Checked, other)
+    }
+
+    /// Addition item by item that wraps around at the bounds of the
+    /// integer type instead of raising `OverflowError`.
+    pub fn wrapping_add(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<List> {
+        self.arith(py, BinOp::Add, Mode::Wrapping, other)
+    }
+
+    pub fn wrapping_sub(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<List> {
+        self.arith(py, BinOp::Sub, Mode::Wrapping, other)
+    }
+
+    pub fn wrapping_mul(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<List> {
+        self.arith(py, BinOp::Mul, Mode::Wrapping, other)
+    }
+
+    pub fn wrapping_floordiv(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<List> {
+        self.arith(py, BinOp::FloorDiv, Mode::Wrapping, other)
+    }
+
+    pub fn wrapping_rem(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<List> {
+        self.arith(py, BinOp::Rem, Mode::Wrapping, other)
+    }
+
+    pub fn wrapping_shl(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<List> {
+        self.arith(py, BinOp::Shl, Mode::Wrapping, other)
+    }
+
+    pub fn wrapping_shr(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<List> {
+        self.arith(py, BinOp::Shr, Mode::Wrapping, other)
+    }
+
+    /// Addition item by item that clamps to the bounds of the integer type
+    /// instead of raising `OverflowError`.
+    pub fn saturating_add(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<List> {
+        self.arith(py, BinOp::Add, Mode::Saturating, other)
+    }
+
+    pub fn saturating_sub(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<List> {
+        self.arith(py, BinOp::Sub, Mode::Saturating, other)
+    }
+
+    pub fn saturating_mul(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<List> {
+        self.arith(py, BinOp::Mul, Mode::Saturating, other)
+    }
+
+    /// A list of `f(item)` for each item, like Rust's `Iterator::map`.
+    ///
+    /// The new list keeps this list's element type when every result is
+    /// valid for it, or else takes the Rustique primitive all results are
+    /// instances of, if any. `filter_map`, `flat_map` and `scan` type their
+    /// results the same way.
+    pub fn map(slf: &Bound<'_, Self>, f: &Bound<'_, PyAny>) -> PyResult<List> {
+        let (items, _type) = List::snapshot(slf)?;
+        let mapped = items.iter().map(|item| Ok(f.call1((item,))?.unbind())).collect::<PyResult<_>>()?;
+        List::inferred(slf.py(), mapped, &_type)
+    }
+
+    /// The items for which `predicate(item)` is true.
+    pub fn filter(slf: &Bound<'_, Self>, predicate: &Bound<'_, PyAny>) -> PyResult<List> {
+        let (items, _type) = List::snapshot(slf)?;
+        let mut kept = Vec::new();
+        for item in items {
+            if predicate.call1((&item,))?.is_truthy()? {
+                kept.push(item);
+            }
+        }
+        List::of_items(slf.py(), kept, _type)
+    }
+
+    /// `f(item)` for each item where it is not `None`.
+    pub fn filter_map(slf: &Bound<'_, Self>, f: &Bound<'_, PyAny>) -> PyResult<List> {
+        let (items, _type) = List::snapshot(slf)?;
+        let mut mapped = Vec::new();
+        for item in items {
+            let result = f.call1((item,))?;
+            if !result.is_none() {
+                mapped.push(result.unbind());
+            }
+        }
+        List::inferred(slf.py(), mapped, &_type)
+    }
+
+    /// The items of each iterable `f(item)`, in order.
+    pub fn flat_map(slf: &Bound<'_, Self>, f: &Bound<'_, PyAny>) -> PyResult<List> {
+        let (items, _type) = List::snapshot(slf)?;
+        let mut mapped = Vec::new();
+        for item in items {
+            for result in f.call1((item,))?.try_iter()? {
+                mapped.push(result?.unbind());
+            }
+        }
+        List::inferred(slf.py(), mapped, &_type)
+    }
+
+    /// `init` combined with each item in turn as `f(acc, item)`.
+    pub fn fold(slf: &Bound<'_, Self>, init: PyObject, f: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        let (items, _) = List::snapshot(slf)?;
+        let mut acc = init;
+        for item in items {
+            acc = f.call1((acc, item))?.unbind();
+        }
+        Ok(acc)
+    }
+
+    /// Like `fold`, starting from the first item, or `None` when the list
+    /// is empty.
+    pub fn reduce(slf: &Bound<'_, Self>, f: &Bound<'_, PyAny>) -> PyResult<Option<PyObject>> {
+        let (items, _) = List::snapshot(slf)?;
+        let mut items = items.into_iter();
+        let Some(mut acc) = items.next() else {
+            return Ok(None);
+        };
+        for item in items {
+            acc = f.call1((acc, item))?.unbind();
+        }
+        Ok(Some(acc))
+    }
+
+    /// Each state `f(state, item)`, starting from `init`. Returning `None`
+    /// ends the scan, like returning `None` from the closure in Rust.
+    pub fn scan(slf: &Bound<'_, Self>, init: PyObject, f: &Bound<'_, PyAny>) -> PyResult<List> {
+        let (items, _type) = List::snapshot(slf)?;
+        let py = slf.py();
+        let mut states = Vec::new();
+        let mut state = init;
+        for item in items {
+            let next = f.call1((state, item))?;
+            if next.is_none() {
+                break;
+            }
+            state = next.unbind();
+            states.push(state.clone_ref(py));
+        }
+        List::inferred(py, states, &_type)
+    }
+
+    /// The leading items for which `predicate(item)` is true.
+    pub fn take_while(slf: &Bound<'_, Self>, predicate: &Bound<'_, PyAny>) -> PyResult<List> {
+        let (mut items, _type) = List::snapshot(slf)?;
+        let end = List::leading(&items, predicate)?;
+        items.truncate(end);
+        List::of_items(slf.py(), items, _type)
+    }
+
+    /// The items after the leading ones for which `predicate(item)` is true.
+    pub fn skip_while(slf: &Bound<'_, Self>, predicate: &Bound<'_, PyAny>) -> PyResult<List> {
+        let (mut items, _type) = List::snapshot(slf)?;
+        let start = List::leading(&items, predicate)?;
+        items.drain(..start);
+        List::of_items(slf.py(), items, _type)
+    }
+
+    /// `(index, item)` tuples for the items.
+    pub fn enumerate(&self, py: Python) -> PyResult<List> {
+        let pairs = self
+            .data
+            .objects(py)?
+            .into_iter()
+            .enumerate()
+            .map(|(i, item)| Ok(PyTuple::new(py, [i.into_py_any(py)?, item])?.into_any().unbind()))
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(List::new(pairs.into(), None))
+    }
+
+    /// `(item, other_item)` tuples pairing the items with those of the
+    /// iterable `other`, up to the shorter of the two.
+    pub fn zip(slf: &Bound<'_, Self>, other: &Bound<'_, PyAny>) -> PyResult<List> {
+        let py = slf.py();
+        let (items, _) = List::snapshot(slf)?;
+        let mut pairs = Vec::with_capacity(items.len());
+        for (item, other_item) in items.into_iter().zip(other.try_iter()?) {
+            pairs.push(PyTuple::new(py, [item, other_item?.unbind()])?.into_any().unbind());
+        }
+        Ok(List::new(pairs.into(), None))
+    }
+
+    /// Lists of `size` consecutive items of the same type as this one. The
+    /// last is shorter when `size` does not divide the length.
+    pub fn chunks(&self, py: Python, size: usize) -> PyResult<List> {
+        let size = List::window_size(size)?;
+        self.sublists(py, (0..self.data.len()).step_by(size).map(|start| (start, size.min(self.data.len() - start))))
+    }
+
+    /// Like `chunks`, leaving out the last items when `size` does not divide
+    /// the length.
+    pub fn chunks_exact(&self, py: Python, size: usize) -> PyResult<List> {
+        let size = List::window_size(size)?;
+        self.sublists(py, (0..self.data.len() / size).map(|k| (k * size, size)))
+    }
+
+    /// Lists of every `size` consecutive items, overlapping.
+    pub fn windows(&self, py: Python, size: usize) -> PyResult<List> {
+        let size = List::window_size(size)?;
+        let count = (self.data.len() + 1).saturating_sub(size);
+        self.sublists(py, (0..count).map(|start| (start, size)))
+    }
+
+    /// Lists of the runs of items where `predicate(a, b)` is true for each
+    /// adjacent pair, like Rust's `slice::chunk_by`.
+    pub fn chunk_by(slf: &Bound<'_, Self>, predicate: &Bound<'_, PyAny>) -> PyResult<List> {
+        let py = slf.py();
+        let (items, _type) = List::snapshot(slf)?;
+        let mut runs = Vec::new();
+        let mut start = 0;
+        for i in 1..=items.len() {
+            if i == items.len() || !predicate.call1((&items[i - 1], &items[i]))?.is_truthy()? {
+                let run = List::of_items(py, items[start..i].to_vec(), _type.clone())?;
+                runs.push(Py::new(py, run)?.into_any());
+                start = i;
+            }
+        }
+        Ok(List::new(runs.into(), None))
+    }
+
+    /// The name `chunk_by` had before it was stabilised in Rust.
+    pub fn group_by(slf: &Bound<'_, Self>, predicate: &Bound<'_, PyAny>) -> PyResult<List> {
+        List::chunk_by(slf, predicate)
+    }
+
+    /// The items for which `predicate(item)` is true and those for which
+    /// it is false, both keeping the element type.
+    pub fn partition(slf: &Bound<'_, Self>, predicate: &Bound<'_, PyAny>) -> PyResult<(List, List)> {
+        let py = slf.py();
+        let (items, _type) = List::snapshot(slf)?;
+        let (mut matching, mut rest) = (Vec::new(), Vec::new());
+        for item in items {
+            if predicate.call1((&item,))?.is_truthy()? {
+                matching.push(item);
+            } else {
+                rest.push(item);
+            }
+        }
+        Ok((List::of_items(py, matching, _type.clone())?, List::of_items(py, rest, _type)?))
+    }
+
+    /// Index of the first item for which `predicate(item)` is true, or `None`.
+    pub fn position(slf: &Bound<'_, Self>, predicate: &Bound<'_, PyAny>) -> PyResult<Option<usize>> {
+        let (items, _) = List::snapshot(slf)?;
+        for (i, item) in items.iter().enumerate() {
+            if predicate.call1((item,))?.is_truthy()? {
+                return Ok(Some(i));
+            }
+        }
+        Ok(None)
+    }
 
-                self.data[i as usize] = Py::from(item);
-                i += step;
+    /// Index of the last item for which `predicate(item)` is true, or `None`.
+    pub fn rposition(slf: &Bound<'_, Self>, predicate: &Bound<'_, PyAny>) -> PyResult<Option<usize>> {
+        let (items, _) = List::snapshot(slf)?;
+        for (i, item) in items.iter().enumerate().rev() {
+            if predicate.call1((item,))?.is_truthy()? {
+                return Ok(Some(i));
             }
+        }
+        Ok(None)
+    }
+
+    /// Whether `predicate(item)` is true for some item, stopping at the first.
+    pub fn any(slf: &Bound<'_, Self>, predicate: &Bound<'_, PyAny>) -> PyResult<bool> {
+        Ok(List::position(slf, predicate)?.is_some())
+    }
+
+    /// Whether `predicate(item)` is true for every item, stopping at the
+    /// first for which it is not.
+    pub fn all(slf: &Bound<'_, Self>, predicate: &Bound<'_, PyAny>) -> PyResult<bool> {
+        let (items, _) = List::snapshot(slf)?;
+        for item in items {
+            if !predicate.call1((item,))?.is_truthy()? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// The first item for which `predicate(item)` is true, or `None`.
+    pub fn find(slf: &Bound<'_, Self>, predicate: &Bound<'_, PyAny>) -> PyResult<Option<PyObject>> {
+        let (items, _) = List::snapshot(slf)?;
+        for item in items {
+            if predicate.call1((&item,))?.is_truthy()? {
+                return Ok(Some(item));
+            }
+        }
+        Ok(None)
+    }
+
+    /// The first `f(item)` that is not `None`, or `None`.
+    pub fn find_map(slf: &Bound<'_, Self>, f: &Bound<'_, PyAny>) -> PyResult<Option<PyObject>> {
+        let (items, _) = List::snapshot(slf)?;
+        for item in items {
+            let result = f.call1((item,))?;
+            if !result.is_none() {
+                return Ok(Some(result.unbind()));
+            }
+        }
+        Ok(None)
+    }
+
+    /// The first item with the smallest `key(item)`, or `None` when the list
+    /// is empty.
+    pub fn min_by_key(slf: &Bound<'_, Self>, key: &Bound<'_, PyAny>) -> PyResult<Option<PyObject>> {
+        List::extreme_by_key(slf, key, false)
+    }
+
+    /// The last item with the largest `key(item)`, as in Rust, or `None`
+    /// when the list is empty.
+    pub fn max_by_key(slf: &Bound<'_, Self>, key: &Bound<'_, PyAny>) -> PyResult<Option<PyObject>> {
+        List::extreme_by_key(slf, key, true)
+    }
+
+    /// The sum of the items.
+    ///
+    /// Lists of Rustique numbers add natively, by default in `i128`, `u128`
+    /// or `f64` so that small integer types cannot overflow; `accumulator`
+    /// picks another primitive, e.g. `rs.i16`, and `OverflowError` is raised
+    /// when the sum does not fit it. Floats use compensated summation. Other
+    /// lists add their items with `+`, starting from 0.
+    #[pyo3(signature = (*, accumulator=None))]
+    pub fn sum(&self, py: Python, accumulator: Option<&Bound<'_, PyType>>) -> PyResult<PyObject> {
+        stats::reduce(py, &self.data, accumulator, false)
+    }
+
+    /// The product of the items, accumulated like `sum`.
+    #[pyo3(signature = (*, accumulator=None))]
+    pub fn product(&self, py: Python, accumulator: Option<&Bound<'_, PyType>>) -> PyResult<PyObject> {
+        stats::reduce(py, &self.data, accumulator, true)
+    }
+
+    /// The first smallest item, like `min()`.
+    pub fn min(&self, py: Python) -> PyResult<PyObject> {
+        self.data.get(py, stats::extreme(py, &self.data, false, "min")?)
+    }
+
+    /// The first largest item, like `max()`.
+    pub fn max(&self, py: Python) -> PyResult<PyObject> {
+        self.data.get(py, stats::extreme(py, &self.data, true, "max")?)
+    }
+
+    /// The index of the first smallest item.
+    pub fn argmin(&self, py: Python) -> PyResult<usize> {
+        stats::extreme(py, &self.data, false, "argmin")
+    }
+
+    /// The index of the first largest item.
+    pub fn argmax(&self, py: Python) -> PyResult<usize> {
+        stats::extreme(py, &self.data, true, "argmax")
+    }
+
+    /// The arithmetic mean. Items of untyped lists are read with `float()`,
+    /// as they are by the other statistics.
+    pub fn mean(&self, py: Python) -> PyResult<f64> {
+        stats::mean(py, &self.data)
+    }
+
+    /// The variance, dividing by `len - ddof`: 0 for the population and 1
+    /// for a sample.
+    #[pyo3(signature = (ddof=0))]
+    pub fn variance(&self, py: Python, ddof: usize) -> PyResult<f64> {
+        stats::variance(py, &self.data, ddof)
+    }
+
+    /// The standard deviation, the square root of `variance(ddof)`.
+    #[pyo3(signature = (ddof=0))]
+    pub fn std(&self, py: Python, ddof: usize) -> PyResult<f64> {
+        Ok(stats::variance(py, &self.data, ddof)?.sqrt())
+    }
+
+    pub fn median(&self, py: Python) -> PyResult<f64> {
+        stats::median(py, &self.data)
+    }
+
+    /// The `q` quantile for `0 <= q <= 1`, interpolating linearly.
+    pub fn quantile(&self, py: Python, q: f64) -> PyResult<f64> {
+        stats::quantile(py, &self.data, q)
+    }
+
+    /// Counts of the items in `bins` equal-width bins over `range`, by
+    /// default from the smallest to the largest item, as a `List[u64]`,
+    /// along with the `List[f64]` of the `bins + 1` bin edges.
+    #[pyo3(signature = (bins=10, range=None))]
+    pub fn histogram(&self, py: Python, bins: usize, range: Option<(f64, f64)>) -> PyResult<(List, List)> {
+        let (counts, edges) = stats::histogram(py, &self.data, bins, range)?;
+        Ok((List::of_kind(py, counts)?, List::of_kind(py, edges)?))
+    }
+
+    /// A `List[bool]` of `item <op> value` for each item, where `other` is
+    /// a scalar or a list of the same length, e.g. `a.mask("<", b)`.
+    pub fn mask(&self, py: Python, op: &str, other: &Bound<'_, PyAny>) -> PyResult<List> {
+        let mask = elementwise::mask(&self.data, Predicate::parse(op)?, other)?;
+        List::of_kind(py, mask)
+    }
+
+    pub fn __delitem__(&mut self, index: &Bound<'_, PyAny>) -> PyResult<()> {
+        self.check_resizable()?;
+        if let Ok(idx) = index.extract::<isize>() {
+            let actual_idx = normalize_index(self.data.len(), idx)?;
+            self.data.remove(index.py(), actual_idx)?;
+            self.version += 1;
+            return Ok(());
+        }
+
+        if let Ok(slice) = index.downcast::<PySlice>() {
+            let indices = slice.indices(self.data.len() as isize)?;
+            if indices.slicelength == 0 {
+                return Ok(());
+            }
+
+            if indices.step == 1 {
+                self.data.drain(indices.start as usize..indices.stop as usize);
+            } else {
+                let mut remove = vec![false; self.data.len()];
+                let mut i = indices.start;
+                for _ in 0..indices.slicelength {
+                    remove[i as usize] = true;
+                    i += indices.step;
+                }
+                self.data.remove_flagged(&remove);
+            }
+            self.version += 1;
             return Ok(());
         }
 
@@ -348,123 +980,610 @@ impl List {
         )))
     }
 
-    pub fn __delitem__(&mut self, index: isize) {
-        self.data.remove(index as usize);
+    pub fn append(&mut self, value: &Bound<'_, PyAny>) -> PyResult<()> {
+        self.check_resizable()?;
+        let index = self.data.len();
+        self.data.push(self._type.as_deref(), value, Some(index))?;
+        self.version += 1;
+        Ok(())
     }
 
-    pub fn append(&mut self, py: Python, value: &Bound<'_, PyAny>) -> PyResult<()> {
-        if let Some(ref t) = self._type {
-            if !value.is_instance(t.bind(py))? {
-                return Err(PyTypeError::new_err(format!(
-                    "Expected item of type {}, got {}",
-                    t.bind(py).name()?,
-                    value.get_type().name()?
-                )));
+    pub fn clear(&mut self) -> PyResult<()> {
+        self.check_resizable()?;
+        self.data.clear();
+        self.version += 1;
+        Ok(())
+    }
+
+    /// Number of items equal to `value`. Like `in`, this compares without
+    /// validating `value`, so one of the wrong type is simply not found.
+    pub fn count(slf: &Bound<'_, Self>, value: &Bound<'_, PyAny>) -> PyResult<usize> {
+        let mut count = 0;
+        let mut start = 0;
+        while let Some(i) = List::find_equal(slf, value, start, usize::MAX)? {
+            count += 1;
+            start = i + 1;
+        }
+        Ok(count)
+    }
+
+    pub fn copy(&self) -> Self {
+        List::new(self.data.clone(), self._type.clone())
+    }
+
+    pub fn extend(slf: &Bound<'_, Self>, values: &Bound<'_, PyAny>) -> PyResult<()> {
+        let offset = slf.borrow().data.len();
+        let items = List::collect_from(slf, values, offset)?;
+        let mut list = slf.borrow_mut();
+        list.check_resizable()?;
+        list.data.extend(slf.py(), items)?;
+        list.version += 1;
+        Ok(())
+    }
+
+    /// Position of the first item equal to `value` within `[start, stop)`,
+    /// with `start` and `stop` interpreted like slice bounds. As in `count`,
+    /// a value of the wrong type is not found.
+    #[pyo3(signature = (value, start=0, stop=isize::MAX))]
+    pub fn index(
+        slf: &Bound<'_, Self>,
+        value: &Bound<'_, PyAny>,
+        #[pyo3(from_py_with = "slice_bound")] start: isize,
+        #[pyo3(from_py_with = "slice_bound")] stop: isize,
+    ) -> PyResult<usize> {
+        let len = slf.borrow().data.len();
+        List::find_equal(slf, value, insert_position(len, start), insert_position(len, stop))?
+            .ok_or_else(|| PyErr::new::<PyValueError, _>("Value not found"))
+    }
+
+    /// Insert `value` before `index`, clamping out-of-range indices like `list.insert`.
+    pub fn insert(&mut self, index: isize, value: &Bound<'_, PyAny>) -> PyResult<()> {
+        self.check_resizable()?;
+        let at = insert_position(self.data.len(), index);
+        self.data.insert(self._type.as_deref(), at, value)?;
+        self.version += 1;
+        Ok(())
+    }
+
+    /// Insert every item of `values` before `index`, all or nothing.
+    pub fn insert_many(slf: &Bound<'_, Self>, index: isize, values: &Bound<'_, PyAny>) -> PyResult<()> {
+        let items = List::collect_from(slf, values, 0)?;
+        let mut list = slf.borrow_mut();
+        list.check_resizable()?;
+        let at = insert_position(list.data.len(), index);
+        list.data.splice(slf.py(), at..at, items)?;
+        list.version += 1;
+        Ok(())
+    }
+
+    #[pyo3(signature = (index=-1))]
+    pub fn pop(&mut self, py: Python, index: isize) -> PyResult<PyObject> {
+        self.check_resizable()?;
+        if self.data.is_empty() {
+            return Err(PyErr::new::<PyIndexError, _>("pop from empty list"));
+        }
+        let actual_idx = normalize_index(self.data.len(), index)?;
+        let item = self.data.remove(py, actual_idx)?;
+        self.version += 1;
+        Ok(item)
+    }
+
+    /// Remove the first item equal to `value`.
+    pub fn remove(slf: &Bound<'_, Self>, value: &Bound<'_, PyAny>) -> PyResult<()> {
+        let index = List::find_equal(slf, value, 0, usize::MAX)?
+            .ok_or_else(|| PyErr::new::<PyValueError, _>("List.remove(x): x not in list"))?;
+
+        let mut list = slf.borrow_mut();
+        list.check_resizable()?;
+        list.data.remove(slf.py(), index)?;
+        list.version += 1;
+        Ok(())
+    }
+
+    pub fn reverse(&mut self) {
+        self.data.reverse();
+        self.version += 1;
+    }
+
+    /// Stable sort in place, using only `<` like Python's `list.sort`.
+    #[pyo3(signature = (*, key=None, reverse=false))]
+    pub fn sort(slf: &Bound<'_, Self>, key: Option<&Bound<'_, PyAny>>, reverse: bool) -> PyResult<()> {
+        let native = key.is_none().then_some((reverse, true));
+        List::sort_in_place(slf, native, |py, data| sort_items(py, data, key, reverse, true))
+    }
+
+    /// Sort in place without preserving the order of equal items.
+    #[pyo3(signature = (*, key=None, reverse=false))]
+    pub fn sort_unstable(slf: &Bound<'_, Self>, key: Option<&Bound<'_, PyAny>>, reverse: bool) -> PyResult<()> {
+        let native = key.is_none().then_some((reverse, false));
+        List::sort_in_place(slf, native, |py, data| sort_items(py, data, key, reverse, false))
+    }
+
+    /// Stable sort calling `key` on both sides of every comparison, like
+    /// Rust's `sort_by_key`. Prefer `sort_by_cached_key` for expensive keys.
+    #[pyo3(signature = (key, *, reverse=false))]
+    pub fn sort_by_key(slf: &Bound<'_, Self>, key: &Bound<'_, PyAny>, reverse: bool) -> PyResult<()> {
+        List::sort_in_place(slf, None, |_, data| {
+            let mut data = data.to_vec();
+            try_merge_sort(&mut data, &mut |a: &PyObject, b: &PyObject| {
+                let (a, b) = if reverse { (b, a) } else { (a, b) };
+                key.call1((a,))?.lt(key.call1((b,))?)
+            })?;
+            Ok(data)
+        })
+    }
+
+    /// Stable sort calling `key` once per item.
+    #[pyo3(signature = (key, *, reverse=false))]
+    pub fn sort_by_cached_key(slf: &Bound<'_, Self>, key: &Bound<'_, PyAny>, reverse: bool) -> PyResult<()> {
+        List::sort_in_place(slf, None, |py, data| sort_items(py, data, Some(key), reverse, true))
+    }
+
+    /// A sorted copy of the list, like `sorted()`.
+    #[pyo3(signature = (*, key=None, reverse=false))]
+    pub fn sorted(&self, py: Python, key: Option<&Bound<'_, PyAny>>, reverse: bool) -> PyResult<List> {
+        let mut data = self.data.clone();
+        if key.is_some() || !data.sort(reverse, true) {
+            let sorted = sort_items(py, &self.data.objects(py)?, key, reverse, true)?;
+            data = Storage::from_objects(py, data.kind(), sorted)?;
+        }
+        Ok(List::new(data, self._type.clone()))
+    }
+
+    /// Whether no item is less than the one before it.
+    #[pyo3(signature = (*, key=None, reverse=false))]
+    pub fn is_sorted(&self, py: Python, key: Option<&Bound<'_, PyAny>>, reverse: bool) -> PyResult<bool> {
+        let items = self.data.objects(py)?;
+        let keys: Vec<Bound<'_, PyAny>> = match key {
+            Some(key) => items.iter().map(|item| key.call1((item,))).collect::<PyResult<_>>()?,
+            None => items.iter().map(|item| item.bind(py).clone()).collect(),
+        };
+
+        for pair in keys.windows(2) {
+            let (a, b) = if reverse { (&pair[0], &pair[1]) } else { (&pair[1], &pair[0]) };
+            if a.lt(b)? {
+                return Ok(false);
             }
         }
+        Ok(true)
+    }
 
-        self.data.push(Py::from(value.clone()));
+    /// Stable sort in place on the thread pool, with the GIL released.
+    /// Only Lists of Rustique primitives are supported, and not while a
+    /// buffer is exported.
+    #[pyo3(signature = (*, reverse=false))]
+    pub fn par_sort(&mut self, py: Python, reverse: bool) -> PyResult<()> {
+        self.check_unexported()?;
+        parallel::sort(py, &mut self.data, reverse, true)?;
+        self.version += 1;
         Ok(())
     }
 
-    pub fn clear(&mut self) {
-        self.data.clear();
+    /// Like `par_sort`, without preserving the order of equal items.
+    #[pyo3(signature = (*, reverse=false))]
+    pub fn par_sort_unstable(&mut self, py: Python, reverse: bool) -> PyResult<()> {
+        self.check_unexported()?;
+        parallel::sort(py, &mut self.data, reverse, false)?;
+        self.version += 1;
+        Ok(())
     }
 
-    pub fn count(&self, py: Python, value: &Bound<'_, PyAny>) -> PyResult<usize> {
-        if let Some(ref t) = self._type {
-            if !value.is_instance(t.bind(py))? {
-                return Err(PyTypeError::new_err(format!(
-                    "Expected item of type {}, got {}",
-                    t.bind(py).name()?,
-                    value.get_type().name()?
-                )));
+    /// The sum of the items as an `int` or `float`, computed in parallel.
+    /// Integers never wrap; a `List[bool]` counts its `True` items.
+    pub fn par_sum(&self, py: Python) -> PyResult<PyObject> {
+        parallel::sum(py, &self.parallel_data())
+    }
+
+    /// The first smallest item, computed in parallel.
+    pub fn par_min(&self, py: Python) -> PyResult<PyObject> {
+        parallel::extreme(py, &self.parallel_data(), false)
+    }
+
+    /// The first largest item, computed in parallel.
+    pub fn par_max(&self, py: Python) -> PyResult<PyObject> {
+        parallel::extreme(py, &self.parallel_data(), true)
+    }
+
+    /// A new list of the same type with the built-in `op` applied to every
+    /// item in parallel, e.g. `par_map("mul", 2)` or `par_map("sqrt")`.
+    #[pyo3(signature = (op, operand=None))]
+    pub fn par_map(&self, py: Python, op: &str, operand: Option<&Bound<'_, PyAny>>) -> PyResult<List> {
+        let data = parallel::map(py, &self.parallel_data(), op, operand)?;
+        Ok(List::new(data, self._type.clone()))
+    }
+
+    /// A new list of the items for which `item <op> value` holds, e.g.
+    /// `par_filter(">", 0)`, in their original order.
+    pub fn par_filter(&self, py: Python, op: &str, value: &Bound<'_, PyAny>) -> PyResult<List> {
+        let data = parallel::filter(py, &self.parallel_data(), op, value)?;
+        Ok(List::new(data, self._type.clone()))
+    }
+
+    /// Remove consecutive equal items in place, in parallel.
+    pub fn par_dedup(&mut self, py: Python) -> PyResult<()> {
+        self.check_resizable()?;
+        parallel::dedup(py, &mut self.data)?;
+        self.version += 1;
+        Ok(())
+    }
+}
+
+impl List {
+    pub(crate) fn new(data: Storage, _type: Option<Arc<ElementType>>) -> Self {
+        List { data, _type, version: 0, exports: 0 }
+    }
+
+    /// The items and element type, copied so that callbacks may modify the
+    /// list while they run.
+    fn snapshot(slf: &Bound<'_, Self>) -> PyResult<(Vec<PyObject>, Option<Arc<ElementType>>)> {
+        let list = slf.try_borrow()?;
+        Ok((list.data.objects(slf.py())?, list._type.clone()))
+    }
+
+    /// A list of `items` taken from a list of element type `_type`.
+    fn of_items(py: Python, items: Vec<PyObject>, _type: Option<Arc<ElementType>>) -> PyResult<Self> {
+        Ok(List::new(Storage::from_objects(py, kind(&_type), items)?, _type))
+    }
+
+    /// A list of `items` computed from a list of element type `_type`, typed
+    /// as described for `map`.
+    fn inferred(py: Python, items: Vec<PyObject>, _type: &Option<Arc<ElementType>>) -> PyResult<Self> {
+        if let Some(t) = _type {
+            let mut valid = true;
+            for item in &items {
+                if !t.is_valid(item.bind(py))? {
+                    valid = false;
+                    break;
+                }
+            }
+            if valid {
+                return List::of_items(py, items, _type.clone());
             }
         }
-    
-        Ok(self
-            .data
-            .iter()
-            .filter(|item| {
-                item.bind(py)
-                    .rich_compare(value, pyo3::basic::CompareOp::Eq)
-                    .and_then(|res| res.is_truthy())
-                    .unwrap_or(false)
+        let Some(cls) = items.first().map(|item| item.bind(py).get_type()) else {
+            return Ok(List::new(items.into(), None));
+        };
+        let shared = Kind::of(&cls).is_some() && items.iter().all(|item| item.bind(py).get_type().is(&cls));
+        if !shared {
+            return Ok(List::new(items.into(), None));
+        }
+        let _type = Some(Arc::new(ElementType::compile(cls.as_any())?));
+        List::of_items(py, items, _type)
+    }
+
+    /// The number of leading items for which `predicate(item)` is true.
+    fn leading(items: &[PyObject], predicate: &Bound<'_, PyAny>) -> PyResult<usize> {
+        for (i, item) in items.iter().enumerate() {
+            if !predicate.call1((item,))?.is_truthy()? {
+                return Ok(i);
+            }
+        }
+        Ok(items.len())
+    }
+
+    /// Reject the zero size Rust's `chunks` and `windows` panic on.
+    fn window_size(size: usize) -> PyResult<usize> {
+        if size == 0 {
+            return Err(PyValueError::new_err("Size must be non-zero"));
+        }
+        Ok(size)
+    }
+
+    /// An untyped list of the lists of `len` items at each `start`, typed
+    /// like this one.
+    fn sublists(&self, py: Python, ranges: impl Iterator<Item = (usize, usize)>) -> PyResult<List> {
+        let lists = ranges
+            .map(|(start, len)| {
+                let data = self.data.select(start as isize, 1, len);
+                Ok(Py::new(py, List::new(data, self._type.clone()))?.into_any())
             })
-            .count())
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(List::new(lists.into(), None))
     }
 
-    pub fn copy(&self) -> Self {
-        List {
-            data: self.data.clone(),
-            _type: self._type.clone(),
+    /// The first item with the smallest key, or the last with the largest
+    /// one when `max` is set, matching Rust's `min_by_key` and `max_by_key`.
+    fn extreme_by_key(slf: &Bound<'_, Self>, key: &Bound<'_, PyAny>, max: bool) -> PyResult<Option<PyObject>> {
+        let (items, _) = List::snapshot(slf)?;
+        let mut best: Option<(Bound<'_, PyAny>, PyObject)> = None;
+        for item in items {
+            let k = key.call1((&item,))?;
+            let replace = match &best {
+                None => true,
+                Some((best_key, _)) if max => !k.lt(best_key)?,
+                Some((best_key, _)) => k.lt(best_key)?,
+            };
+            if replace {
+                best = Some((k, item));
+            }
+        }
+        Ok(best.map(|(_, item)| item))
+    }
+
+    /// A list typed by the primitive `data` holds, such as the `List[bool]`
+    /// of a mask.
+    pub(crate) fn of_kind(py: Python, data: Storage) -> PyResult<Self> {
+        let _type = match data.kind() {
+            Some(kind) => Some(Arc::new(ElementType::compile(kind.class(py).as_any())?)),
+            None => None,
+        };
+        Ok(List::new(data, _type))
+    }
+
+    fn arith(&self, py: Python, op: BinOp, mode: Mode, other: &Bound<'_, PyAny>) -> PyResult<List> {
+        let data = elementwise::binary(&self.data, op, mode, other)?;
+        if data.kind() == self.data.kind() {
+            Ok(List::new(data, self._type.clone()))
+        } else {
+            List::of_kind(py, data)
         }
     }
 
-    pub fn extend(&mut self, py: Python, values: &Bound<'_, PyList>) -> PyResult<()> {
-        for item in values.iter() {
-            self.append(py, &item)?;
+    /// Raise `BufferError` while a buffer is exported, as the items must not move.
+    fn check_resizable(&self) -> PyResult<()> {
+        if self.exports > 0 {
+            return Err(PyBufferError::new_err(
+                "Existing exports of data: List cannot be resized",
+            ));
         }
         Ok(())
     }
 
-    pub fn index(&self, py: Python, value: &Bound<'_, PyAny>) -> PyResult<usize> {
-        if let Some(ref t) = self._type {
-            if !value.is_instance(t.bind(py))? {
-                return Err(PyTypeError::new_err(format!(
-                    "Expected item of type {}, got {}",
-                    t.bind(py).name()?,
-                    value.get_type().name()?
-                )));
+    /// Raise `BufferError` while a buffer is exported: once the GIL is
+    /// released, other threads could access the items through it.
+    fn check_unexported(&self) -> PyResult<()> {
+        if self.exports > 0 {
+            return Err(PyBufferError::new_err(
+                "Existing exports of data: List cannot be sorted in parallel",
+            ));
+        }
+        Ok(())
+    }
+
+    /// The items for a parallel method that only reads them, copied while a
+    /// buffer is exported so that writes through it cannot race the workers.
+    fn parallel_data(&self) -> Cow<'_, Storage> {
+        match self.exports {
+            0 => Cow::Borrowed(&self.data),
+            _ => Cow::Owned(self.data.clone()),
+        }
+    }
+
+    fn accept(&self, value: &Bound<'_, PyAny>, index: Option<usize>) -> PyResult<PyObject> {
+        match self._type {
+            Some(ref t) => t.accept(value, index),
+            None => Ok(value.clone().unbind()),
+        }
+    }
+
+    pub(crate) fn from_values(values: &Bound<'_, PyTuple>, _type: Option<Arc<ElementType>>) -> PyResult<Self> {
+        let py = values.py();
+        if values.len() == 1 {
+            let value = values.get_item(0)?;
+            if unpacks(&value) {
+                let data = collect_items(py, &_type, &value, 0)?;
+                return Ok(List::new(data, _type));
             }
         }
-    
-        for (i, item) in self.data.iter().enumerate() {
-            if item
-                .bind(py)
-                .rich_compare(value, pyo3::basic::CompareOp::Eq)?
-                .is_truthy()?
-            {
-                return Ok(i);
+
+        let data = collect_items(py, &_type, values.as_any(), 0)?;
+        Ok(List::new(data, _type))
+    }
+
+    fn concat(py: Python, a: &List, b: &List) -> PyResult<List> {
+        let _type = match (&a._type, &b._type) {
+            (None, None) => None,
+            (Some(t), None) | (None, Some(t)) => Some(t.clone()),
+            (Some(x), Some(y)) => {
+                let (sub_xy, sub_yx) = match (x.as_type(), y.as_type()) {
+                    (Some(tx), Some(ty)) => (
+                        tx.bind(py).is_subclass(ty.bind(py))?,
+                        ty.bind(py).is_subclass(tx.bind(py))?,
+                    ),
+                    _ => (false, false),
+                };
+                if sub_yx || x.same(py, y)? {
+                    Some(x.clone())
+                } else if sub_xy {
+                    Some(y.clone())
+                } else {
+                    return Err(PyTypeError::new_err(format!(
+                        "Cannot concatenate List[{}] and List[{}]",
+                        x.name(py)?,
+                        y.name(py)?
+                    )));
+                }
+            }
+        };
+
+        let mut data = Storage::new(kind(&_type), a.data.len() + b.data.len());
+        for side in [a, b] {
+            if side.data.kind() == data.kind() && (_type.is_none() || side._type.is_some()) {
+                data.extend(py, side.data.clone())?;
+                continue;
+            }
+            for i in 0..side.data.len() {
+                let at = data.len();
+                data.push(_type.as_deref(), side.data.get(py, i)?.bind(py), Some(at))?;
             }
         }
-    
-        Err(PyErr::new::<PyValueError, _>("Value not found"))
+        Ok(List::new(data, _type))
     }
 
-    pub fn insert(&mut self, py: Python, index: isize, value: &Bound<'_, PyAny>) -> PyResult<()> {
-        if let Some(ref t) = self._type {
-            if !value.is_instance(t.bind(py))? {
-                return Err(PyTypeError::new_err(format!(
-                    "Expected item of type {}, got {}",
-                    t.bind(py).name()?,
-                    value.get_type().name()?
-                )));
+    /// Run `sort` on a snapshot of the data and store the result, so that an
+    /// exception leaves the list untouched.
+    ///
+    /// `native` holds `(reverse, stable)` when unboxed items may be sorted
+    /// without calling into Python, as when no key is given.
+    fn sort_in_place<F>(slf: &Bound<'_, Self>, native: Option<(bool, bool)>, sort: F) -> PyResult<()>
+    where
+        F: FnOnce(Python, &[PyObject]) -> PyResult<Vec<PyObject>>,
+    {
+        let py = slf.py();
+        let (mut sorted, version) = {
+            let list = slf.borrow();
+            list.check_resizable()?;
+            (list.data.clone(), list.version)
+        };
+
+        let done = native.is_some_and(|(reverse, stable)| sorted.sort(reverse, stable));
+        if !done {
+            let items = sort(py, &sorted.objects(py)?)?;
+            sorted = Storage::from_objects(py, sorted.kind(), items)?;
+        }
+
+        let mut list = slf.borrow_mut();
+        if list.version != version {
+            return Err(PyValueError::new_err("List modified during sort"));
+        }
+        list.check_resizable()?;
+        list.data = sorted;
+        list.version += 1;
+        Ok(())
+    }
+
+    /// Index of the first item in `[start, stop)` equal to `value`.
+    ///
+    /// The list is only borrowed while fetching each item, so `__eq__`
+    /// implementations may inspect or even mutate it, as with Python's list.
+    fn find_equal(slf: &Bound<'_, Self>, value: &Bound<'_, PyAny>, start: usize, stop: usize) -> PyResult<Option<usize>> {
+        let py = slf.py();
+        if let Some(found) = slf.borrow().data.position(value, start, stop) {
+            return Ok(found);
+        }
+
+        let mut i = start;
+        while i < stop {
+            let item = {
+                let list = slf.borrow();
+                if i >= list.data.len() {
+                    break;
+                }
+                list.data.get(py, i)?
+            };
+            let item = item.bind(py);
+            if item.is(value) || item.eq(value)? {
+                return Ok(Some(i));
             }
+            i += 1;
+        }
+        Ok(None)
+    }
+
+    /// Collect and validate `values` against this list's type without
+    /// holding a borrow, so that `values` may be the list itself.
+    fn collect_from(slf: &Bound<'_, Self>, values: &Bound<'_, PyAny>, offset: usize) -> PyResult<Storage> {
+        if values.is(slf) {
+            return Ok(slf.borrow().data.clone());
         }
-    
-        self.data.insert(index as usize, Py::from(value.clone()));
+        let _type = slf.borrow()._type.clone();
+        collect_items(slf.py(), &_type, values, offset)
+    }
+
+    fn extend_from(&mut self, py: Python, values: &Bound<'_, PyAny>) -> PyResult<()> {
+        self.check_resizable()?;
+        let items = collect_items(py, &self._type, values, self.data.len())?;
+        self.data.extend(py, items)?;
+        self.version += 1;
         Ok(())
     }
+}
 
-    pub fn pop(&mut self, py: Python) -> PyResult<PyObject> {
-        self.data.pop().ok_or_else(|| {
-            PyErr::new::<PyIndexError, _>("pop from empty list")
-        })
+fn check_version(list: &List, version: u64) -> PyResult<()> {
+    if list.version != version {
+        return Err(PyRuntimeError::new_err("List was modified during iteration"));
     }
+    Ok(())
+}
+
+/// Forward iterator over a `List`.
+///
+/// Like a Rust iterator borrowing a `Vec`, it is invalidated by any
+/// structural modification of the list and raises instead of skipping or
+/// repeating elements. Once exhausted it releases the list and stays
+/// exhausted, like Python's own iterators.
+#[pyclass(name = "ListIterator")]
+pub struct ListIterator {
+    list: Option<Py<List>>,
+    index: usize,
+    version: u64,
+}
 
-    pub fn remove(&mut self, index: isize) {
-        self.data.remove(index as usize);
+#[pymethods]
+impl ListIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
     }
 
-    pub fn reverse(&mut self) {
-        self.data.reverse();
+    fn __next__(&mut self, py: Python) -> PyResult<Option<PyObject>> {
+        let Some(list) = &self.list else {
+            return Ok(None);
+        };
+        let list = list.borrow(py);
+        if self.index >= list.data.len() {
+            drop(list);
+            self.list = None;
+            return Ok(None);
+        }
+        check_version(&list, self.version)?;
+        let item = list.data.get(py, self.index)?;
+        self.index += 1;
+        Ok(Some(item))
+    }
+
+    fn __length_hint__(&self, py: Python) -> usize {
+        match &self.list {
+            Some(list) => list.borrow(py).data.len().saturating_sub(self.index),
+            None => 0,
+        }
+    }
+}
+
+/// Reverse iterator over a `List`, see `ListIterator`.
+#[pyclass(name = "ListReverseIterator")]
+pub struct ListReverseIterator {
+    list: Option<Py<List>>,
+    index: usize,
+    version: u64,
+}
+
+#[pymethods]
+impl ListReverseIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
     }
 
+    fn __next__(&mut self, py: Python) -> PyResult<Option<PyObject>> {
+        let Some(list) = &self.list else {
+            return Ok(None);
+        };
+        if self.index == 0 {
+            self.list = None;
+            return Ok(None);
+        }
+        let list = list.borrow(py);
+        check_version(&list, self.version)?;
+        self.index -= 1;
+        Ok(Some(list.data.get(py, self.index)?))
+    }
+
+    fn __length_hint__(&self) -> usize {
+        match self.list {
+            Some(_) => self.index,
+            None => 0,
+        }
+    }
 }
 
 #[pymodule]
 pub fn register_list(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<List>()?;
+    m.add_class::<ListIterator>()?;
+    m.add_class::<ListReverseIterator>()?;
+    // Let `collections.abc` based code treat List as a mutable sequence
+    let py = m.py();
+    MUTABLE_SEQUENCE
+        .import(py, "collections.abc", "MutableSequence")?
+        .call_method1("register", (py.get_type::<List>(),))?;
     Ok(())
 }
@@ -2,11 +2,23 @@ use pyo3::prelude::*;
 
 // pub mod vector;
 // pub mod hashmap;
+pub mod alias;
+pub mod buffer;
+pub mod elementwise;
+pub mod iter;
 pub mod list;
+pub mod parallel;
+pub mod sort;
+pub mod stats;
+pub mod storage;
+pub mod typing;
 
 pub fn register_collections(m: &Bound<'_, PyModule>) -> PyResult<()> {
     // vector::register_vector(m)?;
     list::register_list(m)?;
+    alias::register_alias(m)?;
+    parallel::register_parallel(m)?;
+    iter::register_iter(m)?;
     // hashmap::register_hashmap(m)?;
     Ok(())
 }
@@ -0,0 +1,533 @@
+use std::cmp::Ordering;
+use std::sync::{Arc, RwLock};
+
+use pyo3::basic::CompareOp;
+use pyo3::exceptions::{PyOverflowError, PyRuntimeError, PyTypeError, PyValueError, PyZeroDivisionError};
+use pyo3::prelude::*;
+use pyo3::IntoPyObjectExt;
+use rayon::prelude::*;
+use rayon::{ThreadPool, ThreadPoolBuilder};
+
+use super::storage::{each_native, Native, Storage};
+
+/// Items per chunk for reductions that would otherwise depend on how rayon
+/// splits the work, such as float sums, so that results are the same for
+/// any number of threads.
+const CHUNK: usize = 4096;
+
+/// The pool set by `set_num_threads`, or `None` for rayon's global pool.
+static POOL: RwLock<Option<Arc<ThreadPool>>> = RwLock::new(None);
+
+/// Run `f` on the configured thread pool with the GIL released.
+fn run<R: Send>(py: Python, f: impl FnOnce() -> R + Send) -> R {
+    let pool = POOL.read().unwrap_or_else(|e| e.into_inner()).clone();
+    py.allow_threads(|| match pool {
+        Some(pool) => pool.install(f),
+        None => f(),
+    })
+}
+
+/// Use `threads` worker threads for the parallel List methods, or one per
+/// CPU when `threads` is 0.
+#[pyfunction]
+pub fn set_num_threads(threads: usize) -> PyResult<()> {
+    let pool = match threads {
+        0 => None,
+        n => Some(Arc::new(
+            ThreadPoolBuilder::new()
+                .num_threads(n)
+                .build()
+                .map_err(|e| PyRuntimeError::new_err(e.to_string()))?,
+        )),
+    };
+    *POOL.write().unwrap_or_else(|e| e.into_inner()) = pool;
+    Ok(())
+}
+
+/// The number of worker threads used by the parallel List methods.
+#[pyfunction]
+pub fn get_num_threads() -> usize {
+    match *POOL.read().unwrap_or_else(|e| e.into_inner()) {
+        Some(ref pool) => pool.current_num_threads(),
+        None => rayon::current_num_threads(),
+    }
+}
+
+/// A built-in operation for `par_map`.
+#[derive(Clone, Copy)]
+pub(crate) enum MapOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Pow,
+    Min,
+    Max,
+    Abs,
+    Neg,
+    Square,
+    Sqrt,
+    Exp,
+    Log,
+    Floor,
+    Ceil,
+    Round,
+    Not,
+    And,
+    Or,
+    Xor,
+}
+
+impl MapOp {
+    fn parse(name: &str) -> PyResult<Self> {
+        Ok(match name {
+            "add" => MapOp::Add,
+            "sub" => MapOp::Sub,
+            "mul" => MapOp::Mul,
+            "div" => MapOp::Div,
+            "rem" => MapOp::Rem,
+            "pow" => MapOp::Pow,
+            "min" => MapOp::Min,
+            "max" => MapOp::Max,
+            "abs" => MapOp::Abs,
+            "neg" => MapOp::Neg,
+            "square" => MapOp::Square,
+            "sqrt" => MapOp::Sqrt,
+            "exp" => MapOp::Exp,
+            "log" => MapOp::Log,
+            "floor" => MapOp::Floor,
+            "ceil" => MapOp::Ceil,
+            "round" => MapOp::Round,
+            "not" => MapOp::Not,
+            "and" => MapOp::And,
+            "or" => MapOp::Or,
+            "xor" => MapOp::Xor,
+            _ => return Err(PyValueError::new_err(format!("Unknown par_map op '{}'", name))),
+        })
+    }
+
+    fn binary(self) -> bool {
+        matches!(
+            self,
+            MapOp::Add
+                | MapOp::Sub
+                | MapOp::Mul
+                | MapOp::Div
+                | MapOp::Rem
+                | MapOp::Pow
+                | MapOp::Min
+                | MapOp::Max
+                | MapOp::And
+                | MapOp::Or
+                | MapOp::Xor
+        )
+    }
+}
+
+/// A comparison of each item with a value, for `par_filter` and masks.
+#[derive(Clone, Copy)]
+pub(crate) enum Predicate {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+    Ne,
+}
+
+impl Predicate {
+    pub(crate) fn parse(name: &str) -> PyResult<Self> {
+        Ok(match name {
+            "<" | "lt" => Predicate::Lt,
+            "<=" | "le" => Predicate::Le,
+            ">" | "gt" => Predicate::Gt,
+            ">=" | "ge" => Predicate::Ge,
+            "==" | "eq" => Predicate::Eq,
+            "!=" | "ne" => Predicate::Ne,
+            _ => return Err(PyValueError::new_err(format!("Unknown comparison '{}'", name))),
+        })
+    }
+
+    pub(crate) fn test<T: PartialOrd>(self, item: &T, value: &T) -> bool {
+        match self {
+            Predicate::Lt => item < value,
+            Predicate::Le => item <= value,
+            Predicate::Gt => item > value,
+            Predicate::Ge => item >= value,
+            Predicate::Eq => item == value,
+            Predicate::Ne => item != value,
+        }
+    }
+}
+
+impl From<Predicate> for CompareOp {
+    fn from(predicate: Predicate) -> Self {
+        match predicate {
+            Predicate::Lt => CompareOp::Lt,
+            Predicate::Le => CompareOp::Le,
+            Predicate::Gt => CompareOp::Gt,
+            Predicate::Ge => CompareOp::Ge,
+            Predicate::Eq => CompareOp::Eq,
+            Predicate::Ne => CompareOp::Ne,
+        }
+    }
+}
+
+impl From<CompareOp> for Predicate {
+    fn from(op: CompareOp) -> Self {
+        match op {
+            CompareOp::Lt => Predicate::Lt,
+            CompareOp::Le => Predicate::Le,
+            CompareOp::Gt => Predicate::Gt,
+            CompareOp::Ge => Predicate::Ge,
+            CompareOp::Eq => Predicate::Eq,
+            CompareOp::Ne => Predicate::Ne,
+        }
+    }
+}
+
+/// The result of `par_sum`.
+pub(crate) enum Total {
+    Int(i128),
+    UInt(u128),
+    Float(f64),
+}
+
+/// Arithmetic on the unboxed items of a List.
+pub(crate) trait Parallel: Native + Default + Send + Sync {
+    fn supports(op: MapOp) -> bool;
+
+    /// `op` applied to `x`, with `rhs` as the operand of binary ops, or
+    /// `None` on overflow.
+    fn apply(op: MapOp, x: Self, rhs: Self) -> Option<Self>;
+
+    /// Reject operands that fail for every item, such as a zero divisor.
+    fn check_operand(_op: MapOp, _rhs: Self) -> PyResult<()> {
+        Ok(())
+    }
+
+    fn total(items: &[Self]) -> PyResult<Total>;
+}
+
+fn overflow(kind: &str) -> PyErr {
+    PyOverflowError::new_err(format!("{} overflow in par_sum", kind))
+}
+
+macro_rules! int_apply {
+    ($op:expr, $x:expr, $rhs:expr, abs => $abs:expr, neg => $neg:expr) => {
+        match $op {
+            MapOp::Add => $x.checked_add($rhs),
+            MapOp::Sub => $x.checked_sub($rhs),
+            MapOp::Mul => $x.checked_mul($rhs),
+            MapOp::Div => $x.checked_div($rhs),
+            MapOp::Rem => $x.checked_rem($rhs),
+            MapOp::Pow => u32::try_from($rhs).ok().and_then(|e| $x.checked_pow(e)),
+            MapOp::Min => Some($x.min($rhs)),
+            MapOp::Max => Some($x.max($rhs)),
+            MapOp::Abs => $abs,
+            MapOp::Neg => $neg,
+            MapOp::Square => $x.checked_mul($x),
+            _ => None,
+        }
+    };
+}
+
+macro_rules! int_parallel {
+    ($($t:ty => $total:ident, $acc:ty, abs => $abs:expr, neg => $neg:expr);* $(;)?) => {$(
+        impl Parallel for $t {
+            fn supports(op: MapOp) -> bool {
+                matches!(
+                    op,
+                    MapOp::Add
+                        | MapOp::Sub
+                        | MapOp::Mul
+                        | MapOp::Div
+                        | MapOp::Rem
+                        | MapOp::Pow
+                        | MapOp::Min
+                        | MapOp::Max
+                        | MapOp::Abs
+                        | MapOp::Neg
+                        | MapOp::Square
+                )
+            }
+
+            fn apply(op: MapOp, x: Self, rhs: Self) -> Option<Self> {
+                int_apply!(op, x, rhs, abs => ($abs)(x), neg => ($neg)(x))
+            }
+
+            fn check_operand(op: MapOp, rhs: Self) -> PyResult<()> {
+                match op {
+                    MapOp::Div | MapOp::Rem if rhs == 0 => {
+                        Err(PyZeroDivisionError::new_err("par_map division by zero"))
+                    }
+                    MapOp::Pow if u32::try_from(rhs).is_err() => Err(PyValueError::new_err(format!(
+                        "par_map exponent must be between 0 and {}, got {}",
+                        u32::MAX,
+                        rhs
+                    ))),
+                    _ => Ok(()),
+                }
+            }
+
+            fn total(items: &[Self]) -> PyResult<Total> {
+                // Chunked so that overflow is detected the same way every time
+                let partials: Vec<Option<$acc>> = items
+                    .par_chunks(CHUNK)
+                    .map(|chunk| chunk.iter().try_fold(0 as $acc, |acc, &x| acc.checked_add(x as $acc)))
+                    .collect();
+                partials
+                    .into_iter()
+                    .try_fold(0 as $acc, |acc, partial| acc.checked_add(partial?))
+                    .map(Total::$total)
+                    .ok_or_else(|| overflow(stringify!($t)))
+            }
+        }
+    )*};
+}
+
+int_parallel! {
+    i8 => Int, i128, abs => |x: i8| x.checked_abs(), neg => |x: i8| x.checked_neg();
+    i16 => Int, i128, abs => |x: i16| x.checked_abs(), neg => |x: i16| x.checked_neg();
+    i32 => Int, i128, abs => |x: i32| x.checked_abs(), neg => |x: i32| x.checked_neg();
+    i64 => Int, i128, abs => |x: i64| x.checked_abs(), neg => |x: i64| x.checked_neg();
+    i128 => Int, i128, abs => |x: i128| x.checked_abs(), neg => |x: i128| x.checked_neg();
+    isize => Int, i128, abs => |x: isize| x.checked_abs(), neg => |x: isize| x.checked_neg();
+    u8 => UInt, u128, abs => Some, neg => |x: u8| x.checked_neg();
+    u16 => UInt, u128, abs => Some, neg => |x: u16| x.checked_neg();
+    u32 => UInt, u128, abs => Some, neg => |x: u32| x.checked_neg();
+    u64 => UInt, u128, abs => Some, neg => |x: u64| x.checked_neg();
+    u128 => UInt, u128, abs => Some, neg => |x: u128| x.checked_neg();
+    usize => UInt, u128, abs => Some, neg => |x: usize| x.checked_neg();
+}
+
+macro_rules! float_parallel {
+    ($($t:ty),*) => {$(
+        impl Parallel for $t {
+            fn supports(op: MapOp) -> bool {
+                !matches!(op, MapOp::Not | MapOp::And | MapOp::Or | MapOp::Xor)
+            }
+
+            fn apply(op: MapOp, x: Self, rhs: Self) -> Option<Self> {
+                Some(match op {
+                    MapOp::Add => x + rhs,
+                    MapOp::Sub => x - rhs,
+                    MapOp::Mul => x * rhs,
+                    MapOp::Div => x / rhs,
+                    MapOp::Rem => x % rhs,
+                    MapOp::Pow => x.powf(rhs),
+                    MapOp::Min => x.min(rhs),
+                    MapOp::Max => x.max(rhs),
+                    MapOp::Abs => x.abs(),
+                    MapOp::Neg => -x,
+                    MapOp::Square => x * x,
+                    MapOp::Sqrt => x.sqrt(),
+                    MapOp::Exp => x.exp(),
+                    MapOp::Log => x.ln(),
+                    MapOp::Floor => x.floor(),
+                    MapOp::Ceil => x.ceil(),
+                    MapOp::Round => x.round(),
+                    MapOp::Not | MapOp::And | MapOp::Or | MapOp::Xor => return None,
+                })
+            }
+
+            fn total(items: &[Self]) -> PyResult<Total> {
+                // Float addition is not associative, so fix the grouping
+                let partials: Vec<f64> = items
+                    .par_chunks(CHUNK)
+                    .map(|chunk| chunk.iter().map(|&x| x as f64).sum::<f64>())
+                    .collect();
+                Ok(Total::Float(partials.into_iter().sum()))
+            }
+        }
+    )*};
+}
+
+float_parallel!(f32, f64);
+
+impl Parallel for bool {
+    fn supports(op: MapOp) -> bool {
+        matches!(op, MapOp::Not | MapOp::And | MapOp::Or | MapOp::Xor | MapOp::Min | MapOp::Max)
+    }
+
+    fn apply(op: MapOp, x: Self, rhs: Self) -> Option<Self> {
+        match op {
+            MapOp::Not => Some(!x),
+            MapOp::And | MapOp::Min => Some(x & rhs),
+            MapOp::Or | MapOp::Max => Some(x | rhs),
+            MapOp::Xor => Some(x ^ rhs),
+            _ => None,
+        }
+    }
+
+    /// The number of `True` items.
+    fn total(items: &[Self]) -> PyResult<Total> {
+        Ok(Total::UInt(items.par_iter().filter(|&&x| x).count() as u128))
+    }
+}
+
+impl Parallel for char {
+    fn supports(op: MapOp) -> bool {
+        matches!(op, MapOp::Min | MapOp::Max)
+    }
+
+    fn apply(op: MapOp, x: Self, rhs: Self) -> Option<Self> {
+        match op {
+            MapOp::Min => Some(x.min(rhs)),
+            MapOp::Max => Some(x.max(rhs)),
+            _ => None,
+        }
+    }
+
+    fn total(_: &[Self]) -> PyResult<Total> {
+        Err(PyTypeError::new_err("par_sum is not supported for List[char]"))
+    }
+}
+
+fn unsupported(method: &str) -> PyErr {
+    PyTypeError::new_err(format!("{} requires a List of a Rustique primitive", method))
+}
+
+fn check_ordered<T: Parallel>(items: &[T]) -> PyResult<()> {
+    if items.par_iter().any(|x| x.partial_cmp(x).is_none()) {
+        return Err(PyValueError::new_err("Cannot order a List containing NaN"));
+    }
+    Ok(())
+}
+
+fn sort_items<T: Parallel>(items: &mut [T], reverse: bool, stable: bool) -> PyResult<()> {
+    check_ordered(items)?;
+    let order = |a: &T, b: &T| {
+        let (a, b) = if reverse { (b, a) } else { (a, b) };
+        a.partial_cmp(b).unwrap_or(Ordering::Equal)
+    };
+    if stable {
+        items.par_sort_by(order);
+    } else {
+        items.par_sort_unstable_by(order);
+    }
+    Ok(())
+}
+
+/// Sort unboxed items in place on the thread pool.
+pub(crate) fn sort(py: Python, data: &mut Storage, reverse: bool, stable: bool) -> PyResult<()> {
+    let method = if stable { "par_sort" } else { "par_sort_unstable" };
+    each_native!(data, v => run(py, || sort_items(v, reverse, stable)), _ => Err(unsupported(method)))
+}
+
+/// The sum of the items as a Python `int` or `float`.
+pub(crate) fn sum(py: Python, data: &Storage) -> PyResult<PyObject> {
+    let total = each_native!(data, v => run(py, || Parallel::total(v.as_slice())), _ => Err(unsupported("par_sum")))?;
+    match total {
+        Total::Int(n) => n.into_py_any(py),
+        Total::UInt(n) => n.into_py_any(py),
+        Total::Float(x) => x.into_py_any(py),
+    }
+}
+
+fn extreme_item<T: Parallel>(items: &[T], max: bool, method: &str) -> PyResult<T> {
+    check_ordered(items)?;
+    // Keeping the earlier of two equal items makes this associative
+    items
+        .par_iter()
+        .copied()
+        .reduce_with(|a, b| if (max && b > a) || (!max && b < a) { b } else { a })
+        .ok_or_else(|| PyValueError::new_err(format!("{}() arg is an empty List", method)))
+}
+
+/// The first smallest, or with `max` largest, item.
+pub(crate) fn extreme(py: Python, data: &Storage, max: bool) -> PyResult<PyObject> {
+    let method = if max { "par_max" } else { "par_min" };
+    each_native!(data, v => run(py, || extreme_item(v, max, method))?.into_object(py), _ => Err(unsupported(method)))
+}
+
+/// The op `name` and its operand as given to `method`, checked for items
+/// of type `T`.
+pub(crate) fn map_operand<T: Parallel>(method: &str, name: &str, operand: Option<&Bound<'_, PyAny>>) -> PyResult<(MapOp, T)> {
+    let op = MapOp::parse(name)?;
+    if !T::supports(op) {
+        return Err(PyTypeError::new_err(format!(
+            "{} op '{}' is not supported for List[{}]",
+            method,
+            name,
+            T::KIND.name()
+        )));
+    }
+    let rhs = match (op.binary(), operand) {
+        (true, Some(obj)) => {
+            let rhs = T::value(obj)?;
+            T::check_operand(op, rhs)?;
+            rhs
+        }
+        (true, None) => return Err(PyTypeError::new_err(format!("{} op '{}' requires an operand", method, name))),
+        (false, Some(_)) => return Err(PyTypeError::new_err(format!("{} op '{}' takes no operand", method, name))),
+        (false, None) => T::default(),
+    };
+    Ok((op, rhs))
+}
+
+fn map_items<T: Parallel>(py: Python, items: &[T], name: &str, operand: Option<&Bound<'_, PyAny>>) -> PyResult<Vec<T>> {
+    let (op, rhs) = map_operand::<T>("par_map", name, operand)?;
+
+    run(py, || {
+        let mut out = vec![T::default(); items.len()];
+        let done = out
+            .par_iter_mut()
+            .zip(items.par_iter())
+            .try_for_each(|(out, &x)| {
+                *out = T::apply(op, x, rhs)?;
+                Some(())
+            });
+        if done.is_none() {
+            // Report the first failing item, whichever thread hit one
+            let index = items.par_iter().position_first(|&x| T::apply(op, x, rhs).is_none());
+            return Err(PyOverflowError::new_err(format!(
+                "{} overflow in par_map('{}') at index {}",
+                T::KIND.name(),
+                name,
+                index.unwrap_or_default()
+            )));
+        }
+        Ok(out)
+    })
+}
+
+/// Apply the built-in op `name` to every item.
+pub(crate) fn map(py: Python, data: &Storage, name: &str, operand: Option<&Bound<'_, PyAny>>) -> PyResult<Storage> {
+    each_native!(data, v => Ok(map_items(py, v, name, operand)?.into()), _ => Err(unsupported("par_map")))
+}
+
+fn filter_items<T: Parallel>(py: Python, items: &[T], predicate: Predicate, value: &Bound<'_, PyAny>) -> PyResult<Vec<T>> {
+    let value = T::value(value)?;
+    Ok(run(py, || items.par_iter().copied().filter(|x| predicate.test(x, &value)).collect()))
+}
+
+/// The items for which `item <op> value` holds, in order.
+pub(crate) fn filter(py: Python, data: &Storage, op: &str, value: &Bound<'_, PyAny>) -> PyResult<Storage> {
+    let predicate = Predicate::parse(op)?;
+    each_native!(data, v => Ok(filter_items(py, v, predicate, value)?.into()), _ => Err(unsupported("par_filter")))
+}
+
+fn dedup_items<T: Parallel>(items: &[T]) -> Vec<T> {
+    items
+        .par_iter()
+        .enumerate()
+        .filter(|&(i, x)| i == 0 || items[i - 1] != *x)
+        .map(|(_, &x)| x)
+        .collect()
+}
+
+/// Remove consecutive equal items, like `Vec::dedup`.
+pub(crate) fn dedup(py: Python, data: &mut Storage) -> PyResult<()> {
+    each_native!(data, v => {
+        *v = run(py, || dedup_items(v));
+        Ok(())
+    }, _ => Err(unsupported("par_dedup")))
+}
+
+pub fn register_parallel(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(set_num_threads, m)?)?;
+    m.add_function(wrap_pyfunction!(get_num_threads, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,222 @@
+use std::cmp::Ordering;
+use std::mem::discriminant;
+
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyFloat, PyInt, PyString};
+
+use crate::primitives::bool::Bool;
+use crate::primitives::char::Char;
+use crate::primitives::f32::F32;
+use crate::primitives::f64::F64;
+use crate::primitives::i128::I128;
+use crate::primitives::i16::I16;
+use crate::primitives::i32::I32;
+use crate::primitives::i64::I64;
+use crate::primitives::i8::I8;
+use crate::primitives::isize::ISize;
+use crate::primitives::u128::U128;
+use crate::primitives::u16::U16;
+use crate::primitives::u32::U32;
+use crate::primitives::u64::U64;
+use crate::primitives::u8::U8;
+use crate::primitives::usize::Usize;
+
+/// A sort key that can be compared without calling back into Python.
+pub(crate) enum NativeKey {
+    Int(i128),
+    UInt(u128),
+    Float(f64),
+    Str(String),
+    Char(char),
+}
+
+impl NativeKey {
+    fn from_py(obj: &Bound<'_, PyAny>) -> Option<Self> {
+        // Only exact builtin types, so subclasses overriding `__lt__` keep working
+        if obj.is_exact_instance_of::<PyInt>() || obj.is_exact_instance_of::<PyBool>() {
+            return obj.extract::<i128>().ok().map(NativeKey::Int);
+        }
+        if obj.is_exact_instance_of::<PyFloat>() {
+            return Self::float(obj.extract::<f64>().ok()?);
+        }
+        if obj.is_exact_instance_of::<PyString>() {
+            return obj.extract::<String>().ok().map(NativeKey::Str);
+        }
+
+        if let Ok(v) = obj.downcast::<I8>() {
+            return Some(NativeKey::Int(v.borrow().0 as i128));
+        }
+        if let Ok(v) = obj.downcast::<I16>() {
+            return Some(NativeKey::Int(v.borrow().value as i128));
+        }
+        if let Ok(v) = obj.downcast::<I32>() {
+            return Some(NativeKey::Int(v.borrow().value as i128));
+        }
+        if let Ok(v) = obj.downcast::<I64>() {
+            return Some(NativeKey::Int(v.borrow().value as i128));
+        }
+        if let Ok(v) = obj.downcast::<I128>() {
+            return Some(NativeKey::Int(v.borrow().value));
+        }
+        if let Ok(v) = obj.downcast::<ISize>() {
+            return Some(NativeKey::Int(v.borrow().value as i128));
+        }
+        if let Ok(v) = obj.downcast::<U8>() {
+            return Some(NativeKey::UInt(v.borrow().value as u128));
+        }
+        if let Ok(v) = obj.downcast::<U16>() {
+            return Some(NativeKey::UInt(v.borrow().value as u128));
+        }
+        if let Ok(v) = obj.downcast::<U32>() {
+            return Some(NativeKey::UInt(v.borrow().value as u128));
+        }
+        if let Ok(v) = obj.downcast::<U64>() {
+            return Some(NativeKey::UInt(v.borrow().value as u128));
+        }
+        if let Ok(v) = obj.downcast::<U128>() {
+            return Some(NativeKey::UInt(v.borrow().value));
+        }
+        if let Ok(v) = obj.downcast::<Usize>() {
+            return Some(NativeKey::UInt(v.borrow().value as u128));
+        }
+        if let Ok(v) = obj.downcast::<Bool>() {
+            return Some(NativeKey::UInt(v.borrow().value as u128));
+        }
+        if let Ok(v) = obj.downcast::<F32>() {
+            return Self::float(v.borrow().value as f64);
+        }
+        if let Ok(v) = obj.downcast::<F64>() {
+            return Self::float(v.borrow().value);
+        }
+        if let Ok(v) = obj.downcast::<Char>() {
+            return Some(NativeKey::Char(v.borrow().value));
+        }
+        None
+    }
+
+    // NaN has no place in a total order, so those lists take the generic path
+    fn float(value: f64) -> Option<Self> {
+        (!value.is_nan()).then_some(NativeKey::Float(value))
+    }
+
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (NativeKey::Int(a), NativeKey::Int(b)) => a.cmp(b),
+            (NativeKey::UInt(a), NativeKey::UInt(b)) => a.cmp(b),
+            (NativeKey::Float(a), NativeKey::Float(b)) => a.partial_cmp(b).unwrap_or(Ordering::Equal),
+            (NativeKey::Str(a), NativeKey::Str(b)) => a.cmp(b),
+            (NativeKey::Char(a), NativeKey::Char(b)) => a.cmp(b),
+            _ => Ordering::Equal,
+        }
+    }
+}
+
+/// Convert every key to a `NativeKey` of a single kind, or `None` when the
+/// keys have to be compared through Python.
+pub(crate) fn native_keys(py: Python, keys: &[PyObject]) -> Option<Vec<NativeKey>> {
+    let native = keys
+        .iter()
+        .map(|key| NativeKey::from_py(key.bind(py)))
+        .collect::<Option<Vec<_>>>()?;
+
+    let kind = native.first().map(discriminant);
+    native
+        .iter()
+        .all(|key| Some(discriminant(key)) == kind)
+        .then_some(native)
+}
+
+/// Stable merge sort driven by a fallible `is_less`.
+///
+/// The standard library sorts may panic on comparators that are not a
+/// total order, which a Python `__lt__` is free to be, and cannot stop on
+/// an exception. This one only ever asks `is_less` and returns the first
+/// error raised.
+pub(crate) fn try_merge_sort<T: Clone, F>(v: &mut [T], is_less: &mut F) -> PyResult<()>
+where
+    F: FnMut(&T, &T) -> PyResult<bool>,
+{
+    const RUN: usize = 16;
+    let len = v.len();
+
+    for start in (0..len).step_by(RUN) {
+        let end = (start + RUN).min(len);
+        for i in start + 1..end {
+            let mut j = i;
+            while j > start && is_less(&v[j], &v[j - 1])? {
+                v.swap(j, j - 1);
+                j -= 1;
+            }
+        }
+    }
+
+    let mut buf: Vec<T> = Vec::with_capacity(len);
+    let mut width = RUN;
+    while width < len {
+        for start in (0..len).step_by(2 * width) {
+            let mid = (start + width).min(len);
+            let end = (start + 2 * width).min(len);
+            if mid == end {
+                continue;
+            }
+
+            buf.clear();
+            let (mut i, mut j) = (start, mid);
+            while i < mid && j < end {
+                // Take from the right run only when strictly less, for stability
+                if is_less(&v[j], &v[i])? {
+                    buf.push(v[j].clone());
+                    j += 1;
+                } else {
+                    buf.push(v[i].clone());
+                    i += 1;
+                }
+            }
+            buf.extend_from_slice(&v[i..mid]);
+            buf.extend_from_slice(&v[j..end]);
+            v[start..end].clone_from_slice(&buf);
+        }
+        width *= 2;
+    }
+    Ok(())
+}
+
+/// Sort `items` by `key` (or by the items themselves), comparing natively
+/// when every key is a builtin `int`, `float` or `str` or a Rustique
+/// primitive of one kind, and through Python's `<` otherwise.
+pub(crate) fn sort_items(
+    py: Python,
+    items: &[PyObject],
+    key: Option<&Bound<'_, PyAny>>,
+    reverse: bool,
+    stable: bool,
+) -> PyResult<Vec<PyObject>> {
+    let keys: Vec<PyObject> = match key {
+        Some(key) => items
+            .iter()
+            .map(|item| Ok(key.call1((item,))?.unbind()))
+            .collect::<PyResult<_>>()?,
+        None => items.to_vec(),
+    };
+
+    let mut order: Vec<usize> = (0..items.len()).collect();
+
+    if let Some(native) = native_keys(py, &keys) {
+        let compare = |a: &usize, b: &usize| {
+            let ordering = native[*a].cmp(&native[*b]);
+            if reverse { ordering.reverse() } else { ordering }
+        };
+        if stable {
+            order.sort_by(compare);
+        } else {
+            order.sort_unstable_by(compare);
+        }
+    } else {
+        try_merge_sort(&mut order, &mut |a: &usize, b: &usize| {
+            let (a, b) = if reverse { (b, a) } else { (a, b) };
+            keys[*a].bind(py).lt(keys[*b].bind(py))
+        })?;
+    }
+
+    Ok(order.into_iter().map(|i| items[i].clone_ref(py)).collect())
+}
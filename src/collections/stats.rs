@@ -0,0 +1,400 @@
+use std::cmp::Ordering;
+
+use pyo3::exceptions::{PyOverflowError, PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyType;
+use pyo3::IntoPyObjectExt;
+
+use super::storage::{each_native, Kind, Native, Storage};
+
+/// Integer types that sums and products can be accumulated in.
+trait Accumulator: Copy {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    fn checked_mul(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! accumulator {
+    ($($t:ty),*) => {$(
+        impl Accumulator for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$t>::checked_add(self, other)
+            }
+
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                <$t>::checked_mul(self, other)
+            }
+        }
+    )*};
+}
+
+accumulator!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// The sum, or with `product` the product, of `items` in `A`, or `None`
+/// when an item or a partial result does not fit.
+fn reduce_in<T: Copy, A: Accumulator + TryFrom<T>>(items: &[T], product: bool) -> Option<A> {
+    let mut total = if product { A::ONE } else { A::ZERO };
+    for &item in items {
+        let item = A::try_from(item).ok()?;
+        total = if product { total.checked_mul(item)? } else { total.checked_add(item)? };
+    }
+    Some(total)
+}
+
+/// Statistics on the unboxed items of a List.
+trait Numeric: Native {
+    fn float(self) -> f64;
+
+    /// The sum or product of `items` in the integer type `acc`, or `None`
+    /// when it does not fit.
+    fn reduce_int(py: Python, items: &[Self], acc: Kind, product: bool) -> PyResult<Option<PyObject>>;
+}
+
+fn accumulate_error(kind: Kind, acc: Kind) -> PyErr {
+    PyTypeError::new_err(format!("Cannot accumulate List[{}] in {}", kind.name(), acc.name()))
+}
+
+macro_rules! int_numeric {
+    ($($t:ty),*) => {$(
+        impl Numeric for $t {
+            fn float(self) -> f64 {
+                self as f64
+            }
+
+            fn reduce_int(py: Python, items: &[Self], acc: Kind, product: bool) -> PyResult<Option<PyObject>> {
+                let total = match acc {
+                    Kind::I8 => reduce_in::<_, i8>(items, product).map(|t| t.into_py_any(py)),
+                    Kind::I16 => reduce_in::<_, i16>(items, product).map(|t| t.into_py_any(py)),
+                    Kind::I32 => reduce_in::<_, i32>(items, product).map(|t| t.into_py_any(py)),
+                    Kind::I64 => reduce_in::<_, i64>(items, product).map(|t| t.into_py_any(py)),
+                    Kind::I128 => reduce_in::<_, i128>(items, product).map(|t| t.into_py_any(py)),
+                    Kind::ISize => reduce_in::<_, isize>(items, product).map(|t| t.into_py_any(py)),
+                    Kind::U8 => reduce_in::<_, u8>(items, product).map(|t| t.into_py_any(py)),
+                    Kind::U16 => reduce_in::<_, u16>(items, product).map(|t| t.into_py_any(py)),
+                    Kind::U32 => reduce_in::<_, u32>(items, product).map(|t| t.into_py_any(py)),
+                    Kind::U64 => reduce_in::<_, u64>(items, product).map(|t| t.into_py_any(py)),
+                    Kind::U128 => reduce_in::<_, u128>(items, product).map(|t| t.into_py_any(py)),
+                    Kind::USize => reduce_in::<_, usize>(items, product).map(|t| t.into_py_any(py)),
+                    _ => return Err(accumulate_error(Self::KIND, acc)),
+                };
+                total.transpose()
+            }
+        }
+    )*};
+}
+
+int_numeric!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl Numeric for bool {
+    fn float(self) -> f64 {
+        self as u8 as f64
+    }
+
+    fn reduce_int(py: Python, items: &[Self], acc: Kind, product: bool) -> PyResult<Option<PyObject>> {
+        let items: Vec<u8> = items.iter().map(|&b| b as u8).collect();
+        u8::reduce_int(py, &items, acc, product)
+    }
+}
+
+macro_rules! float_numeric {
+    ($($t:ty),*) => {$(
+        impl Numeric for $t {
+            fn float(self) -> f64 {
+                self as f64
+            }
+
+            fn reduce_int(_: Python, _: &[Self], acc: Kind, _: bool) -> PyResult<Option<PyObject>> {
+                Err(accumulate_error(Self::KIND, acc))
+            }
+        }
+    )*};
+}
+
+float_numeric!(f32, f64);
+
+/// Never used, as `List[char]` is rejected first.
+impl Numeric for char {
+    fn float(self) -> f64 {
+        self as u32 as f64
+    }
+
+    fn reduce_int(_: Python, _: &[Self], acc: Kind, _: bool) -> PyResult<Option<PyObject>> {
+        Err(accumulate_error(Kind::Char, acc))
+    }
+}
+
+/// Run `$body` with `$v` bound to the items of `$data` as a slice of a
+/// `Numeric` type, reading Python objects through `float()`.
+macro_rules! numeric {
+    ($py:expr, $data:expr, $method:expr, $v:ident => $body:expr) => {{
+        let data: &Storage = $data;
+        if data.kind() == Some(Kind::Char) {
+            return Err(PyTypeError::new_err(format!("{} is not supported for List[char]", $method)));
+        }
+        match data {
+            Storage::Object(objects) => {
+                let floats = objects.iter().map(|item| item.extract::<f64>($py)).collect::<PyResult<Vec<f64>>>()?;
+                let $v = &floats;
+                $body
+            }
+            data => each_native!(data, $v => $body, _ => unreachable!("objects are handled above")),
+        }
+    }};
+}
+
+/// Neumaier's variant of Kahan summation, whose error does not grow with
+/// the number of items.
+fn compensated_sum(values: impl Iterator<Item = f64>) -> f64 {
+    let (mut sum, mut compensation) = (0.0f64, 0.0f64);
+    for x in values {
+        let t = sum + x;
+        compensation += if sum.abs() >= x.abs() { (sum - t) + x } else { (x - t) + sum };
+        sum = t;
+    }
+    // The compensation is NaN once the sum is infinite
+    if sum.is_finite() {
+        sum + compensation
+    } else {
+        sum
+    }
+}
+
+/// The accumulator that cannot overflow for items of `kind` in practice.
+fn default_accumulator(kind: Kind) -> Kind {
+    match kind {
+        Kind::I8 | Kind::I16 | Kind::I32 | Kind::I64 | Kind::I128 | Kind::ISize => Kind::I128,
+        Kind::F32 | Kind::F64 => Kind::F64,
+        _ => Kind::U128,
+    }
+}
+
+/// The sum, or with `product` the product, of the items.
+///
+/// Unboxed items are accumulated in `accumulator`, or by default in
+/// `i128`, `u128` or `f64`. Float sums are compensated. Python objects are
+/// combined with `+` or `*` instead.
+pub(crate) fn reduce(py: Python, data: &Storage, accumulator: Option<&Bound<'_, PyType>>, product: bool) -> PyResult<PyObject> {
+    let method = if product { "product" } else { "sum" };
+    let Some(kind) = data.kind() else {
+        if accumulator.is_some() {
+            return Err(PyTypeError::new_err(format!(
+                "{}(accumulator=...) requires a List of a Rustique number",
+                method
+            )));
+        }
+        let mut total = if product { 1.into_py_any(py)? } else { 0.into_py_any(py)? };
+        for item in data.objects(py)? {
+            let next = if product { total.bind(py).mul(item)? } else { total.bind(py).add(item)? };
+            total = next.unbind();
+        }
+        return Ok(total);
+    };
+    if kind == Kind::Char {
+        return Err(PyTypeError::new_err(format!("{} is not supported for List[char]", method)));
+    }
+
+    let acc = match accumulator {
+        Some(cls) => match Kind::of(cls) {
+            Some(acc) if !matches!(acc, Kind::Bool | Kind::Char) => acc,
+            _ => {
+                return Err(PyTypeError::new_err(format!(
+                    "accumulator must be a Rustique integer or float type, got {}",
+                    cls.name()?
+                )))
+            }
+        },
+        None => default_accumulator(kind),
+    };
+
+    if matches!(acc, Kind::F32 | Kind::F64) {
+        let total = numeric!(py, data, method, v => {
+            let values = v.iter().map(|&x| x.float());
+            if product { values.product() } else { compensated_sum(values) }
+        });
+        return match acc {
+            Kind::F32 => (total as f32).into_py_any(py),
+            _ => total.into_py_any(py),
+        };
+    }
+    let total = numeric!(py, data, method, v => Numeric::reduce_int(py, v, acc, product)?);
+    total.ok_or_else(|| {
+        PyOverflowError::new_err(format!(
+            "{} of List[{}] does not fit in {}",
+            if product { "Product" } else { "Sum" },
+            kind.name(),
+            acc.name()
+        ))
+    })
+}
+
+fn empty_error(method: &str) -> PyErr {
+    PyValueError::new_err(format!("{}() arg is an empty List", method))
+}
+
+fn nan_error(method: &str) -> PyErr {
+    PyValueError::new_err(format!("{}() is undefined for a List containing NaN", method))
+}
+
+/// Index of the first extreme item, or `None` if an item is unordered.
+fn native_extreme<T: PartialOrd>(items: &[T], max: bool) -> Option<Option<usize>> {
+    let mut best: Option<usize> = None;
+    for (i, item) in items.iter().enumerate() {
+        // Only NaN is unordered with itself
+        item.partial_cmp(item)?;
+        let replace = match best {
+            None => true,
+            Some(b) => match item.partial_cmp(&items[b])? {
+                Ordering::Less => !max,
+                Ordering::Greater => max,
+                Ordering::Equal => false,
+            },
+        };
+        if replace {
+            best = Some(i);
+        }
+    }
+    Some(best)
+}
+
+/// Index of the first smallest, or with `max` largest, item, comparing
+/// Python objects with `<` like `min()` and `max()`.
+pub(crate) fn extreme(py: Python, data: &Storage, max: bool, method: &str) -> PyResult<usize> {
+    let found = match data {
+        Storage::Object(items) => {
+            let mut best: Option<usize> = None;
+            for (i, item) in items.iter().enumerate() {
+                let replace = match best {
+                    None => true,
+                    Some(b) if max => items[b].bind(py).lt(item)?,
+                    Some(b) => item.bind(py).lt(&items[b])?,
+                };
+                if replace {
+                    best = Some(i);
+                }
+            }
+            best
+        }
+        data => each_native!(data, v => native_extreme(v, max).ok_or_else(|| nan_error(method))?, _ => unreachable!()),
+    };
+    found.ok_or_else(|| empty_error(method))
+}
+
+fn mean_items<T: Numeric>(items: &[T]) -> PyResult<f64> {
+    if items.is_empty() {
+        return Err(empty_error("mean"));
+    }
+    Ok(compensated_sum(items.iter().map(|&x| x.float())) / items.len() as f64)
+}
+
+/// The arithmetic mean of the items as a float.
+pub(crate) fn mean(py: Python, data: &Storage) -> PyResult<f64> {
+    numeric!(py, data, "mean", v => mean_items(v))
+}
+
+fn variance_items<T: Numeric>(items: &[T], ddof: usize) -> PyResult<f64> {
+    if items.len() <= ddof {
+        return Err(PyValueError::new_err(format!(
+            "variance() requires more than ddof={} items, got {}",
+            ddof,
+            items.len()
+        )));
+    }
+    let mean = mean_items(items)?;
+    let squares = compensated_sum(items.iter().map(|&x| (x.float() - mean).powi(2)));
+    Ok(squares / (items.len() - ddof) as f64)
+}
+
+/// The variance of the items, dividing by `len - ddof`.
+pub(crate) fn variance(py: Python, data: &Storage, ddof: usize) -> PyResult<f64> {
+    numeric!(py, data, "variance", v => variance_items(v, ddof))
+}
+
+/// The items as sorted-order-ready floats, rejecting NaN.
+fn ordered_floats(py: Python, data: &Storage, method: &str) -> PyResult<Vec<f64>> {
+    let values: Vec<f64> = numeric!(py, data, method, v => v.iter().map(|&x| x.float()).collect());
+    if values.iter().any(|x| x.is_nan()) {
+        return Err(nan_error(method));
+    }
+    Ok(values)
+}
+
+/// The `q` quantile of `values`, interpolating linearly between the two
+/// nearest items like NumPy's default method.
+fn quantile_of(values: &mut [f64], q: f64, method: &str) -> PyResult<f64> {
+    if values.is_empty() {
+        return Err(empty_error(method));
+    }
+    let position = q * (values.len() - 1) as f64;
+    let lower = position.floor() as usize;
+    let (_, &mut a, above) = values.select_nth_unstable_by(lower, f64::total_cmp);
+    let fraction = position - lower as f64;
+    if fraction == 0.0 {
+        return Ok(a);
+    }
+    let b = above.iter().copied().min_by(f64::total_cmp).unwrap_or(a);
+    Ok(a + (b - a) * fraction)
+}
+
+/// The middle item, or the mean of the two middle items, as a float.
+pub(crate) fn median(py: Python, data: &Storage) -> PyResult<f64> {
+    quantile_of(&mut ordered_floats(py, data, "median")?, 0.5, "median")
+}
+
+/// The `q` quantile of the items for `0 <= q <= 1`.
+pub(crate) fn quantile(py: Python, data: &Storage, q: f64) -> PyResult<f64> {
+    if !(0.0..=1.0).contains(&q) {
+        return Err(PyValueError::new_err(format!("Quantile must be between 0 and 1, got {}", q)));
+    }
+    quantile_of(&mut ordered_floats(py, data, "quantile")?, q, "quantile")
+}
+
+/// The number of items in each of `bins` equal-width bins and the bins'
+/// `bins + 1` edges, like `numpy.histogram`.
+///
+/// `range` defaults to the smallest and largest item. Every bin but the
+/// last is half-open, and items outside `range` are not counted.
+pub(crate) fn histogram(py: Python, data: &Storage, bins: usize, range: Option<(f64, f64)>) -> PyResult<(Storage, Storage)> {
+    if bins == 0 {
+        return Err(PyValueError::new_err("histogram() requires at least one bin"));
+    }
+    let values: Vec<f64> = numeric!(py, data, "histogram", v => v.iter().map(|&x| x.float()).collect());
+    let (lo, hi) = match range {
+        Some((lo, hi)) if !lo.is_finite() || !hi.is_finite() || lo > hi => {
+            return Err(PyValueError::new_err(format!("Invalid histogram range ({}, {})", lo, hi)))
+        }
+        Some(range) => range,
+        None if values.iter().any(|x| !x.is_finite()) => {
+            return Err(PyValueError::new_err(
+                "histogram() needs an explicit range for a List containing NaN or infinity",
+            ))
+        }
+        None if values.is_empty() => (0.0, 1.0),
+        None => values.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), &x| (lo.min(x), hi.max(x))),
+    };
+    let (lo, hi) = if lo == hi { (lo - 0.5, hi + 0.5) } else { (lo, hi) };
+
+    let width = (hi - lo) / bins as f64;
+    let mut edges: Vec<f64> = (0..bins).map(|i| lo + width * i as f64).collect();
+    edges.push(hi);
+    let mut counts = vec![0u64; bins];
+    for x in values {
+        if !(lo..=hi).contains(&x) {
+            continue;
+        }
+        let mut bin = (((x - lo) / width) as usize).min(bins - 1);
+        // Rounding can put items next to an edge in the neighbouring bin
+        if x < edges[bin] {
+            bin -= 1;
+        } else if bin + 1 < bins && x >= edges[bin + 1] {
+            bin += 1;
+        }
+        counts[bin] += 1;
+    }
+    Ok((counts.into(), edges.into()))
+}
@@ -0,0 +1,492 @@
+use std::cmp::Ordering;
+use std::ffi::c_void;
+use std::ops::Range;
+
+use pyo3::exceptions::{PyMemoryError, PyTypeError};
+use pyo3::prelude::*;
+use pyo3::types::PyType;
+
+use crate::primitives::bool::Bool;
+use crate::primitives::char::Char;
+use crate::primitives::f32::F32;
+use crate::primitives::f64::F64;
+use crate::primitives::i128::I128;
+use crate::primitives::i16::I16;
+use crate::primitives::i32::I32;
+use crate::primitives::i64::I64;
+use crate::primitives::i8::{py_any_to_i8, I8};
+use crate::primitives::isize::ISize;
+use crate::primitives::u128::U128;
+use crate::primitives::u16::U16;
+use crate::primitives::u32::U32;
+use crate::primitives::u64::U64;
+use crate::primitives::u8::U8;
+use crate::primitives::usize::Usize;
+
+use super::typing::ElementType;
+
+/// An item type a `Storage` vector can hold.
+trait Element: Clone {
+    /// Validate `item` against `_type`, converting it when the list coerces.
+    fn accept(_type: Option<&ElementType>, item: &Bound<'_, PyAny>, index: Option<usize>) -> PyResult<Self>;
+
+    /// The item as a Python object.
+    fn boxed(&self, py: Python) -> PyResult<PyObject>;
+
+    /// Index of `value` within `items[start..stop]`, or `None` when only
+    /// Python can tell whether they are equal.
+    fn position(items: &[Self], value: &Bound<'_, PyAny>, start: usize, stop: usize) -> Option<Option<usize>>;
+
+    /// Sort without calling into Python, returning `false` when that is not
+    /// possible.
+    fn sort(items: &mut [Self], reverse: bool, stable: bool) -> bool;
+
+    /// Lexicographic order without calling into Python, if there is one.
+    fn compare(a: &[Self], b: &[Self]) -> Option<Ordering>;
+}
+
+impl Element for PyObject {
+    fn accept(_type: Option<&ElementType>, item: &Bound<'_, PyAny>, index: Option<usize>) -> PyResult<Self> {
+        match _type {
+            Some(t) => t.accept(item, index),
+            None => Ok(item.clone().unbind()),
+        }
+    }
+
+    fn boxed(&self, py: Python) -> PyResult<PyObject> {
+        Ok(self.clone_ref(py))
+    }
+
+    fn position(_: &[Self], _: &Bound<'_, PyAny>, _: usize, _: usize) -> Option<Option<usize>> {
+        None
+    }
+
+    fn sort(_: &mut [Self], _: bool, _: bool) -> bool {
+        false
+    }
+
+    fn compare(_: &[Self], _: &[Self]) -> Option<Ordering> {
+        None
+    }
+}
+
+/// A Rust value that stands in for an instance of a Rustique primitive.
+pub(crate) trait Native: Copy + PartialOrd {
+    const KIND: Kind;
+
+    /// The value of `obj` when it is an instance of the primitive.
+    fn read(obj: &Bound<'_, PyAny>) -> Option<Self>;
+
+    /// Convert `obj` like the primitive's constructor does.
+    fn convert(obj: &Bound<'_, PyAny>) -> PyResult<Self>;
+
+    /// A new instance of the primitive holding this value.
+    fn into_object(self, py: Python) -> PyResult<PyObject>;
+
+    /// The items of `storage` when it holds this primitive.
+    fn items(storage: &Storage) -> Option<&[Self]>;
+
+    /// The value of `obj`, converting it when it is not an instance.
+    fn value(obj: &Bound<'_, PyAny>) -> PyResult<Self> {
+        match Self::read(obj) {
+            Some(value) => Ok(value),
+            None => Self::convert(obj),
+        }
+    }
+}
+
+impl<T: Native> Element for T {
+    fn accept(_type: Option<&ElementType>, item: &Bound<'_, PyAny>, index: Option<usize>) -> PyResult<Self> {
+        if let Some(value) = T::read(item) {
+            return Ok(value);
+        }
+        match _type {
+            Some(t) if t.coerces() => T::convert(item).map_err(|err| t.coercion_error(item, index, err)),
+            Some(t) => Err(t.type_error(item, index)),
+            None => Err(PyTypeError::new_err(format!(
+                "Expected item of type {}, got {}",
+                T::KIND.name(),
+                item.get_type().name().map(|n| n.to_string()).unwrap_or_default()
+            ))),
+        }
+    }
+
+    fn boxed(&self, py: Python) -> PyResult<PyObject> {
+        self.into_object(py)
+    }
+
+    fn position(items: &[Self], value: &Bound<'_, PyAny>, start: usize, stop: usize) -> Option<Option<usize>> {
+        let value = T::read(value)?;
+        let stop = stop.min(items.len());
+        if start >= stop {
+            return Some(None);
+        }
+        Some(items[start..stop].iter().position(|item| *item == value).map(|i| start + i))
+    }
+
+    fn sort(items: &mut [Self], reverse: bool, stable: bool) -> bool {
+        // NaN has no place in a total order, like in `NativeKey`
+        #[allow(clippy::eq_op)]
+        if items.iter().any(|item| item.partial_cmp(item).is_none()) {
+            return false;
+        }
+        let order = |a: &T, b: &T| {
+            let (a, b) = if reverse { (b, a) } else { (a, b) };
+            a.partial_cmp(b).unwrap_or(Ordering::Equal)
+        };
+        if stable {
+            items.sort_by(order);
+        } else {
+            items.sort_unstable_by(order);
+        }
+        true
+    }
+
+    fn compare(a: &[Self], b: &[Self]) -> Option<Ordering> {
+        a.partial_cmp(b)
+    }
+}
+
+macro_rules! natives {
+    ($(($variant:ident, $t:ty, $class:ident, $value:tt, $convert:expr)),* $(,)?) => {
+        /// A Rustique primitive that Lists store unboxed.
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        pub(crate) enum Kind {
+            $($variant),*
+        }
+
+        impl Kind {
+            /// The kind stored for instances of `cls`, if it is a primitive.
+            pub(crate) fn of(cls: &Bound<'_, PyType>) -> Option<Kind> {
+                let py = cls.py();
+                $(
+                    if cls.is(&py.get_type::<$class>()) {
+                        return Some(Kind::$variant);
+                    }
+                )*
+                None
+            }
+
+            /// Convert `obj` to an instance of the primitive, like its constructor.
+            pub(crate) fn convert(self, obj: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+                match self {
+                    $(Kind::$variant => <$t as Native>::convert(obj)?.into_object(obj.py()),)*
+                }
+            }
+
+            /// The primitive's Python class.
+            pub(crate) fn class(self, py: Python<'_>) -> Bound<'_, PyType> {
+                match self {
+                    $(Kind::$variant => py.get_type::<$class>(),)*
+                }
+            }
+
+            pub(crate) fn name(self) -> &'static str {
+                match self {
+                    $(Kind::$variant => stringify!($t),)*
+                }
+            }
+        }
+
+        /// The items of a `List`.
+        ///
+        /// Lists of a Rustique primitive keep their values unboxed in a
+        /// `Vec` of the matching Rust type and only create Python objects
+        /// when items are read, so every read returns a new copy and setting
+        /// `value` on it leaves the list unchanged. Every other list holds
+        /// Python objects.
+        #[derive(Clone)]
+        pub(crate) enum Storage {
+            Object(Vec<PyObject>),
+            $($variant(Vec<$t>)),*
+        }
+
+        impl Storage {
+            /// An empty storage for items of `kind`, or Python objects.
+            pub(crate) fn new(kind: Option<Kind>, capacity: usize) -> Self {
+                match kind {
+                    None => Storage::Object(Vec::with_capacity(capacity)),
+                    $(Some(Kind::$variant) => Storage::$variant(Vec::with_capacity(capacity)),)*
+                }
+            }
+
+            pub(crate) fn kind(&self) -> Option<Kind> {
+                match self {
+                    Storage::Object(_) => None,
+                    $(Storage::$variant(_) => Some(Kind::$variant),)*
+                }
+            }
+        }
+
+        $(
+            impl Native for $t {
+                const KIND: Kind = Kind::$variant;
+
+                fn read(obj: &Bound<'_, PyAny>) -> Option<Self> {
+                    obj.downcast::<$class>().ok()?.try_borrow().ok().map(|v| v.$value)
+                }
+
+                fn convert(obj: &Bound<'_, PyAny>) -> PyResult<Self> {
+                    ($convert)(obj)
+                }
+
+                fn into_object(self, py: Python) -> PyResult<PyObject> {
+                    Ok(Py::new(py, $class::new(self))?.into_any())
+                }
+
+                fn items(storage: &Storage) -> Option<&[Self]> {
+                    match storage {
+                        Storage::$variant(items) => Some(items),
+                        _ => None,
+                    }
+                }
+            }
+
+            impl From<Vec<$t>> for Storage {
+                fn from(items: Vec<$t>) -> Self {
+                    Storage::$variant(items)
+                }
+            }
+        )*
+
+        /// Run `$body` with `$v` bound to the vector behind `$storage`.
+        macro_rules! each {
+            ($storage:expr, $v:ident => $body:expr) => {
+                match $storage {
+                    Storage::Object($v) => $body,
+                    $(Storage::$variant($v) => $body,)*
+                }
+            };
+        }
+
+        /// Run `$body` with `$v` bound to the vector behind a storage of
+        /// unboxed items, or `$object` for a storage of Python objects.
+        macro_rules! each_native {
+            ($storage:expr, $v:ident => $body:expr, _ => $object:expr) => {
+                match $storage {
+                    Storage::Object(_) => $object,
+                    $(Storage::$variant($v) => $body,)*
+                }
+            };
+        }
+
+        /// Run `$body` on the vectors behind two storages of the same kind.
+        macro_rules! pairs {
+            ($a:expr, $b:expr, $x:ident, $y:ident => $body:expr, _ => $other:expr) => {
+                match ($a, $b) {
+                    (Storage::Object($x), Storage::Object($y)) => $body,
+                    $((Storage::$variant($x), Storage::$variant($y)) => $body,)*
+                    _ => $other,
+                }
+            };
+        }
+    };
+}
+
+natives! {
+    (I8, i8, I8, 0, py_any_to_i8),
+    (I16, i16, I16, value, |o: &Bound<'_, PyAny>| o.extract::<i16>()),
+    (I32, i32, I32, value, |o: &Bound<'_, PyAny>| o.extract::<i32>()),
+    (I64, i64, I64, value, |o: &Bound<'_, PyAny>| o.extract::<i64>()),
+    (I128, i128, I128, value, |o: &Bound<'_, PyAny>| o.extract::<i128>()),
+    (ISize, isize, ISize, value, |o: &Bound<'_, PyAny>| o.extract::<isize>()),
+    (U8, u8, U8, value, |o: &Bound<'_, PyAny>| o.extract::<u8>()),
+    (U16, u16, U16, value, |o: &Bound<'_, PyAny>| o.extract::<u16>()),
+    (U32, u32, U32, value, |o: &Bound<'_, PyAny>| o.extract::<u32>()),
+    (U64, u64, U64, value, |o: &Bound<'_, PyAny>| o.extract::<u64>()),
+    (U128, u128, U128, value, |o: &Bound<'_, PyAny>| o.extract::<u128>()),
+    (USize, usize, Usize, value, |o: &Bound<'_, PyAny>| o.extract::<usize>()),
+    (F32, f32, F32, value, |o: &Bound<'_, PyAny>| o.extract::<f32>()),
+    (F64, f64, F64, value, |o: &Bound<'_, PyAny>| o.extract::<f64>()),
+    (Bool, bool, Bool, value, |o: &Bound<'_, PyAny>| o.extract::<bool>()),
+    (Char, char, Char, value, |o: &Bound<'_, PyAny>| o.extract::<char>()),
+}
+
+pub(crate) use each_native;
+
+impl From<Vec<PyObject>> for Storage {
+    fn from(items: Vec<PyObject>) -> Self {
+        Storage::Object(items)
+    }
+}
+
+/// Repeat `items` `count` times, treating negative counts as zero.
+fn repeat<T: Clone>(items: &[T], count: isize) -> PyResult<Vec<T>> {
+    let count = count.max(0) as usize;
+    let len = items
+        .len()
+        .checked_mul(count)
+        .filter(|&len| len <= isize::MAX as usize / std::mem::size_of::<T>().max(1))
+        .ok_or_else(|| PyMemoryError::new_err("List repetition result is too large"))?;
+
+    let mut out = Vec::with_capacity(len);
+    for _ in 0..count {
+        out.extend_from_slice(items);
+    }
+    Ok(out)
+}
+
+impl Storage {
+    /// Storage of `kind` holding `objects`, which must already be valid items.
+    pub(crate) fn from_objects(py: Python, kind: Option<Kind>, objects: Vec<PyObject>) -> PyResult<Self> {
+        if kind.is_none() {
+            return Ok(Storage::Object(objects));
+        }
+        let mut storage = Storage::new(kind, objects.len());
+        for item in objects {
+            storage.push(None, item.bind(py), None)?;
+        }
+        Ok(storage)
+    }
+
+    /// This storage converted to `kind`, if it is not of that kind already.
+    pub(crate) fn into_kind(self, py: Python, kind: Option<Kind>) -> PyResult<Self> {
+        if self.kind() == kind {
+            return Ok(self);
+        }
+        Storage::from_objects(py, kind, self.objects(py)?)
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        each!(self, v => v.len())
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The item at `index`, which must be in range.
+    pub(crate) fn get(&self, py: Python, index: usize) -> PyResult<PyObject> {
+        each!(self, v => v[index].boxed(py))
+    }
+
+    /// Every item as a Python object.
+    pub(crate) fn objects(&self, py: Python) -> PyResult<Vec<PyObject>> {
+        each!(self, v => v.iter().map(|item| item.boxed(py)).collect())
+    }
+
+    /// Validate `item` against `_type` and store it at `index`.
+    pub(crate) fn set(&mut self, _type: Option<&ElementType>, index: usize, item: &Bound<'_, PyAny>) -> PyResult<()> {
+        each!(self, v => {
+            v[index] = Element::accept(_type, item, Some(index))?;
+            Ok(())
+        })
+    }
+
+    /// Validate `item` against `_type` and append it. `index` is the
+    /// position reported in errors.
+    pub(crate) fn push(&mut self, _type: Option<&ElementType>, item: &Bound<'_, PyAny>, index: Option<usize>) -> PyResult<()> {
+        each!(self, v => {
+            v.push(Element::accept(_type, item, index)?);
+            Ok(())
+        })
+    }
+
+    /// Validate `item` against `_type` and insert it before `index`.
+    pub(crate) fn insert(&mut self, _type: Option<&ElementType>, index: usize, item: &Bound<'_, PyAny>) -> PyResult<()> {
+        each!(self, v => {
+            v.insert(index, Element::accept(_type, item, Some(index))?);
+            Ok(())
+        })
+    }
+
+    /// Remove and return the item at `index`, which must be in range.
+    pub(crate) fn remove(&mut self, py: Python, index: usize) -> PyResult<PyObject> {
+        each!(self, v => v.remove(index).boxed(py))
+    }
+
+    pub(crate) fn clear(&mut self) {
+        each!(self, v => v.clear())
+    }
+
+    pub(crate) fn reverse(&mut self) {
+        each!(self, v => v.reverse())
+    }
+
+    pub(crate) fn drain(&mut self, range: Range<usize>) {
+        each!(self, v => {
+            v.drain(range);
+        })
+    }
+
+    /// Remove every item whose flag in `remove` is set.
+    pub(crate) fn remove_flagged(&mut self, remove: &[bool]) {
+        each!(self, v => {
+            let mut flags = remove.iter();
+            v.retain(|_| !flags.next().copied().unwrap_or(false));
+        })
+    }
+
+    /// The items whose flag in `keep` is set.
+    pub(crate) fn masked(&self, keep: &[bool]) -> Storage {
+        each!(self, v => {
+            let items: Vec<_> = v.iter().zip(keep).filter(|(_, &k)| k).map(|(item, _)| item.clone()).collect();
+            items.into()
+        })
+    }
+
+    /// Replace `range` with `items`.
+    pub(crate) fn splice(&mut self, py: Python, range: Range<usize>, items: Storage) -> PyResult<()> {
+        let items = items.into_kind(py, self.kind())?;
+        pairs!(self, items, x, y => {
+            x.splice(range, y);
+        }, _ => unreachable!("storage kinds were just unified"));
+        Ok(())
+    }
+
+    pub(crate) fn extend(&mut self, py: Python, items: Storage) -> PyResult<()> {
+        let items = items.into_kind(py, self.kind())?;
+        pairs!(self, items, x, y => x.extend(y), _ => unreachable!("storage kinds were just unified"));
+        Ok(())
+    }
+
+    /// The `len` items at `start`, `start + step`, ...
+    pub(crate) fn select(&self, start: isize, step: isize, len: usize) -> Storage {
+        each!(self, v => {
+            let items: Vec<_> = (0..len as isize).map(|k| v[(start + k * step) as usize].clone()).collect();
+            items.into()
+        })
+    }
+
+    /// Overwrite the items at `start`, `start + step`, ... with `items`.
+    pub(crate) fn assign(&mut self, py: Python, start: isize, step: isize, items: Storage) -> PyResult<()> {
+        let items = items.into_kind(py, self.kind())?;
+        pairs!(self, items, x, y => {
+            for (k, item) in y.into_iter().enumerate() {
+                x[(start + k as isize * step) as usize] = item;
+            }
+        }, _ => unreachable!("storage kinds were just unified"));
+        Ok(())
+    }
+
+    pub(crate) fn repeat(&self, count: isize) -> PyResult<Storage> {
+        each!(self, v => Ok(repeat(v, count)?.into()))
+    }
+
+    /// Index of the first item in `[start, stop)` equal to `value`, or
+    /// `None` when the items have to be compared through Python.
+    pub(crate) fn position(&self, value: &Bound<'_, PyAny>, start: usize, stop: usize) -> Option<Option<usize>> {
+        each!(self, v => Element::position(v, value, start, stop))
+    }
+
+    /// Sort unboxed items in place, returning `false` when the items have
+    /// to be compared through Python instead.
+    pub(crate) fn sort(&mut self, reverse: bool, stable: bool) -> bool {
+        each!(self, v => Element::sort(v, reverse, stable))
+    }
+
+    /// Pointer to the items, their number and the size of each, for
+    /// exporting unboxed items through the buffer protocol.
+    pub(crate) fn raw(&mut self) -> (*mut c_void, usize, usize) {
+        fn raw<T>(items: &mut [T]) -> (*mut c_void, usize, usize) {
+            (items.as_mut_ptr().cast(), items.len(), std::mem::size_of::<T>())
+        }
+        each!(self, v => raw(v))
+    }
+
+    /// Lexicographic order of two storages of the same primitive, if there
+    /// is one without calling into Python.
+    pub(crate) fn compare(&self, other: &Storage) -> Option<Ordering> {
+        pairs!(self, other, x, y => Element::compare(x, y), _ => None)
+    }
+}
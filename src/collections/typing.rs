@@ -0,0 +1,355 @@
+use std::sync::Arc;
+
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+use pyo3::sync::GILOnceCell;
+use pyo3::types::{PyDict, PyTuple, PyType};
+
+use super::alias::ListAlias;
+use super::list::List;
+use super::storage::Kind;
+
+static ABC_MAPPING: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+static ABC_SEQUENCE: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+static ABC_SET: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+
+/// A typing expression compiled into a check that runs without going
+/// through `typing` again.
+pub(crate) enum Validator {
+    /// `typing.Any` or an unbound `TypeVar`
+    Any,
+    /// A plain class, checked with `isinstance`
+    Type(Py<PyType>),
+    /// `Union[...]`, `Optional[...]` and `X | Y`
+    Union(Vec<Validator>),
+    /// `Literal[...]`, matching both value and type
+    Literal(Vec<PyObject>),
+    /// `list[T]`, `set[T]`, `frozenset[T]` and the `Sequence`/`Set` ABCs
+    Collection(Py<PyType>, Box<Validator>),
+    /// `tuple[A, B]`, or `tuple[T, ...]` when the flag is set
+    Tuple(Vec<Validator>, bool),
+    /// `dict[K, V]` and the `Mapping` ABCs
+    Mapping(Py<PyType>, Box<Validator>, Box<Validator>),
+    /// A Rustique `List` whose items match the inner element type
+    List(Arc<ElementType>),
+}
+
+/// How a `coerce=True` list converts items that fail validation.
+enum Converter {
+    /// A Rustique primitive, built with the same conversion as its constructor
+    Native(Kind),
+    /// Any other class, called with the item
+    Call(Py<PyType>),
+}
+
+/// The element type of a `List`: the typing expression it was created
+/// with, the validator compiled from it and, for `coerce=True` lists, the
+/// converter applied to items that fail validation.
+pub(crate) struct ElementType {
+    source: PyObject,
+    validator: Validator,
+    converter: Option<Converter>,
+    /// Set for Rustique primitives, whose lists store values unboxed
+    pub(crate) kind: Option<Kind>,
+}
+
+impl ElementType {
+    pub(crate) fn compile(source: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let validator = Validator::compile(source)?;
+        let kind = match validator {
+            Validator::Type(ref cls) => Kind::of(cls.bind(source.py())),
+            _ => None,
+        };
+        Ok(ElementType {
+            source: source.clone().unbind(),
+            validator,
+            converter: None,
+            kind,
+        })
+    }
+
+    /// The same element type, converting items instead of rejecting them.
+    pub(crate) fn coercing(&self, py: Python) -> PyResult<Self> {
+        let mut element = ElementType::compile(self.source.bind(py))?;
+        let Some(cls) = element.as_type() else {
+            return Err(PyTypeError::new_err(format!(
+                "coerce=True requires a class as element type, got {}",
+                self.source.bind(py).repr()?
+            )));
+        };
+        let cls = cls.bind(py);
+        element.converter = Some(match element.kind {
+            Some(kind) => Converter::Native(kind),
+            None => Converter::Call(cls.clone().unbind()),
+        });
+        Ok(element)
+    }
+
+    /// Validate `item`, converting it first when coercing. `index` is the
+    /// position reported in errors.
+    pub(crate) fn accept(&self, item: &Bound<'_, PyAny>, index: Option<usize>) -> PyResult<PyObject> {
+        let py = item.py();
+        if self.is_valid(item)? {
+            return Ok(item.clone().unbind());
+        }
+
+        let converted = match self.converter {
+            None => return Err(self.type_error(item, index)),
+            Some(Converter::Native(kind)) => kind.convert(item),
+            Some(Converter::Call(ref cls)) => cls.bind(py).call1((item,)).map(Bound::unbind),
+        };
+        let converted = converted.map_err(|err| self.coercion_error(item, index, err))?;
+
+        if !self.is_valid(converted.bind(py))? {
+            return Err(PyTypeError::new_err(format!(
+                "Expected item of type {}, got {}{} after coercion",
+                self.name(py)?,
+                converted.bind(py).get_type().name()?,
+                at_index(index)
+            )));
+        }
+        Ok(converted)
+    }
+
+    pub(crate) fn coerces(&self) -> bool {
+        self.converter.is_some()
+    }
+
+    /// The error for an `item` that is not of this type.
+    pub(crate) fn type_error(&self, item: &Bound<'_, PyAny>, index: Option<usize>) -> PyErr {
+        let py = item.py();
+        PyTypeError::new_err(format!(
+            "Expected item of type {}, got {}{}",
+            self.name(py).unwrap_or_default(),
+            item.get_type().name().map(|n| n.to_string()).unwrap_or_default(),
+            at_index(index)
+        ))
+    }
+
+    /// `err` raised while coercing `item`, with the item and its position
+    /// added to the message.
+    pub(crate) fn coercion_error(&self, item: &Bound<'_, PyAny>, index: Option<usize>, err: PyErr) -> PyErr {
+        let py = item.py();
+        let message = format!(
+            "Cannot coerce {}{} to {}: {}",
+            item.repr().map(|r| r.to_string()).unwrap_or_else(|_| "item".to_string()),
+            at_index(index),
+            self.name(py).unwrap_or_default(),
+            err.value(py)
+        );
+        let coerced = PyErr::from_type(err.get_type(py), message);
+        coerced.set_cause(py, Some(err));
+        coerced
+    }
+
+    /// The class itself when the element type is a plain class.
+    pub(crate) fn as_type(&self) -> Option<&Py<PyType>> {
+        match self.validator {
+            Validator::Type(ref t) => Some(t),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn is_valid(&self, item: &Bound<'_, PyAny>) -> PyResult<bool> {
+        self.validator.is_valid(item)
+    }
+
+    /// Whether both element types come from equal typing expressions.
+    pub(crate) fn same(&self, py: Python, other: &ElementType) -> PyResult<bool> {
+        let (a, b) = (self.source.bind(py), other.source.bind(py));
+        Ok(a.is(b) || a.eq(b)?)
+    }
+
+    /// `int` for plain classes, the typing expression's repr otherwise.
+    pub(crate) fn name(&self, py: Python) -> PyResult<String> {
+        match self.source.bind(py).downcast::<PyType>() {
+            Ok(t) if self.as_type().is_some() => Ok(t.name()?.to_string()),
+            _ => Ok(self.source.bind(py).repr()?.to_string()),
+        }
+    }
+}
+
+fn at_index(index: Option<usize>) -> String {
+    index.map(|i| format!(" at index {}", i)).unwrap_or_default()
+}
+
+impl Validator {
+    fn compile(t: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let py = t.py();
+        let typing = py.import("typing")?;
+
+        if t.is_none() {
+            return Ok(Validator::Type(py.None().bind(py).get_type().unbind()));
+        }
+        if t.is(&typing.getattr("Any")?) || t.is_instance(&typing.getattr("TypeVar")?)? {
+            return Ok(Validator::Any);
+        }
+
+        // `List[T]` already carries its compiled element type
+        if let Ok(alias) = t.downcast::<ListAlias>() {
+            return Ok(Validator::List(alias.get().element.clone()));
+        }
+
+        let origin = typing.call_method1("get_origin", (t,))?;
+        if !origin.is_none() {
+            let args = typing.call_method1("get_args", (t,))?.downcast_into::<PyTuple>()?;
+            return Validator::compile_generic(t, &origin, &args);
+        }
+
+        if let Ok(cls) = t.downcast::<PyType>() {
+            return Ok(Validator::Type(cls.clone().unbind()));
+        }
+
+        Err(PyTypeError::new_err(format!(
+            "Unsupported element type: {}",
+            t.repr()?
+        )))
+    }
+
+    fn compile_generic(t: &Bound<'_, PyAny>, origin: &Bound<'_, PyAny>, args: &Bound<'_, PyTuple>) -> PyResult<Self> {
+        let py = t.py();
+        let typing = py.import("typing")?;
+        let compile_args = || -> PyResult<Vec<Validator>> {
+            args.iter().map(|arg| Validator::compile(&arg)).collect()
+        };
+        let item = |i: usize| -> PyResult<Box<Validator>> {
+            match args.get_item(i) {
+                Ok(arg) => Ok(Box::new(Validator::compile(&arg)?)),
+                Err(_) => Ok(Box::new(Validator::Any)),
+            }
+        };
+
+        if origin.is(&typing.getattr("Union")?) || origin.is(&py.import("types")?.getattr("UnionType")?) {
+            return Ok(Validator::Union(compile_args()?));
+        }
+        if origin.is(&typing.getattr("Literal")?) {
+            return Ok(Validator::Literal(args.iter().map(|arg| arg.unbind()).collect()));
+        }
+        if origin.is(&typing.getattr("Annotated")?) {
+            return Validator::compile(&args.get_item(0)?);
+        }
+
+        let Ok(origin) = origin.downcast::<PyType>() else {
+            return Err(PyTypeError::new_err(format!(
+                "Unsupported element type: {}",
+                t.repr()?
+            )));
+        };
+
+        if origin.is_subclass_of::<List>()? {
+            let inner = match args.get_item(0) {
+                Ok(arg) => ElementType::compile(&arg)?,
+                Err(_) => ElementType::compile(&typing.getattr("Any")?)?,
+            };
+            return Ok(Validator::List(Arc::new(inner)));
+        }
+        if origin.is_subclass_of::<PyTuple>()? {
+            let variadic = args.len() == 2 && args.get_item(1)?.is(&py.Ellipsis());
+            if variadic {
+                return Ok(Validator::Tuple(vec![Validator::compile(&args.get_item(0)?)?], true));
+            }
+            return Ok(Validator::Tuple(compile_args()?, false));
+        }
+        if origin.is_subclass(ABC_MAPPING.import(py, "collections.abc", "Mapping")?)? {
+            return Ok(Validator::Mapping(origin.clone().unbind(), item(0)?, item(1)?));
+        }
+        if origin.is_subclass(ABC_SEQUENCE.import(py, "collections.abc", "Sequence")?)?
+            || origin.is_subclass(ABC_SET.import(py, "collections.abc", "Set")?)?
+        {
+            return Ok(Validator::Collection(origin.clone().unbind(), item(0)?));
+        }
+
+        // Other generics (`type[T]`, `Callable[...]`, ...) only check the origin
+        Ok(Validator::Type(origin.clone().unbind()))
+    }
+
+    fn is_valid(&self, item: &Bound<'_, PyAny>) -> PyResult<bool> {
+        let py = item.py();
+        match self {
+            Validator::Any => Ok(true),
+            Validator::Type(t) => item.is_instance(t.bind(py)),
+            Validator::Union(options) => {
+                for option in options {
+                    if option.is_valid(item)? {
+                        return Ok(true);
+                    }
+                }
+                Ok(false)
+            }
+            Validator::Literal(values) => {
+                for value in values {
+                    let value = value.bind(py);
+                    if item.get_type().is(&value.get_type()) && item.eq(value)? {
+                        return Ok(true);
+                    }
+                }
+                Ok(false)
+            }
+            Validator::Collection(origin, inner) => {
+                if !item.is_instance(origin.bind(py))? {
+                    return Ok(false);
+                }
+                // Sequences and sets can be iterated without consuming them
+                for element in item.try_iter()? {
+                    if !inner.is_valid(&element?)? {
+                        return Ok(false);
+                    }
+                }
+                Ok(true)
+            }
+            Validator::Tuple(items, variadic) => {
+                let Ok(tuple) = item.downcast::<PyTuple>() else {
+                    return Ok(false);
+                };
+                if *variadic {
+                    for element in tuple.iter() {
+                        if !items[0].is_valid(&element)? {
+                            return Ok(false);
+                        }
+                    }
+                    return Ok(true);
+                }
+                if tuple.len() != items.len() {
+                    return Ok(false);
+                }
+                for (element, validator) in tuple.iter().zip(items) {
+                    if !validator.is_valid(&element)? {
+                        return Ok(false);
+                    }
+                }
+                Ok(true)
+            }
+            Validator::Mapping(origin, key, value) => {
+                if !item.is_instance(origin.bind(py))? {
+                    return Ok(false);
+                }
+                if let Ok(dict) = item.downcast::<PyDict>() {
+                    for (k, v) in dict.iter() {
+                        if !key.is_valid(&k)? || !value.is_valid(&v)? {
+                            return Ok(false);
+                        }
+                    }
+                }
+                Ok(true)
+            }
+            Validator::List(inner) => {
+                let Ok(list) = item.downcast::<List>() else {
+                    return Ok(false);
+                };
+                let list = list.try_borrow()?;
+                // A list already typed with the same expression needs no walk
+                if let Some(ref t) = list._type {
+                    if t.same(py, inner)? {
+                        return Ok(true);
+                    }
+                }
+                for i in 0..list.data.len() {
+                    if !inner.is_valid(list.data.get(py, i)?.bind(py))? {
+                        return Ok(false);
+                    }
+                }
+                Ok(true)
+            }
+        }
+    }
+}
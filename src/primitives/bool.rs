@@ -2,7 +2,7 @@ use pyo3::prelude::*;
 
 #[pyclass(name="bool")]
 pub struct Bool {
-    value: bool,
+    pub(crate) value: bool,
 }
 
 #[pymethods]
@@ -2,7 +2,7 @@ use pyo3::prelude::*;
 
 #[pyclass(name="char")]
 pub struct Char {
-    value: char,
+    pub(crate) value: char,
 }
 
 #[pymethods]
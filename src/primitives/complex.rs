@@ -0,0 +1,831 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use pyo3::class::basic::CompareOp;
+use pyo3::exceptions::{PyTypeError, PyValueError, PyZeroDivisionError};
+use pyo3::prelude::*;
+use pyo3::types::{PyComplex, PyFloat, PyString, PyType};
+use pyo3::IntoPyObjectExt;
+use rug::ops::Pow;
+use rug::{Complex as MpComplex, Float as MpFloat, Integer};
+
+use super::f32::F32;
+use super::f64::F64;
+use super::float::{check_prec, Float, RoundingMode};
+use super::int::{py_any_to_integer, rational_hash, HASH_INF};
+
+/// Multiplier CPython uses to combine the hashes of the real and imaginary parts
+const HASH_IMAG: usize = 1000003;
+
+/// Floating-point scalar a native `Cx` can be built from
+pub trait Real:
+    Copy + PartialOrd + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const TWO: Self;
+    fn abs(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn powf(self, exponent: Self) -> Self;
+    fn hypot(self, other: Self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn is_sign_negative(self) -> bool;
+    /// Shortest string that round-trips at this precision
+    fn shortest(self) -> String;
+}
+
+macro_rules! impl_real {
+    ($t:ty) => {
+        impl Real for $t {
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+            const TWO: Self = 2.0;
+
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+
+            fn sqrt(self) -> Self {
+                <$t>::sqrt(self)
+            }
+
+            fn exp(self) -> Self {
+                <$t>::exp(self)
+            }
+
+            fn ln(self) -> Self {
+                <$t>::ln(self)
+            }
+
+            fn sin(self) -> Self {
+                <$t>::sin(self)
+            }
+
+            fn cos(self) -> Self {
+                <$t>::cos(self)
+            }
+
+            fn powf(self, exponent: Self) -> Self {
+                <$t>::powf(self, exponent)
+            }
+
+            fn hypot(self, other: Self) -> Self {
+                <$t>::hypot(self, other)
+            }
+
+            fn atan2(self, other: Self) -> Self {
+                <$t>::atan2(self, other)
+            }
+
+            fn is_sign_negative(self) -> bool {
+                <$t>::is_sign_negative(self)
+            }
+
+            fn shortest(self) -> String {
+                format!("{:?}", self)
+            }
+        }
+    };
+}
+
+impl_real!(f32);
+impl_real!(f64);
+
+/// Complex number over a native float
+#[derive(Clone, Copy, PartialEq)]
+pub struct Cx<T> {
+    pub re: T,
+    pub im: T,
+}
+
+impl<T: Real> Cx<T> {
+    fn new(re: T, im: T) -> Self {
+        Cx { re, im }
+    }
+
+    fn is_zero(self) -> bool {
+        self.re == T::ZERO && self.im == T::ZERO
+    }
+
+    fn add(self, other: Self) -> Self {
+        Cx::new(self.re + other.re, self.im + other.im)
+    }
+
+    fn sub(self, other: Self) -> Self {
+        Cx::new(self.re - other.re, self.im - other.im)
+    }
+
+    fn mul(self, other: Self) -> Self {
+        Cx::new(
+            self.re * other.re - self.im * other.im,
+            self.re * other.im + self.im * other.re,
+        )
+    }
+
+    /// Smith's algorithm, as in CPython's `_Py_c_quot`: scaling by the larger
+    /// component of the divisor avoids overflow in the intermediate products
+    fn div(self, other: Self) -> Self {
+        if other.re.abs() >= other.im.abs() {
+            let ratio = other.im / other.re;
+            let denom = other.re + other.im * ratio;
+            Cx::new((self.re + self.im * ratio) / denom, (self.im - self.re * ratio) / denom)
+        } else if other.im.abs() >= other.re.abs() {
+            let ratio = other.re / other.im;
+            let denom = other.re * ratio + other.im;
+            Cx::new((self.re * ratio + self.im) / denom, (self.im * ratio - self.re) / denom)
+        } else {
+            // A NaN component in the divisor
+            let nan = T::ZERO / T::ZERO;
+            Cx::new(nan, nan)
+        }
+    }
+
+    fn neg(self) -> Self {
+        Cx::new(-self.re, -self.im)
+    }
+
+    fn conj(self) -> Self {
+        Cx::new(self.re, -self.im)
+    }
+
+    fn norm(self) -> T {
+        self.re.hypot(self.im)
+    }
+
+    fn arg(self) -> T {
+        self.im.atan2(self.re)
+    }
+
+    fn from_polar(r: T, theta: T) -> Self {
+        Cx::new(r * theta.cos(), r * theta.sin())
+    }
+
+    fn exp(self) -> Self {
+        Cx::from_polar(self.re.exp(), self.im)
+    }
+
+    fn ln(self) -> Self {
+        Cx::new(self.norm().ln(), self.arg())
+    }
+
+    fn sqrt(self) -> Self {
+        if self.im == T::ZERO {
+            if self.re >= T::ZERO {
+                return Cx::new(self.re.sqrt(), self.im);
+            }
+            let im = (-self.re).sqrt();
+            return Cx::new(T::ZERO, if self.im.is_sign_negative() { -im } else { im });
+        }
+        if self.re == T::ZERO {
+            let x = (self.im.abs() / T::TWO).sqrt();
+            return Cx::new(x, if self.im.is_sign_negative() { -x } else { x });
+        }
+        Cx::from_polar(self.norm().sqrt(), self.arg() / T::TWO)
+    }
+
+    fn powi(self, exponent: i64) -> Self {
+        let mut base = self;
+        let mut acc = Cx::new(T::ONE, T::ZERO);
+        let mut n = exponent.unsigned_abs();
+        while n > 0 {
+            if n & 1 == 1 {
+                acc = acc.mul(base);
+            }
+            base = base.mul(base);
+            n >>= 1;
+        }
+        if exponent < 0 {
+            Cx::new(T::ONE, T::ZERO).div(acc)
+        } else {
+            acc
+        }
+    }
+
+    fn powc(self, exponent: Self) -> Self {
+        if exponent.is_zero() {
+            return Cx::new(T::ONE, T::ZERO);
+        }
+        if self.is_zero() {
+            return Cx::new(T::ZERO, T::ZERO);
+        }
+        if exponent.im == T::ZERO {
+            return Cx::from_polar(self.norm().powf(exponent.re), self.arg() * exponent.re);
+        }
+        self.ln().mul(exponent).exp()
+    }
+}
+
+/// Precision a complex value is computed in
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ComplexKind {
+    F32,
+    F64,
+    Mp(u32),
+}
+
+impl ComplexKind {
+    fn common(self, other: ComplexKind) -> ComplexKind {
+        match (self, other) {
+            (ComplexKind::Mp(a), ComplexKind::Mp(b)) => ComplexKind::Mp(a.max(b)),
+            (ComplexKind::Mp(p), _) | (_, ComplexKind::Mp(p)) => ComplexKind::Mp(p),
+            (ComplexKind::F64, _) | (_, ComplexKind::F64) => ComplexKind::F64,
+            _ => ComplexKind::F32,
+        }
+    }
+
+    /// Accept `"f32"`, `"f64"`, `"mp"` or the matching Rustique or Python float type
+    fn parse(obj: &Bound<'_, PyAny>, prec: Option<u32>) -> PyResult<ComplexKind> {
+        let mp = || -> PyResult<ComplexKind> { Ok(ComplexKind::Mp(check_prec(prec.unwrap_or(53))?)) };
+        if let Ok(name) = obj.downcast::<PyString>() {
+            return match name.to_str()? {
+                "f32" => Ok(ComplexKind::F32),
+                "f64" => Ok(ComplexKind::F64),
+                "mp" => mp(),
+                other => Err(PyValueError::new_err(format!("Unknown complex kind: {:?}", other))),
+            };
+        }
+        if let Ok(ty) = obj.downcast::<PyType>() {
+            if ty.is(&obj.py().get_type::<F32>()) {
+                return Ok(ComplexKind::F32);
+            }
+            if ty.is(&obj.py().get_type::<F64>()) || ty.is(&obj.py().get_type::<PyFloat>()) {
+                return Ok(ComplexKind::F64);
+            }
+            if ty.is(&obj.py().get_type::<Float>()) {
+                return mp();
+            }
+        }
+        Err(PyTypeError::new_err("kind must be 'f32', 'f64', 'mp' or a float type"))
+    }
+}
+
+#[derive(Clone)]
+pub enum ComplexValue {
+    F32(Cx<f32>),
+    F64(Cx<f64>),
+    Mp(MpComplex),
+}
+
+impl ComplexValue {
+    fn kind(&self) -> ComplexKind {
+        match self {
+            ComplexValue::F32(_) => ComplexKind::F32,
+            ComplexValue::F64(_) => ComplexKind::F64,
+            ComplexValue::Mp(z) => ComplexKind::Mp(z.prec().0),
+        }
+    }
+
+    /// Build a value of `kind` from a pair of Python floats
+    fn from_f64(re: f64, im: f64, kind: ComplexKind) -> Self {
+        match kind {
+            ComplexKind::F32 => ComplexValue::F32(Cx::new(re as f32, im as f32)),
+            ComplexKind::F64 => ComplexValue::F64(Cx::new(re, im)),
+            ComplexKind::Mp(prec) => ComplexValue::Mp(MpComplex::with_val(prec, (re, im))),
+        }
+    }
+
+    fn from_integer(value: Integer, kind: ComplexKind) -> Self {
+        match kind {
+            ComplexKind::Mp(prec) => ComplexValue::Mp(MpComplex::with_val(prec, value)),
+            _ => ComplexValue::from_f64(value.to_f64(), 0.0, kind),
+        }
+    }
+
+    fn to_kind(&self, kind: ComplexKind) -> Self {
+        match (self, kind) {
+            (ComplexValue::F32(z), ComplexKind::F32) => ComplexValue::F32(*z),
+            (ComplexValue::F64(z), ComplexKind::F64) => ComplexValue::F64(*z),
+            (ComplexValue::F32(z), _) => ComplexValue::from_f64(z.re as f64, z.im as f64, kind),
+            (ComplexValue::F64(z), _) => ComplexValue::from_f64(z.re, z.im, kind),
+            (ComplexValue::Mp(z), ComplexKind::Mp(prec)) => ComplexValue::Mp(MpComplex::with_val(prec, z)),
+            (ComplexValue::Mp(z), _) => ComplexValue::from_f64(z.real().to_f64(), z.imag().to_f64(), kind),
+        }
+    }
+
+    fn to_f64(&self) -> (f64, f64) {
+        match self {
+            ComplexValue::F32(z) => (z.re as f64, z.im as f64),
+            ComplexValue::F64(z) => (z.re, z.im),
+            ComplexValue::Mp(z) => (z.real().to_f64(), z.imag().to_f64()),
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        match self {
+            ComplexValue::F32(z) => z.is_zero(),
+            ComplexValue::F64(z) => z.is_zero(),
+            ComplexValue::Mp(z) => z.real().is_zero() && z.imag().is_zero(),
+        }
+    }
+
+    /// Exact real and imaginary parts as multi-precision floats
+    fn parts(&self) -> (MpFloat, MpFloat) {
+        match self {
+            ComplexValue::F32(z) => (MpFloat::with_val(24, z.re), MpFloat::with_val(24, z.im)),
+            ComplexValue::F64(z) => (MpFloat::with_val(53, z.re), MpFloat::with_val(53, z.im)),
+            ComplexValue::Mp(z) => z.clone().into_real_imag(),
+        }
+    }
+
+    fn map(
+        &self,
+        f32_op: impl Fn(Cx<f32>) -> Cx<f32>,
+        f64_op: impl Fn(Cx<f64>) -> Cx<f64>,
+        mp_op: impl Fn(MpComplex) -> MpComplex,
+    ) -> Self {
+        match self {
+            ComplexValue::F32(z) => ComplexValue::F32(f32_op(*z)),
+            ComplexValue::F64(z) => ComplexValue::F64(f64_op(*z)),
+            ComplexValue::Mp(z) => ComplexValue::Mp(mp_op(z.clone())),
+        }
+    }
+
+    /// Apply a binary operation after promoting both operands to their common kind
+    fn zip(
+        &self,
+        other: &ComplexValue,
+        f32_op: impl Fn(Cx<f32>, Cx<f32>) -> Cx<f32>,
+        f64_op: impl Fn(Cx<f64>, Cx<f64>) -> Cx<f64>,
+        mp_op: impl Fn(&MpComplex, &MpComplex, u32) -> MpComplex,
+    ) -> Self {
+        let kind = self.kind().common(other.kind());
+        match (self.to_kind(kind), other.to_kind(kind)) {
+            (ComplexValue::F32(a), ComplexValue::F32(b)) => ComplexValue::F32(f32_op(a, b)),
+            (ComplexValue::F64(a), ComplexValue::F64(b)) => ComplexValue::F64(f64_op(a, b)),
+            (ComplexValue::Mp(a), ComplexValue::Mp(b)) => ComplexValue::Mp(mp_op(&a, &b, a.prec().0)),
+            _ => unreachable!("operands were promoted to the same kind"),
+        }
+    }
+}
+
+/// Hash of a single component, matching Python's `hash(float)`
+fn component_hash(value: &MpFloat) -> isize {
+    if let Some(ratio) = value.to_rational() {
+        return rational_hash(&ratio);
+    }
+    if value.is_infinite() {
+        return if value.is_sign_negative() { -HASH_INF } else { HASH_INF };
+    }
+    0
+}
+
+/// Format a native component the way Python's `complex.__repr__` does:
+/// no trailing `.0`, and a signed exponent of at least two digits
+fn py_style(s: String) -> String {
+    match s.as_str() {
+        "NaN" => return "nan".to_string(),
+        "inf" | "-inf" => return s,
+        _ => {}
+    }
+    let (mantissa, exponent) = match s.find('e') {
+        Some(pos) => (&s[..pos], Some(&s[pos + 1..])),
+        None => (&s[..], None),
+    };
+    let mantissa = mantissa.strip_suffix(".0").unwrap_or(mantissa);
+    match exponent {
+        None => mantissa.to_string(),
+        Some(exponent) => {
+            let (sign, digits) = match exponent.strip_prefix('-') {
+                Some(digits) => ('-', digits),
+                None => ('+', exponent),
+            };
+            format!("{}e{}{:0>2}", mantissa, sign, digits)
+        }
+    }
+}
+
+/// Complex number over `f32`, `f64` or a multi-precision float.
+///
+/// Python numbers adopt the kind of the complex they are combined with, so
+/// `z * 2.0` stays in `f32`; mixing two `Complex` values promotes to the wider kind.
+#[pyclass(name="Complex")]
+#[derive(Clone)]
+pub struct Complex(pub(crate) ComplexValue);
+
+impl Complex {
+    /// Convert the other operand; `None` for unsupported types
+    fn operand(&self, other: &Bound<'_, PyAny>) -> Option<ComplexValue> {
+        let kind = self.0.kind();
+        if let Ok(z) = other.downcast::<Complex>() {
+            return Some(z.borrow().0.clone());
+        }
+        if let Ok(f) = other.downcast::<Float>() {
+            let f = &f.borrow().value;
+            return Some(ComplexValue::Mp(MpComplex::with_val(f.prec(), f)));
+        }
+        if let Ok(f) = other.downcast::<F32>() {
+            return Some(ComplexValue::F32(Cx::new(f.borrow().value, 0.0)));
+        }
+        if let Ok(f) = other.downcast::<F64>() {
+            return Some(ComplexValue::F64(Cx::new(f.borrow().value, 0.0)));
+        }
+        if let Ok(z) = other.downcast::<PyComplex>() {
+            return Some(ComplexValue::from_f64(z.real(), z.imag(), kind));
+        }
+        if let Ok(f) = other.downcast::<PyFloat>() {
+            return Some(ComplexValue::from_f64(f.value(), 0.0, kind));
+        }
+        py_any_to_integer(other).ok().map(|i| ComplexValue::from_integer(i, kind))
+    }
+
+    /// Convert the other operand without rounding it to this value's kind,
+    /// so comparisons are exact
+    fn exact_operand(&self, other: &Bound<'_, PyAny>) -> Option<ComplexValue> {
+        if let Ok(z) = other.downcast::<PyComplex>() {
+            return Some(ComplexValue::F64(Cx::new(z.real(), z.imag())));
+        }
+        if let Ok(f) = other.downcast::<PyFloat>() {
+            return Some(ComplexValue::F64(Cx::new(f.value(), 0.0)));
+        }
+        if let Ok(i) = py_any_to_integer(other) {
+            let prec = i.significant_bits().max(rug::float::prec_min());
+            return Some(ComplexValue::from_integer(i, ComplexKind::Mp(prec)));
+        }
+        self.operand(other)
+    }
+
+    fn real_to_py(py: Python, value: MpFloat, kind: ComplexKind) -> PyResult<PyObject> {
+        match kind {
+            ComplexKind::Mp(_) => Float { value, round: RoundingMode::Nearest }.into_py_any(py),
+            _ => value.to_f64().into_py_any(py),
+        }
+    }
+
+    fn binary(
+        &self,
+        py: Python,
+        other: &Bound<'_, PyAny>,
+        f32_op: impl Fn(Cx<f32>, Cx<f32>) -> Cx<f32>,
+        f64_op: impl Fn(Cx<f64>, Cx<f64>) -> Cx<f64>,
+        mp_op: impl Fn(&MpComplex, &MpComplex, u32) -> MpComplex,
+    ) -> PyResult<PyObject> {
+        match self.operand(other) {
+            Some(other) => Complex(self.0.zip(&other, f32_op, f64_op, mp_op)).into_py_any(py),
+            None => Ok(py.NotImplemented()),
+        }
+    }
+
+    fn pow_value(base: &ComplexValue, exponent: &Bound<'_, PyAny>, exponent_value: &ComplexValue) -> PyResult<ComplexValue> {
+        if base.is_zero() && !exponent_value.is_zero() {
+            let (re, im) = exponent_value.to_f64();
+            if im != 0.0 || re < 0.0 {
+                return Err(PyZeroDivisionError::new_err("0.0 to a negative or complex power"));
+            }
+        }
+        // Integer exponents use repeated multiplication, like `powi`
+        if !exponent.is_instance_of::<PyFloat>() && !exponent.is_instance_of::<Complex>() {
+            if let Some(n) = py_any_to_integer(exponent).ok().and_then(|n| n.to_i64()) {
+                return Ok(base.map(
+                    |z| z.powi(n),
+                    |z| z.powi(n),
+                    |z| {
+                        let prec = z.prec().0;
+                        MpComplex::with_val(prec, z.pow(n))
+                    },
+                ));
+            }
+        }
+        Ok(base.zip(exponent_value, Cx::powc, Cx::powc, |a, b, prec| MpComplex::with_val(prec, a.pow(b))))
+    }
+}
+
+#[pymethods]
+impl Complex {
+    /// `Complex(real, imag, kind=None, prec=None)`; the kind defaults to the
+    /// widest Rustique float among the arguments, otherwise `f64`
+    #[new]
+    #[pyo3(signature = (real=None, imag=None, kind=None, prec=None))]
+    pub fn new(
+        real: Option<&Bound<'_, PyAny>>,
+        imag: Option<&Bound<'_, PyAny>>,
+        kind: Option<&Bound<'_, PyAny>>,
+        prec: Option<u32>,
+    ) -> PyResult<Self> {
+        let zero = Complex(ComplexValue::F64(Cx::new(0.0, 0.0)));
+        let explicit = kind.map(|kind| ComplexKind::parse(kind, prec)).transpose()?;
+        let base = match (explicit, prec) {
+            (Some(kind), _) => kind,
+            (None, Some(prec)) => ComplexKind::Mp(check_prec(prec)?),
+            (None, None) => ComplexKind::F64,
+        };
+        let seed = Complex(zero.0.to_kind(base));
+
+        let component = |value: Option<&Bound<'_, PyAny>>| -> PyResult<ComplexValue> {
+            let Some(value) = value else {
+                return Ok(seed.0.clone());
+            };
+            if let Ok(s) = value.downcast::<PyString>() {
+                let s = s.to_str()?.trim().replace('_', "");
+                if let ComplexKind::Mp(prec) = base {
+                    let parsed = MpFloat::parse(&s)
+                        .map_err(|e| PyValueError::new_err(format!("Invalid literal for Complex: {}", e)))?;
+                    return Ok(ComplexValue::Mp(MpComplex::with_val(prec, parsed)));
+                }
+                let parsed = value.py().get_type::<PyComplex>().call1((s,))?;
+                return Ok(seed.operand(&parsed).unwrap());
+            }
+            match seed.operand(value) {
+                Some(value) => Ok(value),
+                None => Err(PyTypeError::new_err(format!(
+                    "Could not convert {} to Complex",
+                    value.get_type().name()?
+                ))),
+            }
+        };
+
+        let real = component(real)?;
+        let imag = component(imag)?;
+        let kind = explicit.unwrap_or_else(|| real.kind().common(imag.kind()).common(base));
+        let (real, imag) = (real.to_kind(kind), imag.to_kind(kind));
+        // Real components are placed directly, which keeps signed zeros
+        let value = match (&real, &imag) {
+            (ComplexValue::F32(a), ComplexValue::F32(b)) if a.im == 0.0 && b.im == 0.0 => ComplexValue::F32(Cx::new(a.re, b.re)),
+            (ComplexValue::F64(a), ComplexValue::F64(b)) if a.im == 0.0 && b.im == 0.0 => ComplexValue::F64(Cx::new(a.re, b.re)),
+            (ComplexValue::Mp(a), ComplexValue::Mp(b)) if a.imag().is_zero() && b.imag().is_zero() => {
+                ComplexValue::Mp(MpComplex::with_val(a.prec().0, (a.real(), b.real())))
+            }
+            _ => {
+                let i = ComplexValue::F32(Cx::new(0.0, 1.0));
+                let imag = imag.zip(&i, Cx::mul, Cx::mul, |a, b, prec| MpComplex::with_val(prec, a * b));
+                real.zip(&imag, Cx::add, Cx::add, |a, b, prec| MpComplex::with_val(prec, a + b))
+            }
+        };
+        Ok(Complex(value))
+    }
+
+    /// `z = r * (cos(theta) + i sin(theta))`
+    #[staticmethod]
+    #[pyo3(signature = (r, theta, kind=None, prec=None))]
+    pub fn from_polar(
+        r: &Bound<'_, PyAny>,
+        theta: &Bound<'_, PyAny>,
+        kind: Option<&Bound<'_, PyAny>>,
+        prec: Option<u32>,
+    ) -> PyResult<Self> {
+        let r = Complex::new(Some(r), None, kind, prec)?;
+        let theta = Complex::new(Some(theta), None, kind, prec)?;
+        let unit = theta.0.map(
+            |t| Cx::from_polar(1.0, t.re),
+            |t| Cx::from_polar(1.0, t.re),
+            |t| {
+                let prec = t.prec().0;
+                MpComplex::with_val(prec, (MpFloat::new(prec), t.real())).exp()
+            },
+        );
+        Ok(Complex(r.0.zip(&unit, |r, u| Cx::new(r.re * u.re, r.re * u.im), |r, u| Cx::new(r.re * u.re, r.re * u.im), |r, u, prec| {
+            MpComplex::with_val(prec, r * u)
+        })))
+    }
+
+    /// `"f32"`, `"f64"` or `"mp"`
+    #[getter]
+    pub fn kind(&self) -> &'static str {
+        match self.0.kind() {
+            ComplexKind::F32 => "f32",
+            ComplexKind::F64 => "f64",
+            ComplexKind::Mp(_) => "mp",
+        }
+    }
+
+    /// Bits of precision in each component
+    #[getter]
+    pub fn prec(&self) -> u32 {
+        match self.0.kind() {
+            ComplexKind::F32 => 24,
+            ComplexKind::F64 => 53,
+            ComplexKind::Mp(prec) => prec,
+        }
+    }
+
+    /// Convert to another kind, rounding to nearest
+    #[pyo3(signature = (kind, prec=None))]
+    pub fn cast(&self, kind: &Bound<'_, PyAny>, prec: Option<u32>) -> PyResult<Self> {
+        Ok(Complex(self.0.to_kind(ComplexKind::parse(kind, prec)?)))
+    }
+
+    #[getter]
+    pub fn real(&self, py: Python) -> PyResult<PyObject> {
+        Complex::real_to_py(py, self.0.parts().0, self.0.kind())
+    }
+
+    #[getter]
+    pub fn imag(&self, py: Python) -> PyResult<PyObject> {
+        Complex::real_to_py(py, self.0.parts().1, self.0.kind())
+    }
+
+    pub fn __repr__(&self) -> String {
+        match &self.0 {
+            ComplexValue::F32(z) => format!("Complex({}, {}, kind='f32')", z.re.shortest(), z.im.shortest()),
+            ComplexValue::F64(z) => format!("Complex({}, {})", z.re.shortest(), z.im.shortest()),
+            ComplexValue::Mp(z) => format!("Complex('{}', '{}', prec={})", z.real(), z.imag(), z.prec().0),
+        }
+    }
+
+    /// Python `complex` notation, with every digit the precision carries
+    pub fn __str__(&self) -> String {
+        let (re, im) = match &self.0 {
+            ComplexValue::F32(z) => (py_style(z.re.shortest()), py_style(z.im.shortest())),
+            ComplexValue::F64(z) => (py_style(z.re.shortest()), py_style(z.im.shortest())),
+            ComplexValue::Mp(z) => (z.real().to_string(), z.imag().to_string()),
+        };
+        let (re_part, _) = self.0.parts();
+        if re_part.is_zero() && !re_part.is_sign_negative() {
+            return format!("{}j", im);
+        }
+        let sign = if im.starts_with('-') { "" } else { "+" };
+        format!("({}{}{}j)", re, sign, im)
+    }
+
+    /// Components with `digits` significant digits each
+    #[pyo3(signature = (digits=None))]
+    pub fn to_string(&self, digits: Option<usize>) -> String {
+        let Some(digits) = digits else {
+            return self.__str__();
+        };
+        let (re, im) = self.0.parts();
+        let im = im.to_string_radix(10, Some(digits));
+        let sign = if im.starts_with('-') { "" } else { "+" };
+        format!("({}{}{}j)", re.to_string_radix(10, Some(digits)), sign, im)
+    }
+
+    pub fn __format__(&self, py: Python, spec: &str) -> PyResult<String> {
+        if spec.is_empty() {
+            return Ok(self.__str__());
+        }
+        let (re, im) = self.0.to_f64();
+        PyComplex::from_doubles(py, re, im).call_method1("__format__", (spec,))?.extract()
+    }
+
+    pub fn __complex__<'py>(&self, py: Python<'py>) -> Bound<'py, PyComplex> {
+        let (re, im) = self.0.to_f64();
+        PyComplex::from_doubles(py, re, im)
+    }
+
+    /// Hashes equal to Python's `complex` of the same value
+    pub fn __hash__(&self) -> isize {
+        let (re, im) = self.0.parts();
+        let hash = (component_hash(&re) as usize).wrapping_add(HASH_IMAG.wrapping_mul(component_hash(&im) as usize)) as isize;
+        if hash == -1 {
+            -2
+        } else {
+            hash
+        }
+    }
+
+    pub fn __bool__(&self) -> bool {
+        !self.0.is_zero()
+    }
+
+    pub fn __richcmp__(&self, py: Python, other: &Bound<'_, PyAny>, op: CompareOp) -> PyResult<PyObject> {
+        let Some(other) = self.exact_operand(other) else {
+            return Ok(py.NotImplemented());
+        };
+        let (a, b) = (self.0.parts(), other.parts());
+        let equal = a.0 == b.0 && a.1 == b.1;
+        match op {
+            CompareOp::Eq => equal.into_py_any(py),
+            CompareOp::Ne => (!equal).into_py_any(py),
+            _ => Ok(py.NotImplemented()),
+        }
+    }
+
+    pub fn __add__(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        self.binary(py, other, Cx::add, Cx::add, |a, b, prec| MpComplex::with_val(prec, a + b))
+    }
+
+    pub fn __radd__(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        self.binary(py, other, |a, b| b.add(a), |a, b| b.add(a), |a, b, prec| MpComplex::with_val(prec, b + a))
+    }
+
+    pub fn __sub__(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        self.binary(py, other, Cx::sub, Cx::sub, |a, b, prec| MpComplex::with_val(prec, a - b))
+    }
+
+    pub fn __rsub__(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        self.binary(py, other, |a, b| b.sub(a), |a, b| b.sub(a), |a, b, prec| MpComplex::with_val(prec, b - a))
+    }
+
+    pub fn __mul__(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        self.binary(py, other, Cx::mul, Cx::mul, |a, b, prec| MpComplex::with_val(prec, a * b))
+    }
+
+    pub fn __rmul__(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        self.binary(py, other, |a, b| b.mul(a), |a, b| b.mul(a), |a, b, prec| MpComplex::with_val(prec, b * a))
+    }
+
+    pub fn __truediv__(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        match self.operand(other) {
+            Some(divisor) if divisor.is_zero() => Err(PyZeroDivisionError::new_err("Complex division by zero")),
+            _ => self.binary(py, other, Cx::div, Cx::div, |a, b, prec| MpComplex::with_val(prec, a / b)),
+        }
+    }
+
+    pub fn __rtruediv__(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        if self.0.is_zero() {
+            return Err(PyZeroDivisionError::new_err("Complex division by zero"));
+        }
+        self.binary(py, other, |a, b| b.div(a), |a, b| b.div(a), |a, b, prec| MpComplex::with_val(prec, b / a))
+    }
+
+    pub fn __pow__(&self, py: Python, other: &Bound<'_, PyAny>, modulo: Option<&Bound<'_, PyAny>>) -> PyResult<PyObject> {
+        if modulo.is_some_and(|m| !m.is_none()) {
+            return Err(PyValueError::new_err("Complex modulo"));
+        }
+        let Some(exponent) = self.operand(other) else {
+            return Ok(py.NotImplemented());
+        };
+        Complex(Complex::pow_value(&self.0, other, &exponent)?).into_py_any(py)
+    }
+
+    pub fn __rpow__(&self, py: Python, other: &Bound<'_, PyAny>, _modulo: Option<&Bound<'_, PyAny>>) -> PyResult<PyObject> {
+        let Some(base) = self.operand(other) else {
+            return Ok(py.NotImplemented());
+        };
+        let exponent = self.clone().into_pyobject(py)?.into_any();
+        Complex(Complex::pow_value(&base, &exponent, &self.0)?).into_py_any(py)
+    }
+
+    pub fn __neg__(&self) -> Self {
+        Complex(self.0.map(Cx::neg, Cx::neg, |z| -z))
+    }
+
+    pub fn __pos__(&self) -> Self {
+        self.clone()
+    }
+
+    /// Magnitude, as a float of the same precision
+    pub fn __abs__(&self, py: Python) -> PyResult<PyObject> {
+        let value = match &self.0 {
+            ComplexValue::F32(z) => MpFloat::with_val(24, z.norm()),
+            ComplexValue::F64(z) => MpFloat::with_val(53, z.norm()),
+            ComplexValue::Mp(z) => MpFloat::with_val(z.prec().0, z.abs_ref()),
+        };
+        Complex::real_to_py(py, value, self.0.kind())
+    }
+
+    /// Phase angle in `(-pi, pi]`
+    pub fn arg(&self, py: Python) -> PyResult<PyObject> {
+        let value = match &self.0 {
+            ComplexValue::F32(z) => MpFloat::with_val(24, z.arg()),
+            ComplexValue::F64(z) => MpFloat::with_val(53, z.arg()),
+            ComplexValue::Mp(z) => MpFloat::with_val(z.prec().0, z.arg_ref()),
+        };
+        Complex::real_to_py(py, value, self.0.kind())
+    }
+
+    /// `(abs(z), z.arg())`
+    pub fn polar(&self, py: Python) -> PyResult<(PyObject, PyObject)> {
+        Ok((self.__abs__(py)?, self.arg(py)?))
+    }
+
+    pub fn conj(&self) -> Self {
+        Complex(self.0.map(Cx::conj, Cx::conj, |z| z.conj()))
+    }
+
+    pub fn exp(&self) -> Self {
+        Complex(self.0.map(Cx::exp, Cx::exp, |z| z.exp()))
+    }
+
+    /// Principal natural logarithm
+    pub fn ln(&self) -> Self {
+        Complex(self.0.map(Cx::ln, Cx::ln, |z| z.ln()))
+    }
+
+    /// Logarithm to `base`, natural when omitted
+    #[pyo3(signature = (base=None))]
+    pub fn log(&self, base: Option<&Bound<'_, PyAny>>) -> PyResult<Self> {
+        let ln = self.ln();
+        let Some(base) = base else {
+            return Ok(ln);
+        };
+        let base = self
+            .operand(base)
+            .ok_or_else(|| PyTypeError::new_err("log() base must be a number"))?;
+        let ln_base = Complex(base).ln();
+        if ln_base.0.is_zero() {
+            return Err(PyZeroDivisionError::new_err("Logarithm base must not be 1"));
+        }
+        Ok(Complex(ln.0.zip(&ln_base.0, Cx::div, Cx::div, |a, b, prec| MpComplex::with_val(prec, a / b))))
+    }
+
+    pub fn log10(&self) -> Self {
+        Complex(self.0.map(
+            |z| z.ln().div(Cx::new(10f32.ln(), 0.0)),
+            |z| z.ln().div(Cx::new(10f64.ln(), 0.0)),
+            |z| z.log10(),
+        ))
+    }
+
+    /// Principal square root
+    pub fn sqrt(&self) -> Self {
+        Complex(self.0.map(Cx::sqrt, Cx::sqrt, |z| z.sqrt()))
+    }
+}
+
+pub fn register_complex(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Complex>()?;
+    Ok(())
+}
@@ -0,0 +1,508 @@
+use std::cmp::Ordering;
+
+use pyo3::class::basic::CompareOp;
+use pyo3::exceptions::{PyArithmeticError, PyOverflowError, PyTypeError, PyValueError, PyZeroDivisionError};
+use pyo3::prelude::*;
+use pyo3::sync::GILOnceCell;
+use pyo3::types::{PyFloat, PyString, PyType};
+use pyo3::IntoPyObjectExt;
+use rug::ops::Pow;
+use rug::{Integer, Rational as MpRational};
+
+use super::int::{integer_to_py, py_any_to_integer, rational_hash};
+use super::rational::rational_to_f64;
+
+/// Largest supported number of digits after the decimal point
+const MAX_SCALE: u32 = 38;
+
+static PY_DECIMAL: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+
+/// How to round a result that cannot be represented exactly at the target scale
+#[pyclass(name="Rounding", eq, eq_int)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum DecimalRounding {
+    HalfEven,
+    HalfUp,
+    HalfDown,
+    Down,
+    Up,
+    Ceiling,
+    Floor,
+}
+
+/// Accept a `Rounding` member or one of the `decimal.ROUND_*` string constants
+pub(crate) fn py_any_to_rounding(obj: &Bound<'_, PyAny>) -> PyResult<Option<DecimalRounding>> {
+    if obj.is_none() {
+        return Ok(None);
+    }
+    if let Ok(r) = obj.extract::<DecimalRounding>() {
+        return Ok(Some(r));
+    }
+    let name = obj.extract::<String>()?;
+    let rounding = match name.as_str() {
+        "ROUND_HALF_EVEN" => DecimalRounding::HalfEven,
+        "ROUND_HALF_UP" => DecimalRounding::HalfUp,
+        "ROUND_HALF_DOWN" => DecimalRounding::HalfDown,
+        "ROUND_DOWN" => DecimalRounding::Down,
+        "ROUND_UP" => DecimalRounding::Up,
+        "ROUND_CEILING" => DecimalRounding::Ceiling,
+        "ROUND_FLOOR" => DecimalRounding::Floor,
+        _ => return Err(PyValueError::new_err(format!("Unknown rounding mode: {}", name))),
+    };
+    Ok(Some(rounding))
+}
+
+fn pow10(exp: u32) -> Integer {
+    Integer::from(10).pow(exp)
+}
+
+fn check_scale(scale: u32) -> PyResult<u32> {
+    if scale > MAX_SCALE {
+        return Err(PyOverflowError::new_err(format!("Scale must be at most {}", MAX_SCALE)));
+    }
+    Ok(scale)
+}
+
+fn to_mantissa(value: Integer) -> PyResult<i128> {
+    value
+        .to_i128()
+        .ok_or_else(|| PyOverflowError::new_err("Decimal mantissa overflow"))
+}
+
+fn inexact() -> PyErr {
+    PyArithmeticError::new_err("Inexact result; pass a rounding mode to round it")
+}
+
+/// Divide `n` by `d`, rounding according to `rounding`, or fail if inexact and
+/// no rounding mode was given
+pub(crate) fn div_round(n: Integer, d: &Integer, rounding: Option<DecimalRounding>) -> PyResult<Integer> {
+    let (q, r) = n.div_rem(d.clone());
+    if r == 0 {
+        return Ok(q);
+    }
+    let Some(rounding) = rounding else {
+        return Err(inexact());
+    };
+
+    let negative = (r < 0) != (*d < 0);
+    let half = (Integer::from(r.abs_ref()) * 2u32).cmp_abs(d);
+    let away = match rounding {
+        DecimalRounding::Down => false,
+        DecimalRounding::Up => true,
+        DecimalRounding::Ceiling => !negative,
+        DecimalRounding::Floor => negative,
+        DecimalRounding::HalfUp => half != Ordering::Less,
+        DecimalRounding::HalfDown => half == Ordering::Greater,
+        DecimalRounding::HalfEven => half == Ordering::Greater || (half == Ordering::Equal && q.is_odd()),
+    };
+    Ok(match (away, negative) {
+        (false, _) => q,
+        (true, false) => q + 1,
+        (true, true) => q - 1,
+    })
+}
+
+/// Parse `"-1234.50"`, `"1e3"` or `"2.5E-4"` into a mantissa and scale
+fn parse_decimal(s: &str) -> PyResult<(i128, u32)> {
+    let s = s.trim().replace('_', "");
+    let invalid = || PyValueError::new_err(format!("Invalid literal for Decimal: {:?}", s));
+
+    let (mantissa, exponent) = match s.find(['e', 'E']) {
+        Some(pos) => (&s[..pos], s[pos + 1..].parse::<i64>().map_err(|_| invalid())?),
+        None => (&s[..], 0),
+    };
+    let (int_part, frac_part) = match mantissa.find('.') {
+        Some(pos) => (&mantissa[..pos], &mantissa[pos + 1..]),
+        None => (mantissa, ""),
+    };
+    let unsigned = int_part.trim_start_matches(['+', '-']);
+    if unsigned.len() + 1 < int_part.len()
+        || (unsigned.is_empty() && frac_part.is_empty())
+        || !unsigned.chars().chain(frac_part.chars()).all(|c| c.is_ascii_digit())
+    {
+        return Err(invalid());
+    }
+
+    let digits = Integer::from_str_radix(&format!("{}{}", unsigned, frac_part), 10).map_err(|_| invalid())?;
+    let digits = if int_part.starts_with('-') { -digits } else { digits };
+    let scale = frac_part.len() as i64 - exponent;
+    if scale < 0 {
+        if digits == 0 {
+            return Ok((0, 0));
+        }
+        let shift = u32::try_from(-scale)
+            .ok()
+            .filter(|&shift| shift <= MAX_SCALE)
+            .ok_or_else(|| PyOverflowError::new_err("Decimal exponent too large"))?;
+        return Ok((to_mantissa(digits * pow10(shift))?, 0));
+    }
+    let scale = u32::try_from(scale).map_err(|_| PyOverflowError::new_err("Decimal scale too large"))?;
+    Ok((to_mantissa(digits)?, check_scale(scale)?))
+}
+
+/// Extract a Rustique `Decimal` from itself, an integer type or a Python
+/// `decimal.Decimal`. Strings are only parsed by the constructor, so that
+/// operators and comparisons do not treat `"1"` as a number
+pub(crate) fn py_any_to_decimal(obj: &Bound<'_, PyAny>) -> PyResult<Decimal> {
+    if let Ok(d) = obj.downcast::<Decimal>() {
+        return Ok(d.borrow().clone());
+    }
+
+    if let Ok(i) = py_any_to_integer(obj) {
+        return Ok(Decimal { mantissa: to_mantissa(i)?, scale: 0 });
+    }
+
+    let py_decimal = PY_DECIMAL.import(obj.py(), "decimal", "Decimal")?;
+    if obj.is_instance(py_decimal)? {
+        if !obj.call_method0("is_finite")?.is_truthy()? {
+            return Err(PyValueError::new_err("Cannot convert non-finite decimal.Decimal to Decimal"));
+        }
+        let (mantissa, scale) = parse_decimal(obj.str()?.to_str()?)?;
+        return Ok(Decimal { mantissa, scale });
+    }
+
+    Err(PyTypeError::new_err(format!(
+        "Could not convert {} to Decimal",
+        obj.get_type().name()?
+    )))
+}
+
+/// Fixed-scale decimal number: an `i128` mantissa and a count of fractional digits
+#[pyclass(name="Decimal")]
+#[derive(Clone)]
+pub struct Decimal {
+    pub(crate) mantissa: i128,
+    pub(crate) scale: u32,
+}
+
+impl Decimal {
+    /// Mantissa rescaled to `scale`, which must not be below the current scale
+    fn widened(&self, scale: u32) -> Integer {
+        Integer::from(self.mantissa) * pow10(scale - self.scale)
+    }
+
+    /// Re-express this value at `scale`, rounding if digits are dropped
+    fn rescale(&self, scale: u32, rounding: Option<DecimalRounding>) -> PyResult<Decimal> {
+        let scale = check_scale(scale)?;
+        let mantissa = if scale >= self.scale {
+            self.widened(scale)
+        } else {
+            div_round(Integer::from(self.mantissa), &pow10(self.scale - scale), rounding)?
+        };
+        Ok(Decimal { mantissa: to_mantissa(mantissa)?, scale })
+    }
+
+    fn to_rational(&self) -> MpRational {
+        MpRational::from((Integer::from(self.mantissa), pow10(self.scale)))
+    }
+
+    /// Both mantissas aligned to the larger scale
+    fn align(&self, other: &Decimal) -> (Integer, Integer, u32) {
+        let scale = self.scale.max(other.scale);
+        (self.widened(scale), other.widened(scale), scale)
+    }
+
+    fn checked_add(&self, other: &Decimal) -> PyResult<Decimal> {
+        if self.scale == other.scale {
+            if let Some(mantissa) = self.mantissa.checked_add(other.mantissa) {
+                return Ok(Decimal { mantissa, scale: self.scale });
+            }
+        }
+        let (a, b, scale) = self.align(other);
+        Ok(Decimal { mantissa: to_mantissa(a + b)?, scale })
+    }
+
+    fn format_plain(&self, separator: Option<char>) -> String {
+        let digits = self.mantissa.unsigned_abs().to_string();
+        let scale = self.scale as usize;
+        let digits = if digits.len() <= scale {
+            format!("{}{}", "0".repeat(scale - digits.len() + 1), digits)
+        } else {
+            digits
+        };
+        let (int_part, frac_part) = digits.split_at(digits.len() - scale);
+
+        let int_part = match separator {
+            Some(sep) => {
+                let mut grouped = String::new();
+                for (i, c) in int_part.chars().enumerate() {
+                    if i > 0 && (int_part.len() - i) % 3 == 0 {
+                        grouped.push(sep);
+                    }
+                    grouped.push(c);
+                }
+                grouped
+            }
+            None => int_part.to_string(),
+        };
+
+        let sign = if self.mantissa < 0 { "-" } else { "" };
+        if frac_part.is_empty() {
+            format!("{}{}", sign, int_part)
+        } else {
+            format!("{}{}.{}", sign, int_part, frac_part)
+        }
+    }
+}
+
+#[pymethods]
+impl Decimal {
+    #[new]
+    #[pyo3(signature = (value=None, scale=None, rounding=None))]
+    pub fn new(
+        value: Option<&Bound<'_, PyAny>>,
+        scale: Option<u32>,
+        #[pyo3(from_py_with = "py_any_to_rounding")] rounding: Option<DecimalRounding>,
+    ) -> PyResult<Self> {
+        let decimal = match value {
+            None => Decimal { mantissa: 0, scale: 0 },
+            // Floats go through their shortest repr, so Decimal(0.1) is 0.1
+            Some(v) if v.downcast::<PyFloat>().is_ok() => {
+                let (mantissa, scale) = parse_decimal(v.repr()?.to_str()?)?;
+                Decimal { mantissa, scale }
+            }
+            Some(v) if v.downcast::<PyString>().is_ok() => {
+                let (mantissa, scale) = parse_decimal(v.str()?.to_str()?)?;
+                Decimal { mantissa, scale }
+            }
+            Some(v) => py_any_to_decimal(v)?,
+        };
+        match scale {
+            Some(scale) => decimal.rescale(scale, rounding),
+            None => Ok(decimal),
+        }
+    }
+
+    /// Parse a string that may contain a grouping separator, such as `"1,234.50"`
+    #[staticmethod]
+    #[pyo3(signature = (s, separator=","))]
+    pub fn parse(s: &str, separator: &str) -> PyResult<Self> {
+        let (mantissa, scale) = parse_decimal(&s.replace(separator, ""))?;
+        Ok(Decimal { mantissa, scale })
+    }
+
+    #[getter]
+    pub fn mantissa(&self) -> i128 {
+        self.mantissa
+    }
+
+    #[getter]
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!("Decimal('{}')", self.format_plain(None))
+    }
+
+    pub fn __str__(&self) -> String {
+        self.format_plain(None)
+    }
+
+    /// Supports an optional grouping character (`,` or `_`) and a `.N` scale,
+    /// optionally followed by `f`, e.g. `format(d, ",.2f")`
+    pub fn __format__(&self, spec: &str) -> PyResult<String> {
+        let invalid = || PyValueError::new_err(format!("Invalid format specifier for Decimal: {:?}", spec));
+        let spec = spec.strip_suffix('f').unwrap_or(spec);
+        let (separator, rest) = match spec.chars().next() {
+            Some(c @ (',' | '_')) => (Some(c), &spec[1..]),
+            _ => (None, spec),
+        };
+        let value = match rest {
+            "" => self.clone(),
+            r => {
+                let scale = r.strip_prefix('.').ok_or_else(invalid)?.parse::<u32>().map_err(|_| invalid())?;
+                self.rescale(scale, Some(DecimalRounding::HalfEven))?
+            }
+        };
+        Ok(value.format_plain(separator))
+    }
+
+    /// Python-compatible hash, equal to the hash of the same `decimal.Decimal`
+    pub fn __hash__(&self) -> isize {
+        rational_hash(&self.to_rational())
+    }
+
+    pub fn __bool__(&self) -> bool {
+        self.mantissa != 0
+    }
+
+    pub fn __float__(&self) -> f64 {
+        rational_to_f64(&self.to_rational())
+    }
+
+    pub fn __int__(&self, py: Python) -> PyResult<PyObject> {
+        integer_to_py(py, &(Integer::from(self.mantissa) / pow10(self.scale)))
+    }
+
+    pub fn to_decimal(&self, py: Python) -> PyResult<PyObject> {
+        let py_decimal = PY_DECIMAL.import(py, "decimal", "Decimal")?;
+        Ok(py_decimal.call1((self.format_plain(None),))?.unbind())
+    }
+
+    /// Round to `scale` fractional digits; without a rounding mode, dropping
+    /// non-zero digits raises
+    #[pyo3(signature = (scale, rounding=None))]
+    pub fn quantize(
+        &self,
+        scale: &Bound<'_, PyAny>,
+        #[pyo3(from_py_with = "py_any_to_rounding")] rounding: Option<DecimalRounding>,
+    ) -> PyResult<Self> {
+        let scale = match scale.downcast::<Decimal>() {
+            Ok(d) => d.borrow().scale,
+            Err(_) => scale.extract::<u32>()?,
+        };
+        self.rescale(scale, rounding)
+    }
+
+    #[pyo3(signature = (ndigits=None))]
+    pub fn __round__(&self, py: Python, ndigits: Option<u32>) -> PyResult<PyObject> {
+        match ndigits {
+            None => self.rescale(0, Some(DecimalRounding::HalfEven))?.__int__(py),
+            Some(n) => self.rescale(n, Some(DecimalRounding::HalfEven))?.into_py_any(py),
+        }
+    }
+
+    pub fn __richcmp__(&self, py: Python, other: &Bound<'_, PyAny>, op: CompareOp) -> PyResult<PyObject> {
+        let ordering = if let Ok(f) = other.downcast::<PyFloat>() {
+            let f = f.value();
+            if f.is_nan() {
+                None
+            } else if f.is_infinite() {
+                Some(if f > 0.0 { Ordering::Less } else { Ordering::Greater })
+            } else {
+                MpRational::from_f64(f).map(|f| self.to_rational().cmp(&f))
+            }
+        } else if let Ok(other) = py_any_to_decimal(other) {
+            let (a, b, _) = self.align(&other);
+            Some(a.cmp(&b))
+        } else {
+            return Ok(py.NotImplemented());
+        };
+
+        let result = match ordering {
+            Some(ordering) => op.matches(ordering),
+            None => matches!(op, CompareOp::Ne),
+        };
+        result.into_py_any(py)
+    }
+
+    pub fn __add__(&self, #[pyo3(from_py_with = "py_any_to_decimal")] other: Decimal) -> PyResult<Self> {
+        self.checked_add(&other)
+    }
+
+    pub fn __radd__(&self, #[pyo3(from_py_with = "py_any_to_decimal")] other: Decimal) -> PyResult<Self> {
+        other.checked_add(self)
+    }
+
+    pub fn __sub__(&self, #[pyo3(from_py_with = "py_any_to_decimal")] other: Decimal) -> PyResult<Self> {
+        self.checked_add(&other.__neg__()?)
+    }
+
+    pub fn __rsub__(&self, #[pyo3(from_py_with = "py_any_to_decimal")] other: Decimal) -> PyResult<Self> {
+        other.checked_add(&self.__neg__()?)
+    }
+
+    /// Exact product; its scale is the sum of both scales
+    pub fn __mul__(&self, #[pyo3(from_py_with = "py_any_to_decimal")] other: Decimal) -> PyResult<Self> {
+        self.mul(other, None, None)
+    }
+
+    pub fn __rmul__(&self, #[pyo3(from_py_with = "py_any_to_decimal")] other: Decimal) -> PyResult<Self> {
+        other.mul(self.clone(), None, None)
+    }
+
+    /// Exact quotient, raising if it does not terminate within the maximum scale
+    pub fn __truediv__(&self, #[pyo3(from_py_with = "py_any_to_decimal")] other: Decimal) -> PyResult<Self> {
+        self.div(other, None, None)
+    }
+
+    pub fn __rtruediv__(&self, #[pyo3(from_py_with = "py_any_to_decimal")] other: Decimal) -> PyResult<Self> {
+        other.div(self.clone(), None, None)
+    }
+
+    pub fn __neg__(&self) -> PyResult<Self> {
+        let mantissa = self
+            .mantissa
+            .checked_neg()
+            .ok_or_else(|| PyOverflowError::new_err("Decimal mantissa overflow"))?;
+        Ok(Decimal { mantissa, scale: self.scale })
+    }
+
+    pub fn __pos__(&self) -> Self {
+        self.clone()
+    }
+
+    pub fn __abs__(&self) -> PyResult<Self> {
+        if self.mantissa < 0 {
+            self.__neg__()
+        } else {
+            Ok(self.clone())
+        }
+    }
+
+    /// Product rounded to `scale`; defaults to the exact product
+    #[pyo3(signature = (other, scale=None, rounding=None))]
+    pub fn mul(
+        &self,
+        #[pyo3(from_py_with = "py_any_to_decimal")] other: Decimal,
+        scale: Option<u32>,
+        #[pyo3(from_py_with = "py_any_to_rounding")] rounding: Option<DecimalRounding>,
+    ) -> PyResult<Self> {
+        let exact_scale = self.scale + other.scale;
+        let target = check_scale(scale.unwrap_or(exact_scale))?;
+        let product = Integer::from(self.mantissa) * other.mantissa;
+        let mantissa = if target >= exact_scale {
+            product * pow10(target - exact_scale)
+        } else {
+            div_round(product, &pow10(exact_scale - target), rounding)?
+        };
+        Ok(Decimal { mantissa: to_mantissa(mantissa)?, scale: target })
+    }
+
+    /// Quotient at `scale`. Without a scale the result is exact, using at
+    /// least this value's scale; with a rounding mode but no scale, the larger
+    /// of both operands' scales is used
+    #[pyo3(signature = (other, scale=None, rounding=None))]
+    pub fn div(
+        &self,
+        #[pyo3(from_py_with = "py_any_to_decimal")] other: Decimal,
+        scale: Option<u32>,
+        #[pyo3(from_py_with = "py_any_to_rounding")] rounding: Option<DecimalRounding>,
+    ) -> PyResult<Self> {
+        if other.mantissa == 0 {
+            return Err(PyZeroDivisionError::new_err("Division by zero"));
+        }
+
+        let target = match (scale, rounding) {
+            (Some(scale), _) => check_scale(scale)?,
+            (None, Some(_)) => self.scale.max(other.scale),
+            (None, None) => {
+                // A quotient terminates iff its reduced denominator is 2**x * 5**y
+                let quotient = self.to_rational() / other.to_rational();
+                let mut denom = quotient.denom().clone();
+                let twos = denom.remove_factor_mut(&Integer::from(2));
+                let fives = denom.remove_factor_mut(&Integer::from(5));
+                if denom != 1 || twos.max(fives) > MAX_SCALE {
+                    return Err(inexact());
+                }
+                twos.max(fives).max(self.scale)
+            }
+        };
+
+        // self / other at `target` == self.m * 10**(target + other.scale - self.scale) / other.m
+        let exponent = target as i64 + other.scale as i64 - self.scale as i64;
+        let (numer, denom) = if exponent >= 0 {
+            (Integer::from(self.mantissa) * pow10(exponent as u32), Integer::from(other.mantissa))
+        } else {
+            (Integer::from(self.mantissa), Integer::from(other.mantissa) * pow10((-exponent) as u32))
+        };
+        Ok(Decimal { mantissa: to_mantissa(div_round(numer, &denom, rounding)?)?, scale: target })
+    }
+}
+
+pub fn register_decimal(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<DecimalRounding>()?;
+    m.add_class::<Decimal>()?;
+    Ok(())
+}
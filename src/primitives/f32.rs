@@ -2,7 +2,7 @@ use pyo3::prelude::*;
 
 #[pyclass(name="f32")]
 pub struct F32 {
-    value: f32,
+    pub(crate) value: f32,
 }
 
 #[pymethods]
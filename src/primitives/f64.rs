@@ -3,7 +3,7 @@ use pyo3::prelude::*;
 /// A simple Rust-backed f64 type exposed to Python
 #[pyclass(name="f64")]
 pub struct F64 {
-    value: f64,
+    pub(crate) value: f64,
 }
 
 #[pymethods]
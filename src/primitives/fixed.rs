@@ -0,0 +1,652 @@
+use std::cmp::Ordering;
+
+use pyo3::class::basic::CompareOp;
+use pyo3::exceptions::{PyOverflowError, PyTypeError, PyValueError, PyZeroDivisionError};
+use pyo3::prelude::*;
+use pyo3::types::PyFloat;
+use pyo3::{IntoPyObjectExt, PyClassInitializer};
+use rug::ops::Pow;
+use rug::{Integer, Rational as MpRational};
+
+use super::decimal::{div_round, py_any_to_rounding, DecimalRounding};
+use super::int::{integer_to_py, py_any_to_integer, rational_hash};
+use super::rational::{py_any_to_rational, rational_to_f64};
+
+/// Bit layout of a fixed-point number: `int_bits` integer bits and `frac_bits`
+/// fractional bits, plus a sign bit when `signed`
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct FixedFormat {
+    pub int_bits: u32,
+    pub frac_bits: u32,
+    pub signed: bool,
+}
+
+impl FixedFormat {
+    const Q15: FixedFormat = FixedFormat { int_bits: 0, frac_bits: 15, signed: true };
+    const Q31: FixedFormat = FixedFormat { int_bits: 0, frac_bits: 31, signed: true };
+    const Q7_8: FixedFormat = FixedFormat { int_bits: 7, frac_bits: 8, signed: true };
+
+    /// Only layouts that fill one of the integer widths are allowed, so the
+    /// raw bits are exactly those of the corresponding Rust integer
+    pub fn new(int_bits: u32, frac_bits: u32, signed: bool) -> PyResult<Self> {
+        let width = int_bits.saturating_add(frac_bits).saturating_add(signed as u32);
+        if !matches!(width, 8 | 16 | 32 | 64) {
+            return Err(PyValueError::new_err(format!(
+                "Fixed-point width must be 8, 16, 32 or 64 bits, got {}",
+                width
+            )));
+        }
+        Ok(FixedFormat { int_bits, frac_bits, signed })
+    }
+
+    pub fn width(&self) -> u32 {
+        self.int_bits + self.frac_bits + self.signed as u32
+    }
+
+    fn min_bits(&self) -> i128 {
+        if self.signed {
+            -(1 << (self.width() - 1))
+        } else {
+            0
+        }
+    }
+
+    fn max_bits(&self) -> i128 {
+        if self.signed {
+            (1 << (self.width() - 1)) - 1
+        } else {
+            (1 << self.width()) - 1
+        }
+    }
+
+    fn fits(&self, bits: &Integer) -> bool {
+        *bits >= self.min_bits() && *bits <= self.max_bits()
+    }
+
+    /// Keep the low `width` bits, sign-extending for signed formats
+    fn wrap(&self, bits: &Integer) -> i128 {
+        let low = Integer::from(bits.keep_bits_ref(self.width())).to_i128().unwrap();
+        if self.signed && low > self.max_bits() {
+            low - (1 << self.width())
+        } else {
+            low
+        }
+    }
+
+    fn saturate(&self, bits: &Integer) -> i128 {
+        if *bits < self.min_bits() {
+            self.min_bits()
+        } else if *bits > self.max_bits() {
+            self.max_bits()
+        } else {
+            bits.to_i128().unwrap()
+        }
+    }
+
+    fn scale(&self) -> Integer {
+        Integer::from(1) << self.frac_bits
+    }
+}
+
+/// How an out-of-range result is brought back into the format
+#[derive(Clone, Copy)]
+enum Overflow {
+    Raise,
+    Wrap,
+    Saturate,
+}
+
+/// Right-hand operand of a binary operation
+enum Operand {
+    /// Raw bits of a `Fixed` in the same format
+    Fixed(i128),
+    /// Plain integer; scales the raw bits in `*` and `/`
+    Int(Integer),
+}
+
+/// Exact decimal expansion of `bits / 2**frac_bits`
+fn exact_decimal(bits: i128, frac_bits: u32) -> String {
+    let digits = (Integer::from(bits) * Integer::from(5).pow(frac_bits)).abs().to_string();
+    let frac_bits = frac_bits as usize;
+    let digits = format!("{:0>width$}", digits, width = frac_bits + 1);
+    let (int_part, frac_part) = digits.split_at(digits.len() - frac_bits);
+    let frac_part = frac_part.trim_end_matches('0');
+    let sign = if bits < 0 { "-" } else { "" };
+    format!("{}{}.{}", sign, int_part, if frac_part.is_empty() { "0" } else { frac_part })
+}
+
+fn rounding_or(rounding: Option<&Bound<'_, PyAny>>, default: DecimalRounding) -> PyResult<DecimalRounding> {
+    Ok(match rounding {
+        Some(rounding) => py_any_to_rounding(rounding)?.unwrap_or(default),
+        None => default,
+    })
+}
+
+/// Fixed-point number stored as the raw bits of an 8, 16, 32 or 64-bit integer.
+///
+/// Operators raise `OverflowError` on overflow; the `wrapping_`,
+/// `saturating_`, `checked_` and `overflowing_` methods mirror Rust. Products
+/// round towards negative infinity (an arithmetic shift) and quotients towards
+/// zero (integer division) unless another `Rounding` is given.
+#[pyclass(name="Fixed", subclass, frozen)]
+#[derive(Clone)]
+pub struct Fixed {
+    pub(crate) bits: i128,
+    pub(crate) format: FixedFormat,
+}
+
+impl Fixed {
+    pub(crate) fn to_rational(&self) -> MpRational {
+        MpRational::from((Integer::from(self.bits), self.format.scale()))
+    }
+
+    fn from_rational(value: &MpRational, format: FixedFormat, rounding: DecimalRounding, saturate: bool) -> PyResult<Self> {
+        let scaled = Integer::from(value.numer() << format.frac_bits);
+        let bits = div_round(scaled, value.denom(), Some(rounding))?;
+        let bits = if format.fits(&bits) {
+            bits.to_i128().unwrap()
+        } else if saturate {
+            format.saturate(&bits)
+        } else {
+            return Err(PyOverflowError::new_err(format!(
+                "{} is out of range for the fixed-point format",
+                rational_to_f64(value)
+            )));
+        };
+        Ok(Fixed { bits, format })
+    }
+
+    /// Convert a float exactly, or anything `Rational` accepts, into `format`
+    fn from_py(value: &Bound<'_, PyAny>, format: FixedFormat, rounding: DecimalRounding, saturate: bool) -> PyResult<Self> {
+        if let Ok(fixed) = value.downcast::<Fixed>() {
+            return Fixed::from_rational(&fixed.get().to_rational(), format, rounding, saturate);
+        }
+        let value = if let Ok(f) = value.downcast::<PyFloat>() {
+            let f = f.value();
+            match MpRational::from_f64(f) {
+                Some(value) => value,
+                None if saturate && !f.is_nan() => {
+                    let bits = if f > 0.0 { format.max_bits() } else { format.min_bits() };
+                    return Ok(Fixed { bits, format });
+                }
+                None => return Err(PyValueError::new_err(format!("Cannot convert {} to fixed-point", f))),
+            }
+        } else {
+            py_any_to_rational(value)?
+        };
+        Fixed::from_rational(&value, format, rounding, saturate)
+    }
+
+    fn from_bits_checked(bits: &Integer, format: FixedFormat) -> PyResult<Self> {
+        // Accept the raw pattern in either its signed or unsigned reading
+        let unsigned_max = Integer::from(1) << format.width();
+        if *bits < format.min_bits() || *bits >= unsigned_max {
+            return Err(PyOverflowError::new_err(format!(
+                "{} does not fit in {} bits",
+                bits,
+                format.width()
+            )));
+        }
+        Ok(Fixed { bits: format.wrap(bits), format })
+    }
+
+    /// `None` for types that are not numbers this class can combine with
+    fn operand(&self, other: &Bound<'_, PyAny>) -> PyResult<Option<Operand>> {
+        if let Ok(other) = other.downcast::<Fixed>() {
+            let other = other.get();
+            if other.format != self.format {
+                return Err(PyValueError::new_err(
+                    "Cannot mix fixed-point formats; convert one operand first",
+                ));
+            }
+            return Ok(Some(Operand::Fixed(other.bits)));
+        }
+        Ok(py_any_to_integer(other).ok().map(Operand::Int))
+    }
+
+    fn require(&self, other: &Bound<'_, PyAny>) -> PyResult<Operand> {
+        self.operand(other)?.ok_or_else(|| {
+            PyTypeError::new_err("Fixed-point arithmetic requires a Fixed of the same format or an int")
+        })
+    }
+
+    fn exact_add(&self, other: Operand) -> Integer {
+        match other {
+            Operand::Fixed(bits) => Integer::from(self.bits) + bits,
+            Operand::Int(n) => Integer::from(self.bits) + (n << self.format.frac_bits),
+        }
+    }
+
+    fn exact_sub(&self, other: Operand) -> Integer {
+        match other {
+            Operand::Fixed(bits) => Integer::from(self.bits) - bits,
+            Operand::Int(n) => Integer::from(self.bits) - (n << self.format.frac_bits),
+        }
+    }
+
+    fn exact_mul(&self, other: Operand, rounding: DecimalRounding) -> PyResult<Integer> {
+        match other {
+            Operand::Fixed(bits) => div_round(Integer::from(self.bits) * bits, &self.format.scale(), Some(rounding)),
+            Operand::Int(n) => Ok(Integer::from(self.bits) * n),
+        }
+    }
+
+    fn exact_div(&self, other: Operand, rounding: DecimalRounding) -> PyResult<Integer> {
+        let (numerator, divisor) = match other {
+            Operand::Fixed(bits) => (Integer::from(self.bits) << self.format.frac_bits, Integer::from(bits)),
+            Operand::Int(n) => (Integer::from(self.bits), n),
+        };
+        if divisor == 0 {
+            return Err(PyZeroDivisionError::new_err("Division by zero"));
+        }
+        div_round(numerator, &divisor, Some(rounding))
+    }
+
+    /// Build a value of the same Python class as `slf`, so that `Q15`
+    /// arithmetic keeps producing `Q15`
+    fn like<'py>(slf: &Bound<'py, Self>, bits: i128) -> PyResult<Bound<'py, Fixed>> {
+        let py = slf.py();
+        let init = PyClassInitializer::from(Fixed { bits, format: slf.get().format });
+        let obj = if slf.is_instance_of::<Q15>() {
+            Bound::new(py, init.add_subclass(Q15))?.into_any()
+        } else if slf.is_instance_of::<Q31>() {
+            Bound::new(py, init.add_subclass(Q31))?.into_any()
+        } else if slf.is_instance_of::<Q7_8>() {
+            Bound::new(py, init.add_subclass(Q7_8))?.into_any()
+        } else {
+            return Bound::new(py, init);
+        };
+        Ok(obj.downcast_into::<Fixed>()?)
+    }
+
+    fn finish<'py>(slf: &Bound<'py, Self>, exact: Integer, overflow: Overflow, operation: &str) -> PyResult<Bound<'py, Fixed>> {
+        let format = slf.get().format;
+        let bits = match overflow {
+            _ if format.fits(&exact) => exact.to_i128().unwrap(),
+            Overflow::Raise => {
+                return Err(PyOverflowError::new_err(format!("Overflow occurred during {}", operation)));
+            }
+            Overflow::Wrap => format.wrap(&exact),
+            Overflow::Saturate => format.saturate(&exact),
+        };
+        Fixed::like(slf, bits)
+    }
+
+    fn checked<'py>(slf: &Bound<'py, Self>, exact: Integer) -> PyResult<Option<Bound<'py, Fixed>>> {
+        if !slf.get().format.fits(&exact) {
+            return Ok(None);
+        }
+        Ok(Some(Fixed::like(slf, exact.to_i128().unwrap())?))
+    }
+
+    fn overflowing<'py>(slf: &Bound<'py, Self>, exact: Integer) -> PyResult<(Bound<'py, Fixed>, bool)> {
+        let format = slf.get().format;
+        Ok((Fixed::like(slf, format.wrap(&exact))?, !format.fits(&exact)))
+    }
+}
+
+#[pymethods]
+impl Fixed {
+    /// Convert `value` (float, int, `Fixed` or any rational) to the nearest
+    /// representable value, ties to even unless `rounding` says otherwise
+    #[new]
+    #[pyo3(signature = (value, int_bits, frac_bits, signed=true, rounding=None, saturate=false))]
+    pub fn new(
+        value: &Bound<'_, PyAny>,
+        int_bits: u32,
+        frac_bits: u32,
+        signed: bool,
+        rounding: Option<&Bound<'_, PyAny>>,
+        saturate: bool,
+    ) -> PyResult<Self> {
+        let format = FixedFormat::new(int_bits, frac_bits, signed)?;
+        Fixed::from_py(value, format, rounding_or(rounding, DecimalRounding::HalfEven)?, saturate)
+    }
+
+    /// Reinterpret raw integer bits, given in either signed or unsigned form
+    #[staticmethod]
+    #[pyo3(signature = (bits, int_bits, frac_bits, signed=true))]
+    pub fn from_bits(
+        #[pyo3(from_py_with = "py_any_to_integer")] bits: Integer,
+        int_bits: u32,
+        frac_bits: u32,
+        signed: bool,
+    ) -> PyResult<Self> {
+        Fixed::from_bits_checked(&bits, FixedFormat::new(int_bits, frac_bits, signed)?)
+    }
+
+    pub fn to_bits(&self) -> i128 {
+        self.bits
+    }
+
+    /// Convert into another layout, rounding ties to even by default
+    #[pyo3(signature = (int_bits, frac_bits, signed=true, rounding=None, saturate=false))]
+    pub fn convert(
+        &self,
+        int_bits: u32,
+        frac_bits: u32,
+        signed: bool,
+        rounding: Option<&Bound<'_, PyAny>>,
+        saturate: bool,
+    ) -> PyResult<Self> {
+        let format = FixedFormat::new(int_bits, frac_bits, signed)?;
+        let rounding = rounding_or(rounding, DecimalRounding::HalfEven)?;
+        Fixed::from_rational(&self.to_rational(), format, rounding, saturate)
+    }
+
+    #[getter]
+    pub fn int_bits(&self) -> u32 {
+        self.format.int_bits
+    }
+
+    #[getter]
+    pub fn frac_bits(&self) -> u32 {
+        self.format.frac_bits
+    }
+
+    #[getter]
+    pub fn signed(&self) -> bool {
+        self.format.signed
+    }
+
+    #[getter]
+    pub fn width(&self) -> u32 {
+        self.format.width()
+    }
+
+    pub fn __repr__(slf: &Bound<'_, Self>) -> PyResult<String> {
+        let this = slf.get();
+        let value = exact_decimal(this.bits, this.format.frac_bits);
+        if slf.is_exact_instance_of::<Fixed>() {
+            return Ok(format!(
+                "Fixed({}, int_bits={}, frac_bits={}, signed={})",
+                value,
+                this.format.int_bits,
+                this.format.frac_bits,
+                if this.format.signed { "True" } else { "False" }
+            ));
+        }
+        Ok(format!("{}({})", slf.get_type().name()?, value))
+    }
+
+    pub fn __str__(&self) -> String {
+        exact_decimal(self.bits, self.format.frac_bits)
+    }
+
+    pub fn __hash__(&self) -> isize {
+        rational_hash(&self.to_rational())
+    }
+
+    pub fn __bool__(&self) -> bool {
+        self.bits != 0
+    }
+
+    pub fn __float__(&self) -> f64 {
+        rational_to_f64(&self.to_rational())
+    }
+
+    pub fn to_float(&self) -> f64 {
+        self.__float__()
+    }
+
+    pub fn __int__(&self, py: Python) -> PyResult<PyObject> {
+        integer_to_py(py, &self.to_rational().trunc().into_numer_denom().0)
+    }
+
+    pub fn __richcmp__(&self, py: Python, other: &Bound<'_, PyAny>, op: CompareOp) -> PyResult<PyObject> {
+        let ordering = if let Ok(other) = other.downcast::<Fixed>() {
+            Some(self.to_rational().cmp(&other.get().to_rational()))
+        } else if let Ok(f) = other.downcast::<PyFloat>() {
+            let f = f.value();
+            if f.is_nan() {
+                None
+            } else if f.is_infinite() {
+                Some(if f > 0.0 { Ordering::Less } else { Ordering::Greater })
+            } else {
+                MpRational::from_f64(f).map(|f| self.to_rational().cmp(&f))
+            }
+        } else if let Ok(other) = py_any_to_rational(other) {
+            Some(self.to_rational().cmp(&other))
+        } else {
+            return Ok(py.NotImplemented());
+        };
+
+        let result = match ordering {
+            Some(ordering) => op.matches(ordering),
+            None => matches!(op, CompareOp::Ne),
+        };
+        result.into_py_any(py)
+    }
+
+    pub fn __add__(slf: &Bound<'_, Self>, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        let Some(other) = slf.get().operand(other)? else {
+            return Ok(slf.py().NotImplemented());
+        };
+        Ok(Fixed::finish(slf, slf.get().exact_add(other), Overflow::Raise, "addition")?.into_any().unbind())
+    }
+
+    pub fn __radd__(slf: &Bound<'_, Self>, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        Fixed::__add__(slf, other)
+    }
+
+    pub fn checked_add<'py>(slf: &Bound<'py, Self>, other: &Bound<'_, PyAny>) -> PyResult<Option<Bound<'py, Fixed>>> {
+        Fixed::checked(slf, slf.get().exact_add(slf.get().require(other)?))
+    }
+
+    pub fn wrapping_add<'py>(slf: &Bound<'py, Self>, other: &Bound<'_, PyAny>) -> PyResult<Bound<'py, Fixed>> {
+        Fixed::finish(slf, slf.get().exact_add(slf.get().require(other)?), Overflow::Wrap, "addition")
+    }
+
+    pub fn saturating_add<'py>(slf: &Bound<'py, Self>, other: &Bound<'_, PyAny>) -> PyResult<Bound<'py, Fixed>> {
+        Fixed::finish(slf, slf.get().exact_add(slf.get().require(other)?), Overflow::Saturate, "addition")
+    }
+
+    pub fn overflowing_add<'py>(slf: &Bound<'py, Self>, other: &Bound<'_, PyAny>) -> PyResult<(Bound<'py, Fixed>, bool)> {
+        Fixed::overflowing(slf, slf.get().exact_add(slf.get().require(other)?))
+    }
+
+    pub fn __sub__(slf: &Bound<'_, Self>, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        let Some(other) = slf.get().operand(other)? else {
+            return Ok(slf.py().NotImplemented());
+        };
+        Ok(Fixed::finish(slf, slf.get().exact_sub(other), Overflow::Raise, "subtraction")?.into_any().unbind())
+    }
+
+    pub fn __rsub__(slf: &Bound<'_, Self>, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        let Some(other) = slf.get().operand(other)? else {
+            return Ok(slf.py().NotImplemented());
+        };
+        let exact = -slf.get().exact_sub(other);
+        Ok(Fixed::finish(slf, exact, Overflow::Raise, "subtraction")?.into_any().unbind())
+    }
+
+    pub fn checked_sub<'py>(slf: &Bound<'py, Self>, other: &Bound<'_, PyAny>) -> PyResult<Option<Bound<'py, Fixed>>> {
+        Fixed::checked(slf, slf.get().exact_sub(slf.get().require(other)?))
+    }
+
+    pub fn wrapping_sub<'py>(slf: &Bound<'py, Self>, other: &Bound<'_, PyAny>) -> PyResult<Bound<'py, Fixed>> {
+        Fixed::finish(slf, slf.get().exact_sub(slf.get().require(other)?), Overflow::Wrap, "subtraction")
+    }
+
+    pub fn saturating_sub<'py>(slf: &Bound<'py, Self>, other: &Bound<'_, PyAny>) -> PyResult<Bound<'py, Fixed>> {
+        Fixed::finish(slf, slf.get().exact_sub(slf.get().require(other)?), Overflow::Saturate, "subtraction")
+    }
+
+    pub fn overflowing_sub<'py>(slf: &Bound<'py, Self>, other: &Bound<'_, PyAny>) -> PyResult<(Bound<'py, Fixed>, bool)> {
+        Fixed::overflowing(slf, slf.get().exact_sub(slf.get().require(other)?))
+    }
+
+    pub fn __mul__(slf: &Bound<'_, Self>, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        let Some(other) = slf.get().operand(other)? else {
+            return Ok(slf.py().NotImplemented());
+        };
+        let exact = slf.get().exact_mul(other, DecimalRounding::Floor)?;
+        Ok(Fixed::finish(slf, exact, Overflow::Raise, "multiplication")?.into_any().unbind())
+    }
+
+    pub fn __rmul__(slf: &Bound<'_, Self>, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        Fixed::__mul__(slf, other)
+    }
+
+    #[pyo3(signature = (other, rounding=None))]
+    pub fn mul<'py>(slf: &Bound<'py, Self>, other: &Bound<'_, PyAny>, rounding: Option<&Bound<'_, PyAny>>) -> PyResult<Bound<'py, Fixed>> {
+        let exact = slf.get().exact_mul(slf.get().require(other)?, rounding_or(rounding, DecimalRounding::Floor)?)?;
+        Fixed::finish(slf, exact, Overflow::Raise, "multiplication")
+    }
+
+    #[pyo3(signature = (other, rounding=None))]
+    pub fn checked_mul<'py>(slf: &Bound<'py, Self>, other: &Bound<'_, PyAny>, rounding: Option<&Bound<'_, PyAny>>) -> PyResult<Option<Bound<'py, Fixed>>> {
+        let exact = slf.get().exact_mul(slf.get().require(other)?, rounding_or(rounding, DecimalRounding::Floor)?)?;
+        Fixed::checked(slf, exact)
+    }
+
+    #[pyo3(signature = (other, rounding=None))]
+    pub fn wrapping_mul<'py>(slf: &Bound<'py, Self>, other: &Bound<'_, PyAny>, rounding: Option<&Bound<'_, PyAny>>) -> PyResult<Bound<'py, Fixed>> {
+        let exact = slf.get().exact_mul(slf.get().require(other)?, rounding_or(rounding, DecimalRounding::Floor)?)?;
+        Fixed::finish(slf, exact, Overflow::Wrap, "multiplication")
+    }
+
+    #[pyo3(signature = (other, rounding=None))]
+    pub fn saturating_mul<'py>(slf: &Bound<'py, Self>, other: &Bound<'_, PyAny>, rounding: Option<&Bound<'_, PyAny>>) -> PyResult<Bound<'py, Fixed>> {
+        let exact = slf.get().exact_mul(slf.get().require(other)?, rounding_or(rounding, DecimalRounding::Floor)?)?;
+        Fixed::finish(slf, exact, Overflow::Saturate, "multiplication")
+    }
+
+    #[pyo3(signature = (other, rounding=None))]
+    pub fn overflowing_mul<'py>(slf: &Bound<'py, Self>, other: &Bound<'_, PyAny>, rounding: Option<&Bound<'_, PyAny>>) -> PyResult<(Bound<'py, Fixed>, bool)> {
+        let exact = slf.get().exact_mul(slf.get().require(other)?, rounding_or(rounding, DecimalRounding::Floor)?)?;
+        Fixed::overflowing(slf, exact)
+    }
+
+    pub fn __truediv__(slf: &Bound<'_, Self>, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        let Some(other) = slf.get().operand(other)? else {
+            return Ok(slf.py().NotImplemented());
+        };
+        let exact = slf.get().exact_div(other, DecimalRounding::Down)?;
+        Ok(Fixed::finish(slf, exact, Overflow::Raise, "division")?.into_any().unbind())
+    }
+
+    pub fn __rtruediv__(slf: &Bound<'_, Self>, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        let Some(Operand::Int(n)) = slf.get().operand(other)? else {
+            return Ok(slf.py().NotImplemented());
+        };
+        let this = slf.get();
+        if this.bits == 0 {
+            return Err(PyZeroDivisionError::new_err("Division by zero"));
+        }
+        let exact = div_round(n << (2 * this.format.frac_bits), &Integer::from(this.bits), Some(DecimalRounding::Down))?;
+        Ok(Fixed::finish(slf, exact, Overflow::Raise, "division")?.into_any().unbind())
+    }
+
+    #[pyo3(signature = (other, rounding=None))]
+    pub fn div<'py>(slf: &Bound<'py, Self>, other: &Bound<'_, PyAny>, rounding: Option<&Bound<'_, PyAny>>) -> PyResult<Bound<'py, Fixed>> {
+        let exact = slf.get().exact_div(slf.get().require(other)?, rounding_or(rounding, DecimalRounding::Down)?)?;
+        Fixed::finish(slf, exact, Overflow::Raise, "division")
+    }
+
+    #[pyo3(signature = (other, rounding=None))]
+    pub fn checked_div<'py>(slf: &Bound<'py, Self>, other: &Bound<'_, PyAny>, rounding: Option<&Bound<'_, PyAny>>) -> PyResult<Option<Bound<'py, Fixed>>> {
+        let exact = match slf.get().exact_div(slf.get().require(other)?, rounding_or(rounding, DecimalRounding::Down)?) {
+            Err(e) if e.is_instance_of::<PyZeroDivisionError>(slf.py()) => return Ok(None),
+            exact => exact?,
+        };
+        Fixed::checked(slf, exact)
+    }
+
+    #[pyo3(signature = (other, rounding=None))]
+    pub fn wrapping_div<'py>(slf: &Bound<'py, Self>, other: &Bound<'_, PyAny>, rounding: Option<&Bound<'_, PyAny>>) -> PyResult<Bound<'py, Fixed>> {
+        let exact = slf.get().exact_div(slf.get().require(other)?, rounding_or(rounding, DecimalRounding::Down)?)?;
+        Fixed::finish(slf, exact, Overflow::Wrap, "division")
+    }
+
+    #[pyo3(signature = (other, rounding=None))]
+    pub fn saturating_div<'py>(slf: &Bound<'py, Self>, other: &Bound<'_, PyAny>, rounding: Option<&Bound<'_, PyAny>>) -> PyResult<Bound<'py, Fixed>> {
+        let exact = slf.get().exact_div(slf.get().require(other)?, rounding_or(rounding, DecimalRounding::Down)?)?;
+        Fixed::finish(slf, exact, Overflow::Saturate, "division")
+    }
+
+    #[pyo3(signature = (other, rounding=None))]
+    pub fn overflowing_div<'py>(slf: &Bound<'py, Self>, other: &Bound<'_, PyAny>, rounding: Option<&Bound<'_, PyAny>>) -> PyResult<(Bound<'py, Fixed>, bool)> {
+        let exact = slf.get().exact_div(slf.get().require(other)?, rounding_or(rounding, DecimalRounding::Down)?)?;
+        Fixed::overflowing(slf, exact)
+    }
+
+    pub fn __neg__<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, Fixed>> {
+        Fixed::finish(slf, -Integer::from(slf.get().bits), Overflow::Raise, "negation")
+    }
+
+    pub fn wrapping_neg<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, Fixed>> {
+        Fixed::finish(slf, -Integer::from(slf.get().bits), Overflow::Wrap, "negation")
+    }
+
+    pub fn saturating_neg<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, Fixed>> {
+        Fixed::finish(slf, -Integer::from(slf.get().bits), Overflow::Saturate, "negation")
+    }
+
+    pub fn __pos__<'py>(slf: &Bound<'py, Self>) -> Bound<'py, Self> {
+        slf.clone()
+    }
+
+    pub fn __abs__<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, Fixed>> {
+        Fixed::finish(slf, Integer::from(slf.get().bits).abs(), Overflow::Raise, "absolute value")
+    }
+
+    pub fn saturating_abs<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, Fixed>> {
+        Fixed::finish(slf, Integer::from(slf.get().bits).abs(), Overflow::Saturate, "absolute value")
+    }
+}
+
+/// Define a `Fixed` subclass for a standard Q format
+macro_rules! q_format {
+    ($name:ident, $py_name:literal, $format:expr) => {
+        #[pyclass(name=$py_name, extends=Fixed, frozen)]
+        pub struct $name;
+
+        #[pymethods]
+        impl $name {
+            #[new]
+            #[pyo3(signature = (value=None, rounding=None, saturate=false))]
+            pub fn new(
+                value: Option<&Bound<'_, PyAny>>,
+                rounding: Option<&Bound<'_, PyAny>>,
+                saturate: bool,
+            ) -> PyResult<PyClassInitializer<Self>> {
+                let fixed = match value {
+                    Some(value) => Fixed::from_py(value, $format, rounding_or(rounding, DecimalRounding::HalfEven)?, saturate)?,
+                    None => Fixed { bits: 0, format: $format },
+                };
+                Ok(PyClassInitializer::from(fixed).add_subclass($name))
+            }
+
+            #[staticmethod]
+            pub fn from_bits(py: Python, #[pyo3(from_py_with = "py_any_to_integer")] bits: Integer) -> PyResult<Py<Self>> {
+                let fixed = Fixed::from_bits_checked(&bits, $format)?;
+                Py::new(py, PyClassInitializer::from(fixed).add_subclass($name))
+            }
+
+            #[staticmethod]
+            pub fn min_value(py: Python) -> PyResult<Py<Self>> {
+                let fixed = Fixed { bits: $format.min_bits(), format: $format };
+                Py::new(py, PyClassInitializer::from(fixed).add_subclass($name))
+            }
+
+            #[staticmethod]
+            pub fn max_value(py: Python) -> PyResult<Py<Self>> {
+                let fixed = Fixed { bits: $format.max_bits(), format: $format };
+                Py::new(py, PyClassInitializer::from(fixed).add_subclass($name))
+            }
+        }
+    };
+}
+
+q_format!(Q15, "Q15", FixedFormat::Q15);
+q_format!(Q31, "Q31", FixedFormat::Q31);
+q_format!(Q7_8, "Q7_8", FixedFormat::Q7_8);
+
+pub fn register_fixed(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Fixed>()?;
+    m.add_class::<Q15>()?;
+    m.add_class::<Q31>()?;
+    m.add_class::<Q7_8>()?;
+    Ok(())
+}
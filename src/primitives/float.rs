@@ -0,0 +1,563 @@
+use std::cmp::Ordering;
+
+use pyo3::class::basic::CompareOp;
+use pyo3::exceptions::{PyOverflowError, PyTypeError, PyValueError, PyZeroDivisionError};
+use pyo3::prelude::*;
+use pyo3::types::{PyFloat, PyString};
+use pyo3::IntoPyObjectExt;
+use rug::float::{Constant, Round};
+use rug::ops::{AssignRound, Pow};
+use rug::{Float as MpFloat, Integer, Rational as MpRational};
+
+use super::int::{integer_to_py, py_any_to_integer, rational_hash, Int, HASH_INF};
+use super::rational::Rational;
+
+/// Rounding mode applied when a result does not fit the target precision
+#[pyclass(name="Round", eq, eq_int)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum RoundingMode {
+    Nearest,
+    Zero,
+    Up,
+    Down,
+    Away,
+}
+
+impl From<RoundingMode> for Round {
+    fn from(mode: RoundingMode) -> Self {
+        match mode {
+            RoundingMode::Nearest => Round::Nearest,
+            RoundingMode::Zero => Round::Zero,
+            RoundingMode::Up => Round::Up,
+            RoundingMode::Down => Round::Down,
+            RoundingMode::Away => Round::AwayZero,
+        }
+    }
+}
+
+pub(crate) fn check_prec(prec: u32) -> PyResult<u32> {
+    if !(rug::float::prec_min()..=rug::float::prec_max()).contains(&prec) {
+        return Err(PyValueError::new_err(format!(
+            "Precision must be between {} and {} bits",
+            rug::float::prec_min(),
+            rug::float::prec_max()
+        )));
+    }
+    Ok(prec)
+}
+
+/// Exact conversion of a Rustique float, Python float or integer into an `MpFloat`
+pub(crate) fn py_any_to_mpfloat(obj: &Bound<'_, PyAny>) -> PyResult<MpFloat> {
+    if let Ok(f) = obj.downcast::<Float>() {
+        return Ok(f.borrow().value.clone());
+    }
+
+    if let Ok(f) = obj.downcast::<PyFloat>() {
+        return Ok(MpFloat::with_val(53, f.value()));
+    }
+
+    if let Ok(i) = py_any_to_integer(obj) {
+        let prec = i.significant_bits().max(rug::float::prec_min());
+        return Ok(MpFloat::with_val(prec, i));
+    }
+
+    Err(PyTypeError::new_err(format!(
+        "Could not convert {} to float",
+        obj.get_type().name()?
+    )))
+}
+
+/// Round a rational to an integer the way `round` rounds a float
+fn round_rational(value: MpRational, round: RoundingMode) -> Integer {
+    let value = match round {
+        RoundingMode::Nearest => return Rational::round_half_even(&value),
+        RoundingMode::Zero => value.trunc(),
+        RoundingMode::Up => value.ceil(),
+        RoundingMode::Down => value.floor(),
+        RoundingMode::Away if value < 0 => value.floor(),
+        RoundingMode::Away => value.ceil(),
+    };
+    value.into_numer_denom().0
+}
+
+/// Multi-precision binary floating-point number backed by MPFR
+#[pyclass(name="float")]
+#[derive(Clone)]
+pub struct Float {
+    pub(crate) value: MpFloat,
+    pub(crate) round: RoundingMode,
+}
+
+impl Float {
+    /// Round `val` to this number's precision using its rounding mode
+    fn rounded<T>(&self, val: T) -> Self
+    where
+        MpFloat: AssignRound<T, Round = Round, Ordering = Ordering>,
+    {
+        self.rounded_to(self.value.prec(), val)
+    }
+
+    fn rounded_to<T>(&self, prec: u32, val: T) -> Self
+    where
+        MpFloat: AssignRound<T, Round = Round, Ordering = Ordering>,
+    {
+        Float {
+            value: MpFloat::with_val_round(prec, val, self.round.into()).0,
+            round: self.round,
+        }
+    }
+
+    /// Convert the other operand of a binary operation, along with the result
+    /// precision: operands that are not Rustique floats do not widen it
+    fn operand(&self, other: &Bound<'_, PyAny>) -> PyResult<(MpFloat, u32)> {
+        let prec = match other.downcast::<Float>() {
+            Ok(f) => self.value.prec().max(f.borrow().value.prec()),
+            Err(_) => self.value.prec(),
+        };
+        Ok((py_any_to_mpfloat(other)?, prec))
+    }
+
+    fn constant(prec: u32, round: RoundingMode, constant: Constant) -> PyResult<Self> {
+        Ok(Float {
+            value: MpFloat::with_val_round(check_prec(prec)?, constant, round.into()).0,
+            round,
+        })
+    }
+}
+
+#[pymethods]
+impl Float {
+    #[new]
+    #[pyo3(signature = (value=None, prec=53, round=RoundingMode::Nearest))]
+    pub fn new(value: Option<&Bound<'_, PyAny>>, prec: u32, round: RoundingMode) -> PyResult<Self> {
+        let prec = check_prec(prec)?;
+        let value = match value {
+            None => MpFloat::new(prec),
+            Some(v) => {
+                if let Ok(s) = v.downcast::<PyString>() {
+                    let s = s.to_str()?.trim().replace('_', "");
+                    let parsed = MpFloat::parse(&s)
+                        .map_err(|e| PyValueError::new_err(format!("Invalid literal for float: {}", e)))?;
+                    MpFloat::with_val_round(prec, parsed, round.into()).0
+                } else {
+                    MpFloat::with_val_round(prec, py_any_to_mpfloat(v)?, round.into()).0
+                }
+            }
+        };
+        Ok(Float { value, round })
+    }
+
+    #[getter]
+    pub fn prec(&self) -> u32 {
+        self.value.prec()
+    }
+
+    #[getter]
+    pub fn round(&self) -> RoundingMode {
+        self.round
+    }
+
+    /// `numbers.Real` protocol
+    #[getter]
+    pub fn real(&self) -> Self {
+        self.clone()
+    }
+
+    #[getter]
+    pub fn imag(&self) -> Self {
+        self.rounded(0)
+    }
+
+    pub fn conjugate(&self) -> Self {
+        self.clone()
+    }
+
+    /// Round to a new precision, optionally switching rounding mode
+    #[pyo3(signature = (prec, round=None))]
+    pub fn with_prec(&self, prec: u32, round: Option<RoundingMode>) -> PyResult<Self> {
+        let round = round.unwrap_or(self.round);
+        Ok(Float {
+            value: MpFloat::with_val_round(check_prec(prec)?, &self.value, round.into()).0,
+            round,
+        })
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!("float('{}', prec={})", self.value, self.value.prec())
+    }
+
+    pub fn __str__(&self) -> String {
+        self.value.to_string()
+    }
+
+    /// Decimal string with `digits` significant digits, rounded with this number's mode
+    #[pyo3(signature = (digits=None))]
+    pub fn to_string(&self, digits: Option<usize>) -> String {
+        self.value.to_string_radix_round(10, digits, self.round.into())
+    }
+
+    /// Hashes equal to Python numbers of the same value
+    pub fn __hash__(&self) -> isize {
+        if let Some(ratio) = self.value.to_rational() {
+            return rational_hash(&ratio);
+        }
+        if self.value.is_infinite() {
+            return if self.value.is_sign_negative() { -HASH_INF } else { HASH_INF };
+        }
+        0
+    }
+
+    pub fn __bool__(&self) -> bool {
+        !self.value.is_zero()
+    }
+
+    pub fn __float__(&self) -> f64 {
+        self.value.to_f64_round(self.round.into())
+    }
+
+    /// Truncates toward zero, like Python's `int(float)`
+    pub fn __int__(&self, py: Python) -> PyResult<PyObject> {
+        self.to_int(Some(RoundingMode::Zero))?.__int__(py)
+    }
+
+    pub fn __trunc__(&self, py: Python) -> PyResult<PyObject> {
+        self.__int__(py)
+    }
+
+    pub fn __floor__(&self, py: Python) -> PyResult<PyObject> {
+        self.to_int(Some(RoundingMode::Down))?.__int__(py)
+    }
+
+    pub fn __ceil__(&self, py: Python) -> PyResult<PyObject> {
+        self.to_int(Some(RoundingMode::Up))?.__int__(py)
+    }
+
+    /// Round with this number's rounding mode, so the default `Nearest` rounds
+    /// half to even like Python; with `ndigits`, round to a multiple of
+    /// `10**-ndigits` and keep the precision
+    #[pyo3(signature = (ndigits=None))]
+    pub fn __round__(&self, py: Python, ndigits: Option<i32>) -> PyResult<PyObject> {
+        let Some(n) = ndigits else {
+            return self.to_int(None)?.__int__(py);
+        };
+        let Some(value) = self.value.to_rational() else {
+            return self.clone().into_py_any(py);
+        };
+        let shift = MpRational::from(10).pow(n);
+        let rounded = round_rational(value * &shift, self.round);
+        self.rounded(&(MpRational::from(rounded) / shift)).into_py_any(py)
+    }
+
+    /// Correctly rounded conversion to the arbitrary-precision `int`
+    #[pyo3(signature = (round=None))]
+    pub fn to_int(&self, round: Option<RoundingMode>) -> PyResult<Int> {
+        if self.value.is_nan() {
+            return Err(PyValueError::new_err("Cannot convert NaN to int"));
+        }
+        self.value
+            .to_integer_round(round.unwrap_or(self.round).into())
+            .map(|(i, _)| Int(i))
+            .ok_or_else(|| PyOverflowError::new_err("Cannot convert infinity to int"))
+    }
+
+    pub fn as_integer_ratio(&self, py: Python) -> PyResult<(PyObject, PyObject)> {
+        let ratio = self
+            .value
+            .to_rational()
+            .ok_or_else(|| PyValueError::new_err("Cannot convert non-finite float to a ratio"))?;
+        let (numer, denom) = ratio.into_numer_denom();
+        Ok((integer_to_py(py, &numer)?, integer_to_py(py, &denom)?))
+    }
+
+    pub fn is_nan(&self) -> bool {
+        self.value.is_nan()
+    }
+
+    pub fn is_infinite(&self) -> bool {
+        self.value.is_infinite()
+    }
+
+    pub fn is_finite(&self) -> bool {
+        self.value.is_finite()
+    }
+
+    pub fn __richcmp__(&self, py: Python, other: &Bound<'_, PyAny>, op: CompareOp) -> PyResult<PyObject> {
+        let Ok(other) = py_any_to_mpfloat(other) else {
+            return Ok(py.NotImplemented());
+        };
+        let result = match self.value.partial_cmp(&other) {
+            Some(ordering) => op.matches(ordering),
+            // NaN compares unequal to everything
+            None => matches!(op, CompareOp::Ne),
+        };
+        result.into_py_any(py)
+    }
+
+    pub fn __add__(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        let Ok((other, prec)) = self.operand(other) else {
+            return Ok(py.NotImplemented());
+        };
+        self.rounded_to(prec, &self.value + &other).into_py_any(py)
+    }
+
+    pub fn __radd__(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        let Ok((other, prec)) = self.operand(other) else {
+            return Ok(py.NotImplemented());
+        };
+        self.rounded_to(prec, &other + &self.value).into_py_any(py)
+    }
+
+    pub fn __sub__(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        let Ok((other, prec)) = self.operand(other) else {
+            return Ok(py.NotImplemented());
+        };
+        self.rounded_to(prec, &self.value - &other).into_py_any(py)
+    }
+
+    pub fn __rsub__(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        let Ok((other, prec)) = self.operand(other) else {
+            return Ok(py.NotImplemented());
+        };
+        self.rounded_to(prec, &other - &self.value).into_py_any(py)
+    }
+
+    pub fn __mul__(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        let Ok((other, prec)) = self.operand(other) else {
+            return Ok(py.NotImplemented());
+        };
+        self.rounded_to(prec, &self.value * &other).into_py_any(py)
+    }
+
+    pub fn __rmul__(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        let Ok((other, prec)) = self.operand(other) else {
+            return Ok(py.NotImplemented());
+        };
+        self.rounded_to(prec, &other * &self.value).into_py_any(py)
+    }
+
+    pub fn __truediv__(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        let Ok((other, prec)) = self.operand(other) else {
+            return Ok(py.NotImplemented());
+        };
+        if other.is_zero() {
+            return Err(PyZeroDivisionError::new_err("Division by zero"));
+        }
+        self.rounded_to(prec, &self.value / &other).into_py_any(py)
+    }
+
+    pub fn __rtruediv__(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        let Ok((other, prec)) = self.operand(other) else {
+            return Ok(py.NotImplemented());
+        };
+        if self.value.is_zero() {
+            return Err(PyZeroDivisionError::new_err("Division by zero"));
+        }
+        self.rounded_to(prec, &other / &self.value).into_py_any(py)
+    }
+
+    pub fn __pow__(&self, py: Python, other: &Bound<'_, PyAny>, modulo: Option<&Bound<'_, PyAny>>) -> PyResult<PyObject> {
+        if modulo.is_some_and(|m| !m.is_none()) {
+            return Err(PyTypeError::new_err("pow() 3rd argument not allowed unless all arguments are integers"));
+        }
+        let Ok((other, prec)) = self.operand(other) else {
+            return Ok(py.NotImplemented());
+        };
+        self.rounded_to(prec, (&self.value).pow(&other)).into_py_any(py)
+    }
+
+    pub fn __rpow__(&self, py: Python, other: &Bound<'_, PyAny>, modulo: Option<&Bound<'_, PyAny>>) -> PyResult<PyObject> {
+        if modulo.is_some_and(|m| !m.is_none()) {
+            return Err(PyTypeError::new_err("pow() 3rd argument not allowed unless all arguments are integers"));
+        }
+        let Ok((other, prec)) = self.operand(other) else {
+            return Ok(py.NotImplemented());
+        };
+        self.rounded_to(prec, (&other).pow(&self.value)).into_py_any(py)
+    }
+
+    pub fn __neg__(&self) -> Self {
+        self.rounded(-&self.value)
+    }
+
+    pub fn __pos__(&self) -> Self {
+        self.clone()
+    }
+
+    pub fn __abs__(&self) -> Self {
+        self.rounded(self.value.abs_ref())
+    }
+
+    pub fn sqrt(&self) -> Self {
+        self.rounded(self.value.sqrt_ref())
+    }
+
+    pub fn cbrt(&self) -> Self {
+        self.rounded(self.value.cbrt_ref())
+    }
+
+    pub fn root(&self, k: u32) -> Self {
+        self.rounded(self.value.root_ref(k))
+    }
+
+    pub fn exp(&self) -> Self {
+        self.rounded(self.value.exp_ref())
+    }
+
+    pub fn exp2(&self) -> Self {
+        self.rounded(self.value.exp2_ref())
+    }
+
+    pub fn exp10(&self) -> Self {
+        self.rounded(self.value.exp10_ref())
+    }
+
+    pub fn expm1(&self) -> Self {
+        self.rounded(self.value.exp_m1_ref())
+    }
+
+    pub fn ln(&self) -> Self {
+        self.rounded(self.value.ln_ref())
+    }
+
+    pub fn log2(&self) -> Self {
+        self.rounded(self.value.log2_ref())
+    }
+
+    pub fn log10(&self) -> Self {
+        self.rounded(self.value.log10_ref())
+    }
+
+    pub fn log1p(&self) -> Self {
+        self.rounded(self.value.ln_1p_ref())
+    }
+
+    pub fn sin(&self) -> Self {
+        self.rounded(self.value.sin_ref())
+    }
+
+    pub fn cos(&self) -> Self {
+        self.rounded(self.value.cos_ref())
+    }
+
+    pub fn tan(&self) -> Self {
+        self.rounded(self.value.tan_ref())
+    }
+
+    pub fn asin(&self) -> Self {
+        self.rounded(self.value.asin_ref())
+    }
+
+    pub fn acos(&self) -> Self {
+        self.rounded(self.value.acos_ref())
+    }
+
+    pub fn atan(&self) -> Self {
+        self.rounded(self.value.atan_ref())
+    }
+
+    pub fn atan2(&self, x: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let (other, prec) = self.operand(x)?;
+        Ok(self.rounded_to(prec, self.value.atan2_ref(&other)))
+    }
+
+    pub fn sinh(&self) -> Self {
+        self.rounded(self.value.sinh_ref())
+    }
+
+    pub fn cosh(&self) -> Self {
+        self.rounded(self.value.cosh_ref())
+    }
+
+    pub fn tanh(&self) -> Self {
+        self.rounded(self.value.tanh_ref())
+    }
+
+    pub fn asinh(&self) -> Self {
+        self.rounded(self.value.asinh_ref())
+    }
+
+    pub fn acosh(&self) -> Self {
+        self.rounded(self.value.acosh_ref())
+    }
+
+    pub fn atanh(&self) -> Self {
+        self.rounded(self.value.atanh_ref())
+    }
+
+    pub fn hypot(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let (other, prec) = self.operand(other)?;
+        Ok(self.rounded_to(prec, self.value.hypot_ref(&other)))
+    }
+
+    pub fn gamma(&self) -> Self {
+        self.rounded(self.value.gamma_ref())
+    }
+
+    pub fn lgamma(&self) -> Self {
+        self.rounded(self.value.ln_gamma_ref())
+    }
+
+    pub fn erf(&self) -> Self {
+        self.rounded(self.value.erf_ref())
+    }
+
+    pub fn erfc(&self) -> Self {
+        self.rounded(self.value.erfc_ref())
+    }
+
+    pub fn floor(&self) -> Self {
+        self.rounded(self.value.floor_ref())
+    }
+
+    pub fn ceil(&self) -> Self {
+        self.rounded(self.value.ceil_ref())
+    }
+
+    pub fn trunc(&self) -> Self {
+        self.rounded(self.value.trunc_ref())
+    }
+
+    /// Round to the nearest integer, ties to even like Python's `round`
+    pub fn round_even(&self) -> Self {
+        self.rounded(self.value.round_even_ref())
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (prec=53, round=RoundingMode::Nearest))]
+    pub fn pi(prec: u32, round: RoundingMode) -> PyResult<Self> {
+        Float::constant(prec, round, Constant::Pi)
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (prec=53, round=RoundingMode::Nearest))]
+    pub fn ln2(prec: u32, round: RoundingMode) -> PyResult<Self> {
+        Float::constant(prec, round, Constant::Log2)
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (prec=53, round=RoundingMode::Nearest))]
+    pub fn euler(prec: u32, round: RoundingMode) -> PyResult<Self> {
+        Float::constant(prec, round, Constant::Euler)
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (prec=53, round=RoundingMode::Nearest))]
+    pub fn e(prec: u32, round: RoundingMode) -> PyResult<Self> {
+        let one = MpFloat::with_val(rug::float::prec_min(), 1);
+        Ok(Float {
+            value: MpFloat::with_val_round(check_prec(prec)?, one.exp_ref(), round.into()).0,
+            round,
+        })
+    }
+}
+
+pub fn register_float(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<RoundingMode>()?;
+    m.add_class::<Float>()?;
+    // Let `fractions.Fraction` and `numbers` based code fall back to float arithmetic
+    let py = m.py();
+    py.import("numbers")?
+        .getattr("Real")?
+        .call_method1("register", (py.get_type::<Float>(),))?;
+    Ok(())
+}
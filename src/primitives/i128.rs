@@ -2,7 +2,7 @@ use pyo3::prelude::*;
 
 #[pyclass(name="i128")]
 pub struct I128 {
-    value: i128,
+    pub(crate) value: i128,
 }
 
 #[pymethods]
@@ -2,7 +2,7 @@ use pyo3::prelude::*;
 
 #[pyclass(name="i16")]
 pub struct I16 {
-    value: i16,
+    pub(crate) value: i16,
 }
 
 #[pymethods]
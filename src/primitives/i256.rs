@@ -0,0 +1,10 @@
+use pyo3::prelude::*;
+
+use super::wide::wide_int;
+
+wide_int!(I256, "i256", 4, true);
+
+pub fn register_i256(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<I256>()?;
+    Ok(())
+}
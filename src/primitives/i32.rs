@@ -2,7 +2,7 @@ use pyo3::prelude::*;
 
 #[pyclass(name="i32")]
 pub struct I32 {
-    value: i32,
+    pub(crate) value: i32,
 }
 
 #[pymethods]
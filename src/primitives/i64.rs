@@ -2,7 +2,7 @@ use pyo3::prelude::*;
 
 #[pyclass(name="i64")]
 pub struct I64 {
-    value: i64,
+    pub(crate) value: i64,
 }
 
 #[pymethods]
@@ -3,7 +3,7 @@ use pyo3::prelude::*;
 use pyo3::class::basic::CompareOp;
 use pyo3::types::{PyBool, PyFloat, PyInt, PyString};
 
-fn py_any_to_i8(obj: &Bound<'_, PyAny>) -> PyResult<i8> {
+pub(crate) fn py_any_to_i8(obj: &Bound<'_, PyAny>) -> PyResult<i8> {
     if let Ok(i) = obj.extract::<i8>() {
         return Ok(i);
     }
@@ -31,7 +31,7 @@ fn py_any_to_i8(obj: &Bound<'_, PyAny>) -> PyResult<i8> {
 }
 
 #[pyclass(name="i8")]
-pub struct I8(i8);
+pub struct I8(pub(crate) i8);
 
 #[pymethods]
 impl I8 {
@@ -0,0 +1,569 @@
+use std::cmp::Ordering;
+
+use num_bigint::{BigInt, Sign};
+use pyo3::class::basic::CompareOp;
+use pyo3::exceptions::{PyOverflowError, PyTypeError, PyValueError, PyZeroDivisionError};
+use pyo3::prelude::*;
+use pyo3::types::{PyFloat, PyString};
+use pyo3::IntoPyObjectExt;
+use rug::integer::{IsPrime, Order};
+use rug::ops::Pow;
+use rug::{Float, Integer, Rational};
+
+use super::i8::I8;
+use super::i16::I16;
+use super::i32::I32;
+use super::i64::I64;
+use super::i128::I128;
+use super::i256::I256;
+use super::u8::U8;
+use super::u16::U16;
+use super::u32::U32;
+use super::u64::U64;
+use super::u128::U128;
+use super::u256::U256;
+use super::u512::U512;
+use super::isize::ISize;
+use super::usize::Usize;
+use super::num::Num;
+
+/// Modulus used by CPython to hash integers (2**61 - 1)
+const HASH_MODULUS: u64 = (1 << 61) - 1;
+/// CPython's hash of positive infinity
+pub(crate) const HASH_INF: isize = 314159;
+
+pub(crate) fn bigint_to_integer(value: &BigInt) -> Integer {
+    let (sign, digits) = value.to_u32_digits();
+    let magnitude = Integer::from_digits(&digits, Order::Lsf);
+    if sign == Sign::Minus {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
+
+pub(crate) fn integer_to_bigint(value: &Integer) -> BigInt {
+    let sign = match value.cmp0() {
+        Ordering::Less => Sign::Minus,
+        Ordering::Equal => Sign::NoSign,
+        Ordering::Greater => Sign::Plus,
+    };
+    BigInt::from_slice(sign, &value.to_digits::<u32>(Order::Lsf))
+}
+
+/// Convert an `Integer` into a Python `int`
+pub(crate) fn integer_to_py(py: Python, value: &Integer) -> PyResult<PyObject> {
+    if let Some(small) = value.to_i64() {
+        return small.into_py_any(py);
+    }
+    integer_to_bigint(value).into_py_any(py)
+}
+
+/// Python-compatible hash of an integer, so that `hash(int(n)) == hash(n)`
+pub(crate) fn integer_hash(value: &Integer) -> isize {
+    let modulus = Integer::from(HASH_MODULUS);
+    let mut hash = Integer::from(value.abs_ref()) % &modulus;
+    if *value < 0 {
+        hash = -hash;
+    }
+    let hash = hash.to_isize().unwrap_or(0);
+    if hash == -1 {
+        -2
+    } else {
+        hash
+    }
+}
+
+/// Python-compatible hash of a rational, so that `hash(Rational(n, d)) == hash(Fraction(n, d))`
+pub(crate) fn rational_hash(value: &Rational) -> isize {
+    let modulus = Integer::from(HASH_MODULUS);
+    let hash = match value.denom().clone().pow_mod(&Integer::from(HASH_MODULUS - 2), &modulus) {
+        // The denominator is a multiple of the modulus
+        Ok(dinv) if dinv == 0 => HASH_INF,
+        Ok(dinv) => {
+            let h = Integer::from(value.numer().abs_ref()) % &modulus * dinv % &modulus;
+            h.to_isize().unwrap_or(0)
+        }
+        Err(_) => HASH_INF,
+    };
+    let hash = if *value.numer() < 0 { -hash } else { hash };
+    if hash == -1 {
+        -2
+    } else {
+        hash
+    }
+}
+
+/// Widen a Rustique fixed-width integer primitive into an `Integer`
+pub(crate) fn fixed_width_to_integer(obj: &Bound<'_, PyAny>) -> Option<Integer> {
+    if let Ok(i) = obj.downcast::<I8>() {
+        return Some(Integer::from(i.borrow().0));
+    }
+    if let Ok(i) = obj.downcast::<I16>() {
+        return Some(Integer::from(i.borrow().value));
+    }
+    if let Ok(i) = obj.downcast::<I32>() {
+        return Some(Integer::from(i.borrow().value));
+    }
+    if let Ok(i) = obj.downcast::<I64>() {
+        return Some(Integer::from(i.borrow().value));
+    }
+    if let Ok(i) = obj.downcast::<I128>() {
+        return Some(Integer::from(i.borrow().value));
+    }
+    if let Ok(i) = obj.downcast::<ISize>() {
+        return Some(Integer::from(i.borrow().value));
+    }
+    if let Ok(i) = obj.downcast::<U8>() {
+        return Some(Integer::from(i.borrow().value));
+    }
+    if let Ok(i) = obj.downcast::<U16>() {
+        return Some(Integer::from(i.borrow().value));
+    }
+    if let Ok(i) = obj.downcast::<U32>() {
+        return Some(Integer::from(i.borrow().value));
+    }
+    if let Ok(i) = obj.downcast::<U64>() {
+        return Some(Integer::from(i.borrow().value));
+    }
+    if let Ok(i) = obj.downcast::<U128>() {
+        return Some(Integer::from(i.borrow().value));
+    }
+    if let Ok(i) = obj.downcast::<Usize>() {
+        return Some(Integer::from(i.borrow().value));
+    }
+    if let Ok(i) = obj.downcast::<I256>() {
+        return Some(i.get().value.to_integer(true));
+    }
+    if let Ok(i) = obj.downcast::<U256>() {
+        return Some(i.get().value.to_integer(false));
+    }
+    if let Ok(i) = obj.downcast::<U512>() {
+        return Some(i.get().value.to_integer(false));
+    }
+    None
+}
+
+/// Extract an `Integer` from a Rustique integer type or anything implementing `__index__`
+pub(crate) fn py_any_to_integer(obj: &Bound<'_, PyAny>) -> PyResult<Integer> {
+    if let Ok(i) = obj.downcast::<Int>() {
+        return Ok(i.borrow().0.clone());
+    }
+
+    if let Ok(n) = obj.downcast::<Num>() {
+        return Ok(n.borrow().to_integer());
+    }
+
+    if let Some(i) = fixed_width_to_integer(obj) {
+        return Ok(i);
+    }
+
+    if let Ok(i) = obj.extract::<i64>() {
+        return Ok(Integer::from(i));
+    }
+
+    if let Ok(i) = obj.extract::<BigInt>() {
+        return Ok(bigint_to_integer(&i));
+    }
+
+    Err(PyTypeError::new_err(format!(
+        "Could not convert {} to int",
+        obj.get_type().name()?
+    )))
+}
+
+fn py_any_to_u32(obj: &Bound<'_, PyAny>, what: &str) -> PyResult<u32> {
+    py_any_to_integer(obj)?
+        .to_u32()
+        .ok_or_else(|| PyValueError::new_err(format!("{} must be a non-negative integer below 2**32", what)))
+}
+
+fn shift_count(count: &Integer) -> PyResult<u32> {
+    count
+        .to_u32()
+        .ok_or_else(|| PyValueError::new_err("Shift count must be a non-negative integer below 2**32"))
+}
+
+fn true_div(a: Integer, b: Integer) -> PyResult<f64> {
+    if b == 0 {
+        return Err(PyZeroDivisionError::new_err("Division by zero"));
+    }
+    let f = Float::with_val(53, Rational::from((a, b))).to_f64();
+    if f.is_infinite() {
+        return Err(PyOverflowError::new_err("Integer division result too large for a float"));
+    }
+    Ok(f)
+}
+
+/// Floor division and modulo, as Python's `divmod` computes them
+fn div_mod(a: Integer, b: Integer) -> PyResult<(Int, Int)> {
+    if b == 0 {
+        return Err(PyZeroDivisionError::new_err("Division by zero"));
+    }
+    let (q, r) = a.div_rem_floor(b);
+    Ok((Int(q), Int(r)))
+}
+
+fn power(base: Integer, exponent: &Integer) -> PyResult<Int> {
+    let exponent = exponent
+        .to_u32()
+        .ok_or_else(|| PyValueError::new_err("Exponent must be a non-negative integer below 2**32"))?;
+    Ok(Int(base.pow(exponent)))
+}
+
+fn check_modulus(modulo: &Integer) -> PyResult<()> {
+    if *modulo == 0 {
+        return Err(PyZeroDivisionError::new_err("Modulus must not be zero"));
+    }
+    Ok(())
+}
+
+/// Move a residue in `[0, |modulo|)` into the range Python uses, where a
+/// non-zero result takes the sign of the modulus
+pub(crate) fn python_residue(residue: Integer, modulo: &Integer) -> Integer {
+    if *modulo < 0 && residue != 0 {
+        residue + modulo
+    } else {
+        residue
+    }
+}
+
+/// Arbitrary-precision integer backed by GMP
+#[pyclass(name="int")]
+#[derive(Clone)]
+pub struct Int(pub(crate) Integer);
+
+#[pymethods]
+impl Int {
+    #[new]
+    #[pyo3(signature = (value=None, base=None))]
+    pub fn new(value: Option<&Bound<'_, PyAny>>, base: Option<i32>) -> PyResult<Self> {
+        let Some(value) = value else {
+            if base.is_some() {
+                return Err(PyTypeError::new_err("int() missing string argument"));
+            }
+            return Ok(Int(Integer::new()));
+        };
+
+        if let Ok(s) = value.downcast::<PyString>() {
+            let base = base.unwrap_or(10);
+            if !(2..=36).contains(&base) {
+                return Err(PyValueError::new_err("base must be between 2 and 36"));
+            }
+            let s = s.to_str()?.trim().replace('_', "");
+            return Integer::from_str_radix(&s, base)
+                .map(Int)
+                .map_err(|e| PyValueError::new_err(format!("Invalid literal for int: {}", e)));
+        }
+
+        if base.is_some() {
+            return Err(PyTypeError::new_err("int() can't convert non-string with explicit base"));
+        }
+
+        if let Ok(f) = value.downcast::<PyFloat>() {
+            let f = f.value();
+            return Integer::from_f64(f.trunc())
+                .map(Int)
+                .ok_or_else(|| PyOverflowError::new_err("Cannot convert non-finite float to int"));
+        }
+
+        py_any_to_integer(value).map(Int)
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!("int({})", self.0)
+    }
+
+    pub fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    pub fn __hash__(&self) -> isize {
+        integer_hash(&self.0)
+    }
+
+    pub fn __bool__(&self) -> bool {
+        self.0 != 0
+    }
+
+    pub fn __int__(&self, py: Python) -> PyResult<PyObject> {
+        integer_to_py(py, &self.0)
+    }
+
+    pub fn __index__(&self, py: Python) -> PyResult<PyObject> {
+        integer_to_py(py, &self.0)
+    }
+
+    pub fn __float__(&self) -> PyResult<f64> {
+        let f = Float::with_val(53, &self.0).to_f64();
+        if f.is_infinite() {
+            return Err(PyOverflowError::new_err("int too large to convert to float"));
+        }
+        Ok(f)
+    }
+
+    pub fn __richcmp__(&self, py: Python, other: &Bound<'_, PyAny>, op: CompareOp) -> PyResult<PyObject> {
+        let ordering = if let Ok(f) = other.downcast::<PyFloat>() {
+            self.0.partial_cmp(&f.value())
+        } else if let Ok(other) = py_any_to_integer(other) {
+            Some(self.0.cmp(&other))
+        } else {
+            return Ok(py.NotImplemented());
+        };
+
+        let result = match ordering {
+            Some(ordering) => op.matches(ordering),
+            // NaN compares unequal to everything
+            None => matches!(op, CompareOp::Ne),
+        };
+        result.into_py_any(py)
+    }
+
+    pub fn __add__(&self, #[pyo3(from_py_with = "py_any_to_integer")] other: Integer) -> Self {
+        Int(self.0.clone() + other)
+    }
+
+    pub fn __radd__(&self, #[pyo3(from_py_with = "py_any_to_integer")] other: Integer) -> Self {
+        Int(other + &self.0)
+    }
+
+    pub fn __sub__(&self, #[pyo3(from_py_with = "py_any_to_integer")] other: Integer) -> Self {
+        Int(self.0.clone() - other)
+    }
+
+    pub fn __rsub__(&self, #[pyo3(from_py_with = "py_any_to_integer")] other: Integer) -> Self {
+        Int(other - &self.0)
+    }
+
+    pub fn __mul__(&self, #[pyo3(from_py_with = "py_any_to_integer")] other: Integer) -> Self {
+        Int(self.0.clone() * other)
+    }
+
+    pub fn __rmul__(&self, #[pyo3(from_py_with = "py_any_to_integer")] other: Integer) -> Self {
+        Int(other * &self.0)
+    }
+
+    pub fn __truediv__(&self, #[pyo3(from_py_with = "py_any_to_integer")] other: Integer) -> PyResult<f64> {
+        true_div(self.0.clone(), other)
+    }
+
+    pub fn __rtruediv__(&self, #[pyo3(from_py_with = "py_any_to_integer")] other: Integer) -> PyResult<f64> {
+        true_div(other, self.0.clone())
+    }
+
+    pub fn __floordiv__(&self, #[pyo3(from_py_with = "py_any_to_integer")] other: Integer) -> PyResult<Self> {
+        Ok(div_mod(self.0.clone(), other)?.0)
+    }
+
+    pub fn __rfloordiv__(&self, #[pyo3(from_py_with = "py_any_to_integer")] other: Integer) -> PyResult<Self> {
+        Ok(div_mod(other, self.0.clone())?.0)
+    }
+
+    pub fn __mod__(&self, #[pyo3(from_py_with = "py_any_to_integer")] other: Integer) -> PyResult<Self> {
+        Ok(div_mod(self.0.clone(), other)?.1)
+    }
+
+    pub fn __rmod__(&self, #[pyo3(from_py_with = "py_any_to_integer")] other: Integer) -> PyResult<Self> {
+        Ok(div_mod(other, self.0.clone())?.1)
+    }
+
+    pub fn __divmod__(&self, #[pyo3(from_py_with = "py_any_to_integer")] other: Integer) -> PyResult<(Self, Self)> {
+        div_mod(self.0.clone(), other)
+    }
+
+    pub fn __rdivmod__(&self, #[pyo3(from_py_with = "py_any_to_integer")] other: Integer) -> PyResult<(Self, Self)> {
+        div_mod(other, self.0.clone())
+    }
+
+    pub fn __pow__(&self, #[pyo3(from_py_with = "py_any_to_integer")] exponent: Integer, modulo: Option<&Bound<'_, PyAny>>) -> PyResult<Self> {
+        if let Some(modulo) = modulo.filter(|m| !m.is_none()) {
+            return self.pow_mod(exponent, py_any_to_integer(modulo)?);
+        }
+        power(self.0.clone(), &exponent)
+    }
+
+    pub fn __rpow__(&self, #[pyo3(from_py_with = "py_any_to_integer")] base: Integer, modulo: Option<&Bound<'_, PyAny>>) -> PyResult<Self> {
+        if let Some(modulo) = modulo.filter(|m| !m.is_none()) {
+            return Int(base).pow_mod(self.0.clone(), py_any_to_integer(modulo)?);
+        }
+        power(base, &self.0)
+    }
+
+    pub fn __neg__(&self) -> Self {
+        Int(-self.0.clone())
+    }
+
+    pub fn __pos__(&self) -> Self {
+        self.clone()
+    }
+
+    pub fn __abs__(&self) -> Self {
+        Int(self.0.clone().abs())
+    }
+
+    pub fn __invert__(&self) -> Self {
+        Int(!self.0.clone())
+    }
+
+    pub fn __and__(&self, #[pyo3(from_py_with = "py_any_to_integer")] other: Integer) -> Self {
+        Int(self.0.clone() & other)
+    }
+
+    pub fn __rand__(&self, #[pyo3(from_py_with = "py_any_to_integer")] other: Integer) -> Self {
+        Int(other & &self.0)
+    }
+
+    pub fn __or__(&self, #[pyo3(from_py_with = "py_any_to_integer")] other: Integer) -> Self {
+        Int(self.0.clone() | other)
+    }
+
+    pub fn __ror__(&self, #[pyo3(from_py_with = "py_any_to_integer")] other: Integer) -> Self {
+        Int(other | &self.0)
+    }
+
+    pub fn __xor__(&self, #[pyo3(from_py_with = "py_any_to_integer")] other: Integer) -> Self {
+        Int(self.0.clone() ^ other)
+    }
+
+    pub fn __rxor__(&self, #[pyo3(from_py_with = "py_any_to_integer")] other: Integer) -> Self {
+        Int(other ^ &self.0)
+    }
+
+    pub fn __lshift__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(Int(self.0.clone() << py_any_to_u32(other, "Shift count")?))
+    }
+
+    pub fn __rlshift__(&self, #[pyo3(from_py_with = "py_any_to_integer")] other: Integer) -> PyResult<Self> {
+        Ok(Int(other << shift_count(&self.0)?))
+    }
+
+    pub fn __rshift__(&self, other: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(Int(self.0.clone() >> py_any_to_u32(other, "Shift count")?))
+    }
+
+    pub fn __rrshift__(&self, #[pyo3(from_py_with = "py_any_to_integer")] other: Integer) -> PyResult<Self> {
+        Ok(Int(other >> shift_count(&self.0)?))
+    }
+
+    pub fn bit_length(&self) -> u32 {
+        self.0.significant_bits()
+    }
+
+    pub fn gcd(&self, #[pyo3(from_py_with = "py_any_to_integer")] other: Integer) -> Self {
+        Int(self.0.clone().gcd(&other))
+    }
+
+    pub fn lcm(&self, #[pyo3(from_py_with = "py_any_to_integer")] other: Integer) -> Self {
+        Int(self.0.clone().lcm(&other))
+    }
+
+    /// Returns `(g, s, t)` such that `g == gcd(self, other) == self*s + other*t`
+    pub fn gcd_ext(&self, #[pyo3(from_py_with = "py_any_to_integer")] other: Integer) -> (Self, Self, Self) {
+        let (g, s, t) = self.0.clone().extended_gcd(other, Integer::new());
+        (Int(g), Int(s), Int(t))
+    }
+
+    pub fn pow_mod(
+        &self,
+        #[pyo3(from_py_with = "py_any_to_integer")] exponent: Integer,
+        #[pyo3(from_py_with = "py_any_to_integer")] modulo: Integer,
+    ) -> PyResult<Self> {
+        check_modulus(&modulo)?;
+        self.0
+            .clone()
+            .pow_mod(&exponent, &modulo)
+            .map(|r| Int(python_residue(r, &modulo)))
+            .map_err(|_| PyValueError::new_err("Base is not invertible for the given modulus"))
+    }
+
+    /// Modular inverse of `self` modulo `modulo`
+    pub fn invert(&self, #[pyo3(from_py_with = "py_any_to_integer")] modulo: Integer) -> PyResult<Self> {
+        check_modulus(&modulo)?;
+        self.0
+            .clone()
+            .invert(&modulo)
+            .map(|r| Int(python_residue(r, &modulo)))
+            .map_err(|_| PyValueError::new_err("Base is not invertible for the given modulus"))
+    }
+
+    #[pyo3(signature = (reps=25))]
+    pub fn is_probably_prime(&self, reps: u32) -> bool {
+        self.0.is_probably_prime(reps) != IsPrime::No
+    }
+
+    pub fn next_prime(&self) -> Self {
+        Int(self.0.clone().next_prime())
+    }
+
+    pub fn jacobi(&self, #[pyo3(from_py_with = "py_any_to_integer")] n: Integer) -> PyResult<i32> {
+        if n <= 0 || n.is_even() {
+            return Err(PyValueError::new_err("Jacobi symbol requires an odd positive n"));
+        }
+        Ok(self.0.jacobi(&n))
+    }
+
+    pub fn legendre(&self, #[pyo3(from_py_with = "py_any_to_integer")] p: Integer) -> PyResult<i32> {
+        if p <= 2 || p.is_even() || p.is_probably_prime(25) == IsPrime::No {
+            return Err(PyValueError::new_err("Legendre symbol requires an odd prime p"));
+        }
+        Ok(self.0.legendre(&p))
+    }
+
+    pub fn kronecker(&self, #[pyo3(from_py_with = "py_any_to_integer")] n: Integer) -> i32 {
+        self.0.kronecker(&n)
+    }
+
+    /// Returns `(s, r)` where `s` is the truncated square root and `r == self - s*s`
+    pub fn sqrt_rem(&self) -> PyResult<(Self, Self)> {
+        if self.0 < 0 {
+            return Err(PyValueError::new_err("Square root of a negative number"));
+        }
+        let (s, r) = self.0.clone().sqrt_rem(Integer::new());
+        Ok((Int(s), Int(r)))
+    }
+
+    /// Truncated `n`th root
+    pub fn root(&self, n: u32) -> PyResult<Self> {
+        if n == 0 {
+            return Err(PyValueError::new_err("Root degree must be positive"));
+        }
+        if n % 2 == 0 && self.0 < 0 {
+            return Err(PyValueError::new_err("Even root of a negative number"));
+        }
+        Ok(Int(self.0.clone().root(n)))
+    }
+
+    pub fn is_perfect_square(&self) -> bool {
+        self.0.is_perfect_square()
+    }
+
+    pub fn is_perfect_power(&self) -> bool {
+        self.0.is_perfect_power()
+    }
+
+    #[staticmethod]
+    pub fn factorial(n: u32) -> Self {
+        Int(Integer::from(Integer::factorial(n)))
+    }
+
+    /// Binomial coefficient `n` choose `k`; `n` may be negative
+    #[staticmethod]
+    pub fn binomial(#[pyo3(from_py_with = "py_any_to_integer")] n: Integer, k: u32) -> Self {
+        Int(n.binomial(k))
+    }
+
+    #[staticmethod]
+    pub fn fibonacci(n: u32) -> Self {
+        Int(Integer::from(Integer::fibonacci(n)))
+    }
+
+    #[staticmethod]
+    pub fn lucas(n: u32) -> Self {
+        Int(Integer::from(Integer::lucas(n)))
+    }
+}
+
+pub fn register_int(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Int>()?;
+    Ok(())
+}
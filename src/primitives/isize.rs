@@ -2,7 +2,7 @@ use pyo3::prelude::*;
 
 #[pyclass(name="isize")]
 pub struct ISize {
-    value: isize,
+    pub(crate) value: isize,
 }
 
 #[pymethods]
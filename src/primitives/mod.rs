@@ -5,11 +5,14 @@ pub mod i16;  // i16 type
 pub mod i32;  // i32 type
 pub mod i64;  // i64 type
 pub mod i128;  // i128 type
+pub mod i256;  // i256 type
 pub mod u8;   // u8 type
 pub mod u16;  // u16 type
 pub mod u32;  // u32 type
 pub mod u64;  // u64 type
 pub mod u128;  // u128 type
+pub mod u256;  // u256 type
+pub mod u512;  // u512 type
 pub mod isize;  // isize type
 pub mod usize;  // usize type
 pub mod bool;  // bool type
@@ -17,8 +20,15 @@ pub mod char;  // char type
 pub mod str;   // str type
 pub mod f32;  // f32 type
 pub mod f64;  // f64 type
-pub mod int;
-pub mod float;
+pub mod wide;  // limb arithmetic shared by the 256/512-bit types
+pub mod int;   // arbitrary-precision int type
+pub mod num;   // small-int-optimized num type
+pub mod float; // arbitrary-precision float type
+pub mod rational; // exact rational type
+pub mod decimal; // fixed-scale decimal type
+pub mod modint; // modular integer type
+pub mod fixed; // fixed-point Q-format types
+pub mod complex; // complex number type
 
 
 /// Register all primitive types with the Python module
@@ -28,16 +38,27 @@ pub fn register_primitives(m: &Bound<'_, PyModule>) -> PyResult<()> {
     i32::register_i32(m)?;
     i64::register_i64(m)?;
     i128::register_i128(m)?;
+    i256::register_i256(m)?;
     u8::register_u8(m)?;
     u16::register_u16(m)?;
     u32::register_u32(m)?;
     u64::register_u64(m)?;
     u128::register_u128(m)?;
+    u256::register_u256(m)?;
+    u512::register_u512(m)?;
     isize::register_isize(m)?;
     usize::register_usize(m)?;
     bool::register_bool(m)?;
     char::register_char(m)?;
     f32::register_f32(m)?;
     f64::register_f64(m)?;
+    int::register_int(m)?;
+    num::register_num(m)?;
+    float::register_float(m)?;
+    rational::register_rational(m)?;
+    decimal::register_decimal(m)?;
+    modint::register_modint(m)?;
+    fixed::register_fixed(m)?;
+    complex::register_complex(m)?;
     Ok(())
 }
@@ -0,0 +1,420 @@
+use pyo3::class::basic::CompareOp;
+use pyo3::exceptions::{PyValueError, PyZeroDivisionError};
+use pyo3::prelude::*;
+use pyo3::types::PyTuple;
+use pyo3::IntoPyObjectExt;
+use rug::Integer;
+
+use super::int::{integer_to_py, py_any_to_integer, Int};
+
+/// Montgomery arithmetic for an odd `u64` modulus, used for exponentiation
+struct Montgomery {
+    modulus: u64,
+    /// `modulus**-1 mod 2**64`
+    inv: u64,
+    /// `2**128 mod modulus`
+    r2: u64,
+}
+
+impl Montgomery {
+    fn new(modulus: u64) -> Self {
+        // Newton iteration doubles the number of correct low bits each step
+        let mut inv = modulus;
+        for _ in 0..6 {
+            inv = inv.wrapping_mul(2u64.wrapping_sub(modulus.wrapping_mul(inv)));
+        }
+        let r = (1u128 << 64) % modulus as u128;
+        let r2 = (r * r % modulus as u128) as u64;
+        Montgomery { modulus, inv, r2 }
+    }
+
+    /// `t / 2**64 mod modulus` for `t < modulus * 2**64`
+    fn reduce(&self, t: u128) -> u64 {
+        let q = (t as u64).wrapping_mul(self.inv);
+        let qm_high = ((q as u128 * self.modulus as u128) >> 64) as u64;
+        let (result, borrow) = ((t >> 64) as u64).overflowing_sub(qm_high);
+        if borrow {
+            result.wrapping_add(self.modulus)
+        } else {
+            result
+        }
+    }
+
+    fn to_form(&self, a: u64) -> u64 {
+        self.reduce(a as u128 * self.r2 as u128)
+    }
+
+    fn mul(&self, a: u64, b: u64) -> u64 {
+        self.reduce(a as u128 * b as u128)
+    }
+
+    fn pow(&self, base: u64, mut exponent: u64) -> u64 {
+        let mut base = self.to_form(base);
+        let mut acc = self.to_form(1);
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                acc = self.mul(acc, base);
+            }
+            base = self.mul(base, base);
+            exponent >>= 1;
+        }
+        self.reduce(acc as u128)
+    }
+}
+
+fn mul_mod_u64(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+fn pow_mod_u64(base: u64, mut exponent: u64, m: u64) -> u64 {
+    if m % 2 == 1 {
+        return Montgomery::new(m).pow(base, exponent);
+    }
+    let mut base = base;
+    let mut acc = 1 % m;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            acc = mul_mod_u64(acc, base, m);
+        }
+        base = mul_mod_u64(base, base, m);
+        exponent >>= 1;
+    }
+    acc
+}
+
+fn inverse_u64(a: u64, m: u64) -> Option<u64> {
+    let (mut old_r, mut r) = (a as i128, m as i128);
+    let (mut old_s, mut s) = (1i128, 0i128);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_s, s) = (s, old_s - q * s);
+    }
+    if old_r != 1 {
+        return None;
+    }
+    Some(old_s.rem_euclid(m as i128) as u64)
+}
+
+fn not_invertible() -> PyErr {
+    PyValueError::new_err("Value is not invertible for the given modulus")
+}
+
+/// Residue and modulus, on the `u64` fast path whenever the modulus fits
+#[derive(Clone, PartialEq, Eq)]
+pub enum ModValue {
+    Small { value: u64, modulus: u64 },
+    Big { value: Integer, modulus: Integer },
+}
+
+/// Integer that is always reduced modulo a fixed modulus
+#[pyclass(name="ModInt")]
+#[derive(Clone)]
+pub struct ModInt(pub(crate) ModValue);
+
+impl ModInt {
+    pub(crate) fn from_integers(value: Integer, modulus: Integer) -> PyResult<Self> {
+        if modulus <= 0 {
+            return Err(PyValueError::new_err("Modulus must be positive"));
+        }
+        let value = value.div_rem_euc(modulus.clone()).1;
+        Ok(match (value.to_u64(), modulus.to_u64()) {
+            (Some(value), Some(modulus)) => ModInt(ModValue::Small { value, modulus }),
+            _ => ModInt(ModValue::Big { value, modulus }),
+        })
+    }
+
+    fn value_integer(&self) -> Integer {
+        match &self.0 {
+            ModValue::Small { value, .. } => Integer::from(*value),
+            ModValue::Big { value, .. } => value.clone(),
+        }
+    }
+
+    fn modulus_integer(&self) -> Integer {
+        match &self.0 {
+            ModValue::Small { modulus, .. } => Integer::from(*modulus),
+            ModValue::Big { modulus, .. } => modulus.clone(),
+        }
+    }
+
+    fn same_modulus(&self, other: &ModInt) -> bool {
+        match (&self.0, &other.0) {
+            (ModValue::Small { modulus: a, .. }, ModValue::Small { modulus: b, .. }) => a == b,
+            (ModValue::Big { modulus: a, .. }, ModValue::Big { modulus: b, .. }) => a == b,
+            _ => false,
+        }
+    }
+
+    /// Convert the other operand into this modulus: integers are reduced,
+    /// a `ModInt` must share the modulus, and other types give `None`
+    fn operand(&self, other: &Bound<'_, PyAny>) -> PyResult<Option<ModInt>> {
+        if let Ok(other) = other.downcast::<ModInt>() {
+            let other = other.borrow();
+            if !self.same_modulus(&other) {
+                return Err(PyValueError::new_err(format!(
+                    "Cannot mix moduli {} and {}",
+                    self.modulus_integer(),
+                    other.modulus_integer()
+                )));
+            }
+            return Ok(Some(other.clone()));
+        }
+        match py_any_to_integer(other) {
+            Ok(other) => ModInt::from_integers(other, self.modulus_integer()).map(Some),
+            Err(_) => Ok(None),
+        }
+    }
+
+    fn binary(
+        &self,
+        other: &ModInt,
+        small: impl Fn(u64, u64, u64) -> u64,
+        big: impl Fn(Integer, Integer) -> Integer,
+    ) -> PyResult<Self> {
+        match (&self.0, &other.0) {
+            (ModValue::Small { value: a, modulus }, ModValue::Small { value: b, .. }) => Ok(ModInt(ModValue::Small {
+                value: small(*a, *b, *modulus),
+                modulus: *modulus,
+            })),
+            _ => ModInt::from_integers(big(self.value_integer(), other.value_integer()), self.modulus_integer()),
+        }
+    }
+
+    fn add_value(&self, other: &ModInt) -> PyResult<Self> {
+        self.binary(
+            other,
+            |a, b, m| {
+                let (sum, carry) = a.overflowing_add(b);
+                if carry || sum >= m {
+                    sum.wrapping_sub(m)
+                } else {
+                    sum
+                }
+            },
+            |a, b| a + b,
+        )
+    }
+
+    fn sub_value(&self, other: &ModInt) -> PyResult<Self> {
+        self.binary(
+            other,
+            |a, b, m| if a >= b { a - b } else { a.wrapping_sub(b).wrapping_add(m) },
+            |a, b| a - b,
+        )
+    }
+
+    fn mul_value(&self, other: &ModInt) -> PyResult<Self> {
+        self.binary(other, mul_mod_u64, |a, b| a * b)
+    }
+
+    fn inverse_value(&self) -> PyResult<ModInt> {
+        match &self.0 {
+            ModValue::Small { value, modulus } => inverse_u64(*value, *modulus)
+                .map(|value| ModInt(ModValue::Small { value, modulus: *modulus }))
+                .ok_or_else(not_invertible),
+            ModValue::Big { value, modulus } => value
+                .clone()
+                .invert(modulus)
+                .map(|value| ModInt(ModValue::Big { value, modulus: modulus.clone() }))
+                .map_err(|_| not_invertible()),
+        }
+    }
+}
+
+#[pymethods]
+impl ModInt {
+    #[new]
+    pub fn new(
+        #[pyo3(from_py_with = "py_any_to_integer")] value: Integer,
+        #[pyo3(from_py_with = "py_any_to_integer")] modulus: Integer,
+    ) -> PyResult<Self> {
+        ModInt::from_integers(value, modulus)
+    }
+
+    #[getter]
+    pub fn value(&self, py: Python) -> PyResult<PyObject> {
+        integer_to_py(py, &self.value_integer())
+    }
+
+    #[getter]
+    pub fn modulus(&self, py: Python) -> PyResult<PyObject> {
+        integer_to_py(py, &self.modulus_integer())
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!("ModInt({}, {})", self.value_integer(), self.modulus_integer())
+    }
+
+    pub fn __str__(&self) -> String {
+        self.value_integer().to_string()
+    }
+
+    pub fn __int__(&self, py: Python) -> PyResult<PyObject> {
+        self.value(py)
+    }
+
+    pub fn __bool__(&self) -> bool {
+        self.value_integer() != 0
+    }
+
+    pub fn __hash__(&self, py: Python) -> PyResult<isize> {
+        PyTuple::new(py, [self.value(py)?, self.modulus(py)?])?.hash()
+    }
+
+    /// Equality with a `ModInt` of the same value and modulus. Integers never
+    /// compare equal, since they hash differently from their residues
+    pub fn __richcmp__(&self, py: Python, other: &Bound<'_, PyAny>, op: CompareOp) -> PyResult<PyObject> {
+        let Ok(other) = other.downcast::<ModInt>() else {
+            return Ok(py.NotImplemented());
+        };
+        let equal = self.0 == other.borrow().0;
+        match op {
+            CompareOp::Eq => equal.into_py_any(py),
+            CompareOp::Ne => (!equal).into_py_any(py),
+            _ => Ok(py.NotImplemented()),
+        }
+    }
+
+    pub fn __add__(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        let Some(other) = self.operand(other)? else {
+            return Ok(py.NotImplemented());
+        };
+        self.add_value(&other)?.into_py_any(py)
+    }
+
+    pub fn __radd__(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        self.__add__(py, other)
+    }
+
+    pub fn __sub__(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        let Some(other) = self.operand(other)? else {
+            return Ok(py.NotImplemented());
+        };
+        self.sub_value(&other)?.into_py_any(py)
+    }
+
+    pub fn __rsub__(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        let Some(other) = self.operand(other)? else {
+            return Ok(py.NotImplemented());
+        };
+        other.sub_value(self)?.into_py_any(py)
+    }
+
+    pub fn __mul__(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        let Some(other) = self.operand(other)? else {
+            return Ok(py.NotImplemented());
+        };
+        self.mul_value(&other)?.into_py_any(py)
+    }
+
+    pub fn __rmul__(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        self.__mul__(py, other)
+    }
+
+    /// Multiplication by the modular inverse of `other`
+    pub fn __truediv__(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        let Some(other) = self.operand(other)? else {
+            return Ok(py.NotImplemented());
+        };
+        self.mul_value(&other.inverse_value()?)?.into_py_any(py)
+    }
+
+    pub fn __rtruediv__(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        let Some(other) = self.operand(other)? else {
+            return Ok(py.NotImplemented());
+        };
+        other.mul_value(&self.inverse_value()?)?.into_py_any(py)
+    }
+
+    /// Negative exponents raise the modular inverse
+    pub fn __pow__(&self, #[pyo3(from_py_with = "py_any_to_integer")] exponent: Integer, modulo: Option<&Bound<'_, PyAny>>) -> PyResult<Self> {
+        if modulo.is_some_and(|m| !m.is_none()) {
+            return Err(PyValueError::new_err("ModInt already carries its modulus"));
+        }
+        let (base, exponent) = if exponent < 0 {
+            (self.inverse_value()?, -exponent)
+        } else {
+            (self.clone(), exponent)
+        };
+        match (&base.0, exponent.to_u64()) {
+            (ModValue::Small { value, modulus }, Some(exponent)) => Ok(ModInt(ModValue::Small {
+                value: pow_mod_u64(*value, exponent, *modulus),
+                modulus: *modulus,
+            })),
+            _ => {
+                let modulus = base.modulus_integer();
+                let value = base.value_integer().pow_mod(&exponent, &modulus).map_err(|_| not_invertible())?;
+                ModInt::from_integers(value, modulus)
+            }
+        }
+    }
+
+    pub fn __neg__(&self) -> Self {
+        match &self.0 {
+            ModValue::Small { value, modulus } => ModInt(ModValue::Small {
+                value: if *value == 0 { 0 } else { modulus - value },
+                modulus: *modulus,
+            }),
+            ModValue::Big { value, modulus } => ModInt(ModValue::Big {
+                value: if *value == 0 { Integer::new() } else { Integer::from(modulus - value) },
+                modulus: modulus.clone(),
+            }),
+        }
+    }
+
+    pub fn __pos__(&self) -> Self {
+        self.clone()
+    }
+
+    /// Modular inverse; raises `ValueError` when `gcd(value, modulus) != 1`
+    pub fn inverse(&self) -> PyResult<Self> {
+        self.inverse_value()
+    }
+
+    pub fn to_int(&self) -> Int {
+        Int(self.value_integer())
+    }
+
+    /// Combine residues with the Chinese remainder theorem. Moduli need not be
+    /// coprime, but the residues must then agree on their common factors
+    #[staticmethod]
+    #[pyo3(signature = (*residues))]
+    pub fn crt(residues: Vec<ModInt>) -> PyResult<Self> {
+        let mut residues = residues.into_iter();
+        let first = residues
+            .next()
+            .ok_or_else(|| PyValueError::new_err("crt() requires at least one residue"))?;
+        let (mut a1, mut m1) = (first.value_integer(), first.modulus_integer());
+
+        for residue in residues {
+            let (a2, m2) = (residue.value_integer(), residue.modulus_integer());
+            let g = Integer::from(m1.gcd_ref(&m2));
+            let diff = Integer::from(&a2 - &a1);
+            if !diff.is_divisible(&g) {
+                return Err(PyValueError::new_err(format!(
+                    "Inconsistent residues {} mod {} and {} mod {}",
+                    a1, m1, a2, m2
+                )));
+            }
+            let m2_g = Integer::from(&m2 / &g);
+            let m1_g = Integer::from(&m1 / &g);
+            let inv = if m2_g == 1 {
+                Integer::new()
+            } else {
+                m1_g.invert(&m2_g).map_err(|_| PyZeroDivisionError::new_err("Unexpected non-invertible modulus"))?
+            };
+            let k = (diff / &g * inv).div_rem_euc(m2_g.clone()).1;
+            a1 += k * &m1;
+            m1 *= m2_g;
+            a1 = a1.div_rem_euc(m1.clone()).1;
+        }
+
+        ModInt::from_integers(a1, m1)
+    }
+}
+
+pub fn register_modint(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<ModInt>()?;
+    Ok(())
+}
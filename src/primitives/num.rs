@@ -0,0 +1,377 @@
+use pyo3::class::basic::CompareOp;
+use pyo3::exceptions::{PyOverflowError, PyValueError, PyZeroDivisionError};
+use pyo3::prelude::*;
+use pyo3::types::{PyFloat, PyString};
+use pyo3::IntoPyObjectExt;
+use rug::ops::Pow;
+use rug::{Float, Integer, Rational};
+
+use super::int::{integer_hash, integer_to_py, py_any_to_integer, python_residue, Int};
+
+/// Storage for `num`: an inline `i64`, promoted to a GMP integer only when needed
+#[derive(Clone)]
+pub enum NumValue {
+    Small(i64),
+    Big(Integer),
+}
+
+/// Extract a `num` from a Rustique numeric type or anything implementing `__index__`
+pub(crate) fn py_any_to_num(obj: &Bound<'_, PyAny>) -> PyResult<Num> {
+    if let Ok(n) = obj.downcast::<Num>() {
+        return Ok(n.borrow().clone());
+    }
+
+    if let Ok(i) = obj.extract::<i64>() {
+        return Ok(Num(NumValue::Small(i)));
+    }
+
+    Ok(Num::from_integer(py_any_to_integer(obj)?))
+}
+
+fn floor_div_i64(a: i64, b: i64) -> Option<i64> {
+    let q = a.checked_div(b)?;
+    if a % b != 0 && ((a < 0) != (b < 0)) {
+        Some(q - 1)
+    } else {
+        Some(q)
+    }
+}
+
+fn floor_mod_i64(a: i64, b: i64) -> i64 {
+    // wrapping_rem handles i64::MIN % -1, which is 0
+    let r = a.wrapping_rem(b);
+    if r != 0 && ((r < 0) != (b < 0)) {
+        r + b
+    } else {
+        r
+    }
+}
+
+/// Integer with Python semantics that stays on the `i64` fast path until an
+/// operation overflows
+#[pyclass(name="num")]
+#[derive(Clone)]
+pub struct Num(pub(crate) NumValue);
+
+impl Num {
+    /// Build a `num`, demoting to the inline representation when the value fits
+    pub(crate) fn from_integer(value: Integer) -> Self {
+        match value.to_i64() {
+            Some(small) => Num(NumValue::Small(small)),
+            None => Num(NumValue::Big(value)),
+        }
+    }
+
+    pub(crate) fn to_integer(&self) -> Integer {
+        match &self.0 {
+            NumValue::Small(a) => Integer::from(*a),
+            NumValue::Big(a) => a.clone(),
+        }
+    }
+
+    fn binary_op(
+        &self,
+        other: &Num,
+        small: impl Fn(i64, i64) -> Option<i64>,
+        big: impl Fn(Integer, Integer) -> Integer,
+    ) -> Self {
+        if let (NumValue::Small(a), NumValue::Small(b)) = (&self.0, &other.0) {
+            if let Some(result) = small(*a, *b) {
+                return Num(NumValue::Small(result));
+            }
+        }
+        Num::from_integer(big(self.to_integer(), other.to_integer()))
+    }
+
+    fn is_zero(&self) -> bool {
+        match &self.0 {
+            NumValue::Small(a) => *a == 0,
+            NumValue::Big(a) => *a == 0,
+        }
+    }
+}
+
+#[pymethods]
+impl Num {
+    #[new]
+    #[pyo3(signature = (value=None))]
+    pub fn new(value: Option<&Bound<'_, PyAny>>) -> PyResult<Self> {
+        let Some(value) = value else {
+            return Ok(Num(NumValue::Small(0)));
+        };
+
+        if value.downcast::<PyString>().is_ok() || value.downcast::<PyFloat>().is_ok() {
+            return Ok(Num::from_integer(Int::new(Some(value), None)?.0));
+        }
+
+        py_any_to_num(value)
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!("num({})", self.__str__())
+    }
+
+    pub fn __str__(&self) -> String {
+        match &self.0 {
+            NumValue::Small(a) => a.to_string(),
+            NumValue::Big(a) => a.to_string(),
+        }
+    }
+
+    /// Whether the value is currently stored inline as an `i64`
+    #[getter]
+    pub fn is_small(&self) -> bool {
+        matches!(self.0, NumValue::Small(_))
+    }
+
+    pub fn __hash__(&self) -> isize {
+        integer_hash(&self.to_integer())
+    }
+
+    pub fn __bool__(&self) -> bool {
+        !self.is_zero()
+    }
+
+    pub fn __int__(&self, py: Python) -> PyResult<PyObject> {
+        match &self.0 {
+            NumValue::Small(a) => a.into_py_any(py),
+            NumValue::Big(a) => integer_to_py(py, a),
+        }
+    }
+
+    pub fn __index__(&self, py: Python) -> PyResult<PyObject> {
+        self.__int__(py)
+    }
+
+    pub fn __float__(&self) -> PyResult<f64> {
+        let f = Float::with_val(53, &self.to_integer()).to_f64();
+        if f.is_infinite() {
+            return Err(PyOverflowError::new_err("num too large to convert to float"));
+        }
+        Ok(f)
+    }
+
+    pub fn __richcmp__(&self, py: Python, other: &Bound<'_, PyAny>, op: CompareOp) -> PyResult<PyObject> {
+        let ordering = if let Ok(f) = other.downcast::<PyFloat>() {
+            self.to_integer().partial_cmp(&f.value())
+        } else if let Ok(other) = py_any_to_num(other) {
+            match (&self.0, &other.0) {
+                (NumValue::Small(a), NumValue::Small(b)) => Some(a.cmp(b)),
+                _ => Some(self.to_integer().cmp(&other.to_integer())),
+            }
+        } else {
+            return Ok(py.NotImplemented());
+        };
+
+        let result = match ordering {
+            Some(ordering) => op.matches(ordering),
+            None => matches!(op, CompareOp::Ne),
+        };
+        result.into_py_any(py)
+    }
+
+    pub fn __add__(&self, #[pyo3(from_py_with = "py_any_to_num")] other: Num) -> Self {
+        self.binary_op(&other, i64::checked_add, |a, b| a + b)
+    }
+
+    pub fn __radd__(&self, #[pyo3(from_py_with = "py_any_to_num")] other: Num) -> Self {
+        other.binary_op(self, i64::checked_add, |a, b| a + b)
+    }
+
+    pub fn __sub__(&self, #[pyo3(from_py_with = "py_any_to_num")] other: Num) -> Self {
+        self.binary_op(&other, i64::checked_sub, |a, b| a - b)
+    }
+
+    pub fn __rsub__(&self, #[pyo3(from_py_with = "py_any_to_num")] other: Num) -> Self {
+        other.binary_op(self, i64::checked_sub, |a, b| a - b)
+    }
+
+    pub fn __mul__(&self, #[pyo3(from_py_with = "py_any_to_num")] other: Num) -> Self {
+        self.binary_op(&other, i64::checked_mul, |a, b| a * b)
+    }
+
+    pub fn __rmul__(&self, #[pyo3(from_py_with = "py_any_to_num")] other: Num) -> Self {
+        other.binary_op(self, i64::checked_mul, |a, b| a * b)
+    }
+
+    pub fn __truediv__(&self, #[pyo3(from_py_with = "py_any_to_num")] other: Num) -> PyResult<f64> {
+        if other.is_zero() {
+            return Err(PyZeroDivisionError::new_err("Division by zero"));
+        }
+        let f = Float::with_val(53, Rational::from((self.to_integer(), other.to_integer()))).to_f64();
+        if f.is_infinite() {
+            return Err(PyOverflowError::new_err("Integer division result too large for a float"));
+        }
+        Ok(f)
+    }
+
+    pub fn __rtruediv__(&self, #[pyo3(from_py_with = "py_any_to_num")] other: Num) -> PyResult<f64> {
+        other.__truediv__(self.clone())
+    }
+
+    pub fn __floordiv__(&self, #[pyo3(from_py_with = "py_any_to_num")] other: Num) -> PyResult<Self> {
+        if other.is_zero() {
+            return Err(PyZeroDivisionError::new_err("Division by zero"));
+        }
+        Ok(self.binary_op(&other, floor_div_i64, |a, b| a.div_rem_floor(b).0))
+    }
+
+    pub fn __rfloordiv__(&self, #[pyo3(from_py_with = "py_any_to_num")] other: Num) -> PyResult<Self> {
+        other.__floordiv__(self.clone())
+    }
+
+    pub fn __mod__(&self, #[pyo3(from_py_with = "py_any_to_num")] other: Num) -> PyResult<Self> {
+        if other.is_zero() {
+            return Err(PyZeroDivisionError::new_err("Modulo by zero"));
+        }
+        Ok(self.binary_op(&other, |a, b| Some(floor_mod_i64(a, b)), |a, b| a.div_rem_floor(b).1))
+    }
+
+    pub fn __rmod__(&self, #[pyo3(from_py_with = "py_any_to_num")] other: Num) -> PyResult<Self> {
+        other.__mod__(self.clone())
+    }
+
+    pub fn __divmod__(&self, #[pyo3(from_py_with = "py_any_to_num")] other: Num) -> PyResult<(Self, Self)> {
+        Ok((self.__floordiv__(other.clone())?, self.__mod__(other)?))
+    }
+
+    pub fn __rdivmod__(&self, #[pyo3(from_py_with = "py_any_to_num")] other: Num) -> PyResult<(Self, Self)> {
+        other.__divmod__(self.clone())
+    }
+
+    pub fn __pow__(&self, #[pyo3(from_py_with = "py_any_to_num")] exponent: Num, modulo: Option<&Bound<'_, PyAny>>) -> PyResult<Self> {
+        if let Some(modulo) = modulo.filter(|m| !m.is_none()) {
+            let modulo = py_any_to_integer(modulo)?;
+            if modulo == 0 {
+                return Err(PyZeroDivisionError::new_err("Modulus must not be zero"));
+            }
+            return self
+                .to_integer()
+                .pow_mod(&exponent.to_integer(), &modulo)
+                .map(|r| Num::from_integer(python_residue(r, &modulo)))
+                .map_err(|_| PyValueError::new_err("Base is not invertible for the given modulus"));
+        }
+
+        let exponent = exponent
+            .to_integer()
+            .to_u32()
+            .ok_or_else(|| PyValueError::new_err("Exponent must be a non-negative integer below 2**32"))?;
+        if let NumValue::Small(base) = self.0 {
+            if let Some(result) = base.checked_pow(exponent) {
+                return Ok(Num(NumValue::Small(result)));
+            }
+        }
+        Ok(Num::from_integer(self.to_integer().pow(exponent)))
+    }
+
+    pub fn __rpow__(&self, #[pyo3(from_py_with = "py_any_to_num")] base: Num, modulo: Option<&Bound<'_, PyAny>>) -> PyResult<Self> {
+        base.__pow__(self.clone(), modulo)
+    }
+
+    pub fn __neg__(&self) -> Self {
+        match &self.0 {
+            NumValue::Small(a) => match a.checked_neg() {
+                Some(result) => Num(NumValue::Small(result)),
+                None => Num::from_integer(-Integer::from(*a)),
+            },
+            NumValue::Big(a) => Num::from_integer(-a.clone()),
+        }
+    }
+
+    pub fn __pos__(&self) -> Self {
+        self.clone()
+    }
+
+    pub fn __abs__(&self) -> Self {
+        match &self.0 {
+            NumValue::Small(a) => match a.checked_abs() {
+                Some(result) => Num(NumValue::Small(result)),
+                None => Num::from_integer(Integer::from(*a).abs()),
+            },
+            NumValue::Big(a) => Num::from_integer(a.clone().abs()),
+        }
+    }
+
+    pub fn __invert__(&self) -> Self {
+        match &self.0 {
+            NumValue::Small(a) => Num(NumValue::Small(!a)),
+            NumValue::Big(a) => Num::from_integer(!a.clone()),
+        }
+    }
+
+    pub fn __and__(&self, #[pyo3(from_py_with = "py_any_to_num")] other: Num) -> Self {
+        self.binary_op(&other, |a, b| Some(a & b), |a, b| a & b)
+    }
+
+    pub fn __rand__(&self, #[pyo3(from_py_with = "py_any_to_num")] other: Num) -> Self {
+        self.__and__(other)
+    }
+
+    pub fn __or__(&self, #[pyo3(from_py_with = "py_any_to_num")] other: Num) -> Self {
+        self.binary_op(&other, |a, b| Some(a | b), |a, b| a | b)
+    }
+
+    pub fn __ror__(&self, #[pyo3(from_py_with = "py_any_to_num")] other: Num) -> Self {
+        self.__or__(other)
+    }
+
+    pub fn __xor__(&self, #[pyo3(from_py_with = "py_any_to_num")] other: Num) -> Self {
+        self.binary_op(&other, |a, b| Some(a ^ b), |a, b| a ^ b)
+    }
+
+    pub fn __rxor__(&self, #[pyo3(from_py_with = "py_any_to_num")] other: Num) -> Self {
+        self.__xor__(other)
+    }
+
+    pub fn __lshift__(&self, #[pyo3(from_py_with = "py_any_to_num")] other: Num) -> PyResult<Self> {
+        let shift = other
+            .to_integer()
+            .to_u32()
+            .ok_or_else(|| PyValueError::new_err("Shift count must be a non-negative integer below 2**32"))?;
+        if let NumValue::Small(a) = self.0 {
+            if shift < 64 {
+                let shifted = a << shift;
+                if shifted >> shift == a {
+                    return Ok(Num(NumValue::Small(shifted)));
+                }
+            }
+        }
+        Ok(Num::from_integer(self.to_integer() << shift))
+    }
+
+    pub fn __rlshift__(&self, #[pyo3(from_py_with = "py_any_to_num")] other: Num) -> PyResult<Self> {
+        other.__lshift__(self.clone())
+    }
+
+    pub fn __rshift__(&self, #[pyo3(from_py_with = "py_any_to_num")] other: Num) -> PyResult<Self> {
+        let shift = other
+            .to_integer()
+            .to_u32()
+            .ok_or_else(|| PyValueError::new_err("Shift count must be a non-negative integer below 2**32"))?;
+        match &self.0 {
+            NumValue::Small(a) => Ok(Num(NumValue::Small(a >> shift.min(63)))),
+            NumValue::Big(a) => Ok(Num::from_integer(a.clone() >> shift)),
+        }
+    }
+
+    pub fn __rrshift__(&self, #[pyo3(from_py_with = "py_any_to_num")] other: Num) -> PyResult<Self> {
+        other.__rshift__(self.clone())
+    }
+
+    pub fn bit_length(&self) -> u32 {
+        match &self.0 {
+            NumValue::Small(a) => 64 - a.unsigned_abs().leading_zeros(),
+            NumValue::Big(a) => a.significant_bits(),
+        }
+    }
+
+    /// Convert to the arbitrary-precision `int`
+    pub fn to_int(&self) -> Int {
+        Int(self.to_integer())
+    }
+}
+
+pub fn register_num(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Num>()?;
+    Ok(())
+}
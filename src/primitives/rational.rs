@@ -0,0 +1,449 @@
+use std::cmp::Ordering;
+
+use pyo3::class::basic::CompareOp;
+use pyo3::exceptions::{PyOverflowError, PyTypeError, PyValueError, PyZeroDivisionError};
+use pyo3::prelude::*;
+use pyo3::sync::GILOnceCell;
+use pyo3::types::{PyFloat, PyString, PyType};
+use pyo3::IntoPyObjectExt;
+use rug::ops::Pow;
+use rug::{Float, Integer, Rational as MpRational};
+
+use super::int::{integer_to_py, py_any_to_integer, rational_hash, Int};
+
+static NUMBERS_RATIONAL: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+static FRACTION: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+/// Largest exponent accepted in a literal, so that a short string such as
+/// `"1e999999999"` cannot expand into an enormous power of ten
+const MAX_EXPONENT: u64 = 100_000;
+
+/// Correctly rounded conversion to `f64`
+pub(crate) fn rational_to_f64(value: &MpRational) -> f64 {
+    Float::with_val(53, value).to_f64()
+}
+
+/// Parse `"3/4"`, `"-7"`, `"1.25"` or `"6.02e23"` into an exact rational
+fn parse_rational(s: &str) -> PyResult<MpRational> {
+    let s = s.trim().replace('_', "");
+    let invalid = || PyValueError::new_err(format!("Invalid literal for Rational: {:?}", s));
+
+    if s.contains('/') {
+        return MpRational::from_str_radix(&s, 10).map_err(|_| invalid());
+    }
+
+    let (mantissa, exponent) = match s.find(['e', 'E']) {
+        Some(pos) => (&s[..pos], s[pos + 1..].parse::<i64>().map_err(|_| invalid())?),
+        None => (&s[..], 0),
+    };
+    if exponent.unsigned_abs() > MAX_EXPONENT {
+        return Err(PyValueError::new_err(format!(
+            "Exponent of Rational literal must be at most {} in magnitude",
+            MAX_EXPONENT
+        )));
+    }
+    let (int_part, frac_part) = match mantissa.find('.') {
+        Some(pos) => (&mantissa[..pos], &mantissa[pos + 1..]),
+        None => (mantissa, ""),
+    };
+    if !frac_part.chars().all(|c| c.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    let digits = format!("{}{}", int_part, frac_part);
+    let digits = match digits.as_str() {
+        "" | "+" | "-" => return Err(invalid()),
+        d => Integer::from_str_radix(d, 10).map_err(|_| invalid())?,
+    };
+    let scale = exponent - frac_part.len() as i64;
+    let ten = MpRational::from(10);
+    Ok(MpRational::from(digits) * ten.pow(scale as i32))
+}
+
+/// Extract an exact rational from a Rustique `Rational`, an integer type or a
+/// `numbers.Rational` such as `fractions.Fraction`
+pub(crate) fn py_any_to_rational(obj: &Bound<'_, PyAny>) -> PyResult<MpRational> {
+    if let Ok(r) = obj.downcast::<Rational>() {
+        return Ok(r.borrow().0.clone());
+    }
+
+    if let Ok(i) = py_any_to_integer(obj) {
+        return Ok(MpRational::from(i));
+    }
+
+    let py = obj.py();
+    let numbers_rational = NUMBERS_RATIONAL.import(py, "numbers", "Rational")?;
+    if obj.is_instance(numbers_rational)? {
+        let numer = py_any_to_integer(&obj.getattr("numerator")?)?;
+        let denom = py_any_to_integer(&obj.getattr("denominator")?)?;
+        return Ok(MpRational::from((numer, denom)));
+    }
+
+    Err(PyTypeError::new_err(format!(
+        "Could not convert {} to Rational",
+        obj.get_type().name()?
+    )))
+}
+
+fn check_nonzero(value: &MpRational) -> PyResult<()> {
+    if value.is_zero() {
+        return Err(PyZeroDivisionError::new_err("Division by zero"));
+    }
+    Ok(())
+}
+
+/// Exact rational number backed by GMP
+#[pyclass(name="Rational")]
+#[derive(Clone)]
+pub struct Rational(pub(crate) MpRational);
+
+impl Rational {
+    /// Apply `op` to two rationals, or fall back to `f64` arithmetic when the
+    /// other operand is a Python float, like `fractions.Fraction` does
+    fn binary(
+        &self,
+        py: Python,
+        other: &Bound<'_, PyAny>,
+        op: impl Fn(MpRational, MpRational) -> PyResult<MpRational>,
+        float_op: impl Fn(f64, f64) -> PyResult<f64>,
+    ) -> PyResult<PyObject> {
+        if let Ok(f) = other.downcast::<PyFloat>() {
+            return float_op(rational_to_f64(&self.0), f.value())?.into_py_any(py);
+        }
+        match py_any_to_rational(other) {
+            Ok(other) => Rational(op(self.0.clone(), other)?).into_py_any(py),
+            Err(_) => Ok(py.NotImplemented()),
+        }
+    }
+
+    /// Round half to even at an integer boundary
+    pub(crate) fn round_half_even(value: &MpRational) -> Integer {
+        let (fract, floor) = value.clone().fract_floor(Integer::new());
+        match fract.cmp(&MpRational::from((1, 2))) {
+            Ordering::Less => floor,
+            Ordering::Greater => floor + 1,
+            Ordering::Equal if floor.is_even() => floor,
+            Ordering::Equal => floor + 1,
+        }
+    }
+}
+
+fn floordiv(a: MpRational, b: MpRational) -> PyResult<MpRational> {
+    check_nonzero(&b)?;
+    Ok(MpRational::from((a / b).floor()))
+}
+
+fn modulo(a: MpRational, b: MpRational) -> PyResult<MpRational> {
+    check_nonzero(&b)?;
+    let quotient = MpRational::from(&a / &b).floor();
+    Ok(a - b * quotient)
+}
+
+fn pow_int(base: &MpRational, exponent: &Integer) -> PyResult<MpRational> {
+    let exponent = exponent
+        .to_i32()
+        .ok_or_else(|| PyOverflowError::new_err("Exponent too large"))?;
+    if exponent < 0 {
+        check_nonzero(base)?;
+    }
+    Ok(base.clone().pow(exponent))
+}
+
+fn float_div(a: f64, b: f64) -> PyResult<f64> {
+    if b == 0.0 {
+        return Err(PyZeroDivisionError::new_err("Division by zero"));
+    }
+    Ok(a / b)
+}
+
+fn float_mod(a: f64, b: f64) -> PyResult<f64> {
+    if b == 0.0 {
+        return Err(PyZeroDivisionError::new_err("Modulo by zero"));
+    }
+    let r = a % b;
+    Ok(if r != 0.0 && ((r < 0.0) != (b < 0.0)) { r + b } else { r })
+}
+
+#[pymethods]
+impl Rational {
+    #[new]
+    #[pyo3(signature = (numerator=None, denominator=None))]
+    pub fn new(numerator: Option<&Bound<'_, PyAny>>, denominator: Option<&Bound<'_, PyAny>>) -> PyResult<Self> {
+        let numer = match numerator {
+            None => MpRational::new(),
+            Some(n) => {
+                if let Ok(s) = n.downcast::<PyString>() {
+                    parse_rational(s.to_str()?)?
+                } else if let Ok(f) = n.downcast::<PyFloat>() {
+                    Rational::from_float(f.value())?.0
+                } else {
+                    py_any_to_rational(n)?
+                }
+            }
+        };
+
+        match denominator {
+            None => Ok(Rational(numer)),
+            Some(d) => {
+                let denom = py_any_to_rational(d)?;
+                check_nonzero(&denom)?;
+                Ok(Rational(numer / denom))
+            }
+        }
+    }
+
+    /// Exact conversion from a float
+    #[staticmethod]
+    pub fn from_float(value: f64) -> PyResult<Self> {
+        MpRational::from_f64(value).map(Rational).ok_or_else(|| {
+            if value.is_nan() {
+                PyValueError::new_err("Cannot convert NaN to Rational")
+            } else {
+                PyOverflowError::new_err("Cannot convert infinity to Rational")
+            }
+        })
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!("Rational({}, {})", self.0.numer(), self.0.denom())
+    }
+
+    pub fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    pub fn __hash__(&self) -> isize {
+        rational_hash(&self.0)
+    }
+
+    #[getter]
+    pub fn numer(&self) -> Int {
+        Int(self.0.numer().clone())
+    }
+
+    #[getter]
+    pub fn denom(&self) -> Int {
+        Int(self.0.denom().clone())
+    }
+
+    /// `numbers.Rational` protocol, returning Python ints like `Fraction`
+    #[getter]
+    pub fn numerator(&self, py: Python) -> PyResult<PyObject> {
+        integer_to_py(py, self.0.numer())
+    }
+
+    #[getter]
+    pub fn denominator(&self, py: Python) -> PyResult<PyObject> {
+        integer_to_py(py, self.0.denom())
+    }
+
+    pub fn as_integer_ratio(&self, py: Python) -> PyResult<(PyObject, PyObject)> {
+        Ok((integer_to_py(py, self.0.numer())?, integer_to_py(py, self.0.denom())?))
+    }
+
+    pub fn to_fraction(&self, py: Python) -> PyResult<PyObject> {
+        let fraction = FRACTION.import(py, "fractions", "Fraction")?;
+        Ok(fraction.call1(self.as_integer_ratio(py)?)?.unbind())
+    }
+
+    pub fn __bool__(&self) -> bool {
+        !self.0.is_zero()
+    }
+
+    pub fn __float__(&self) -> f64 {
+        rational_to_f64(&self.0)
+    }
+
+    pub fn __int__(&self, py: Python) -> PyResult<PyObject> {
+        integer_to_py(py, &self.0.clone().trunc().into_numer_denom().0)
+    }
+
+    pub fn __trunc__(&self, py: Python) -> PyResult<PyObject> {
+        self.__int__(py)
+    }
+
+    pub fn __floor__(&self, py: Python) -> PyResult<PyObject> {
+        integer_to_py(py, &self.0.clone().floor().into_numer_denom().0)
+    }
+
+    pub fn __ceil__(&self, py: Python) -> PyResult<PyObject> {
+        integer_to_py(py, &self.0.clone().ceil().into_numer_denom().0)
+    }
+
+    /// Round half to even; with `ndigits`, round to a multiple of `10**-ndigits`
+    #[pyo3(signature = (ndigits=None))]
+    pub fn __round__(&self, py: Python, ndigits: Option<i32>) -> PyResult<PyObject> {
+        match ndigits {
+            None => integer_to_py(py, &Rational::round_half_even(&self.0)),
+            Some(n) => {
+                let shift = MpRational::from(10).pow(n);
+                let rounded = Rational::round_half_even(&(self.0.clone() * &shift));
+                Rational(MpRational::from(rounded) / shift).into_py_any(py)
+            }
+        }
+    }
+
+    /// Closest rational with a denominator of at most `max_denominator`
+    #[pyo3(signature = (max_denominator=None))]
+    pub fn limit_denominator(&self, max_denominator: Option<&Bound<'_, PyAny>>) -> PyResult<Self> {
+        let max_denominator = match max_denominator {
+            Some(m) => py_any_to_integer(m)?,
+            None => Integer::from(1_000_000),
+        };
+        if max_denominator < 1 {
+            return Err(PyValueError::new_err("max_denominator should be at least 1"));
+        }
+        if *self.0.denom() <= max_denominator {
+            return Ok(self.clone());
+        }
+
+        // Continued-fraction walk, as in CPython's fractions module
+        let (mut p0, mut q0, mut p1, mut q1) = (Integer::from(0), Integer::from(1), Integer::from(1), Integer::from(0));
+        let (mut n, mut d) = (self.0.numer().clone(), self.0.denom().clone());
+        loop {
+            let a = n.clone().div_rem_floor(d.clone()).0;
+            let q2 = Integer::from(&q0 + &a * &q1);
+            if q2 > max_denominator {
+                break;
+            }
+            let p2 = Integer::from(&p0 + &a * &p1);
+            (p0, q0, p1, q1) = (p1, q1, p2, q2);
+            let r = Integer::from(&n - &a * &d);
+            (n, d) = (d, r);
+        }
+        let k = Integer::from(&max_denominator - &q0).div_rem_floor(q1.clone()).0;
+        let bound1 = MpRational::from((Integer::from(&p0 + &k * &p1), Integer::from(&q0 + &k * &q1)));
+        let bound2 = MpRational::from((p1, q1));
+        let dist1 = MpRational::from(&bound1 - &self.0).abs();
+        let dist2 = MpRational::from(&bound2 - &self.0).abs();
+        Ok(Rational(if dist2 <= dist1 { bound2 } else { bound1 }))
+    }
+
+    pub fn __richcmp__(&self, py: Python, other: &Bound<'_, PyAny>, op: CompareOp) -> PyResult<PyObject> {
+        let ordering = if let Ok(f) = other.downcast::<PyFloat>() {
+            let f = f.value();
+            if f.is_nan() {
+                None
+            } else if f.is_infinite() {
+                Some(if f > 0.0 { Ordering::Less } else { Ordering::Greater })
+            } else {
+                MpRational::from_f64(f).map(|f| self.0.cmp(&f))
+            }
+        } else if let Ok(other) = py_any_to_rational(other) {
+            Some(self.0.cmp(&other))
+        } else {
+            return Ok(py.NotImplemented());
+        };
+
+        let result = match ordering {
+            Some(ordering) => op.matches(ordering),
+            None => matches!(op, CompareOp::Ne),
+        };
+        result.into_py_any(py)
+    }
+
+    pub fn __add__(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        self.binary(py, other, |a, b| Ok(a + b), |a, b| Ok(a + b))
+    }
+
+    pub fn __radd__(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        self.binary(py, other, |a, b| Ok(b + a), |a, b| Ok(b + a))
+    }
+
+    pub fn __sub__(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        self.binary(py, other, |a, b| Ok(a - b), |a, b| Ok(a - b))
+    }
+
+    pub fn __rsub__(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        self.binary(py, other, |a, b| Ok(b - a), |a, b| Ok(b - a))
+    }
+
+    pub fn __mul__(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        self.binary(py, other, |a, b| Ok(a * b), |a, b| Ok(a * b))
+    }
+
+    pub fn __rmul__(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        self.binary(py, other, |a, b| Ok(b * a), |a, b| Ok(b * a))
+    }
+
+    pub fn __truediv__(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        self.binary(py, other, |a, b| check_nonzero(&b).map(|_| a / b), float_div)
+    }
+
+    pub fn __rtruediv__(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        self.binary(py, other, |a, b| check_nonzero(&a).map(|_| b / a), |a, b| float_div(b, a))
+    }
+
+    pub fn __floordiv__(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        self.binary(py, other, floordiv, |a, b| float_div(a, b).map(f64::floor))
+    }
+
+    pub fn __rfloordiv__(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        self.binary(py, other, |a, b| floordiv(b, a), |a, b| float_div(b, a).map(f64::floor))
+    }
+
+    pub fn __mod__(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        self.binary(py, other, modulo, float_mod)
+    }
+
+    pub fn __rmod__(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        self.binary(py, other, |a, b| modulo(b, a), |a, b| float_mod(b, a))
+    }
+
+    pub fn __divmod__(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<(PyObject, PyObject)> {
+        Ok((self.__floordiv__(py, other)?, self.__mod__(py, other)?))
+    }
+
+    /// Integer exponents stay exact; any other exponent falls back to floats
+    pub fn __pow__(&self, py: Python, other: &Bound<'_, PyAny>, modulo: Option<&Bound<'_, PyAny>>) -> PyResult<PyObject> {
+        if modulo.is_some_and(|m| !m.is_none()) {
+            return Err(PyTypeError::new_err("pow() 3rd argument not allowed for Rational"));
+        }
+        if let Ok(exponent) = py_any_to_rational(other) {
+            if *exponent.denom() == 1 {
+                return Rational(pow_int(&self.0, exponent.numer())?).into_py_any(py);
+            }
+            return rational_to_f64(&self.0).powf(rational_to_f64(&exponent)).into_py_any(py);
+        }
+        if let Ok(f) = other.downcast::<PyFloat>() {
+            return rational_to_f64(&self.0).powf(f.value()).into_py_any(py);
+        }
+        Ok(py.NotImplemented())
+    }
+
+    pub fn __rpow__(&self, py: Python, other: &Bound<'_, PyAny>, _modulo: Option<&Bound<'_, PyAny>>) -> PyResult<PyObject> {
+        if *self.0.denom() == 1 {
+            if let Ok(base) = py_any_to_rational(other) {
+                return Rational(pow_int(&base, self.0.numer())?).into_py_any(py);
+            }
+        }
+        let base = match other.downcast::<PyFloat>() {
+            Ok(f) => f.value(),
+            Err(_) => match py_any_to_rational(other) {
+                Ok(r) => rational_to_f64(&r),
+                Err(_) => return Ok(py.NotImplemented()),
+            },
+        };
+        base.powf(rational_to_f64(&self.0)).into_py_any(py)
+    }
+
+    pub fn __neg__(&self) -> Self {
+        Rational(-self.0.clone())
+    }
+
+    pub fn __pos__(&self) -> Self {
+        self.clone()
+    }
+
+    pub fn __abs__(&self) -> Self {
+        Rational(self.0.clone().abs())
+    }
+}
+
+pub fn register_rational(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Rational>()?;
+    // Let `fractions.Fraction` and `numbers` based code treat Rational as a rational number
+    let py = m.py();
+    NUMBERS_RATIONAL
+        .import(py, "numbers", "Rational")?
+        .call_method1("register", (py.get_type::<Rational>(),))?;
+    Ok(())
+}
@@ -2,7 +2,7 @@ use pyo3::prelude::*;
 
 #[pyclass(name="u128")]
 pub struct U128 {
-    value: u128,
+    pub(crate) value: u128,
 }
 
 #[pymethods]
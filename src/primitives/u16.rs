@@ -2,7 +2,7 @@ use pyo3::prelude::*;
 
 #[pyclass(name="u16")]
 pub struct U16 {
-    value: u16,
+    pub(crate) value: u16,
 }
 
 #[pymethods]
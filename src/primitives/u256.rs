@@ -0,0 +1,10 @@
+use pyo3::prelude::*;
+
+use super::wide::wide_int;
+
+wide_int!(U256, "u256", 4, false);
+
+pub fn register_u256(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<U256>()?;
+    Ok(())
+}
@@ -2,7 +2,7 @@ use pyo3::prelude::*;
 
 #[pyclass(name="u32")]
 pub struct U32 {
-    value: u32,
+    pub(crate) value: u32,
 }
 
 #[pymethods]
@@ -0,0 +1,10 @@
+use pyo3::prelude::*;
+
+use super::wide::wide_int;
+
+wide_int!(U512, "u512", 8, false);
+
+pub fn register_u512(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<U512>()?;
+    Ok(())
+}
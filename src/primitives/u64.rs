@@ -2,7 +2,7 @@ use pyo3::prelude::*;
 
 #[pyclass(name="u64")]
 pub struct U64 {
-    value: u64,
+    pub(crate) value: u64,
 }
 
 #[pymethods]
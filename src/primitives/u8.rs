@@ -2,7 +2,7 @@ use pyo3::prelude::*;
 
 #[pyclass(name="u8")]
 pub struct U8 {
-    value: u8,
+    pub(crate) value: u8,
 }
 
 #[pymethods]
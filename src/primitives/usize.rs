@@ -2,7 +2,7 @@ use pyo3::prelude::*;
 
 #[pyclass(name="usize")]
 pub struct Usize {
-    value: usize,
+    pub(crate) value: usize,
 }
 
 #[pymethods]
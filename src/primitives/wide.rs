@@ -0,0 +1,904 @@
+use std::cmp::Ordering;
+
+use rug::integer::Order;
+use rug::Integer;
+
+/// Fixed-width integer stored as `N` little-endian 64-bit limbs.
+///
+/// The same bits back both the unsigned and the two's-complement signed
+/// types; operations whose result depends on signedness take a `signed` flag.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Uint<const N: usize>(pub [u64; N]);
+
+impl<const N: usize> Uint<N> {
+    pub const BITS: u32 = 64 * N as u32;
+    pub const ZERO: Self = Uint([0; N]);
+    pub const MAX: Self = Uint([u64::MAX; N]);
+
+    pub fn one() -> Self {
+        let mut limbs = [0; N];
+        limbs[0] = 1;
+        Uint(limbs)
+    }
+
+    pub fn min_value(signed: bool) -> Self {
+        if signed {
+            let mut limbs = [0; N];
+            limbs[N - 1] = 1 << 63;
+            Uint(limbs)
+        } else {
+            Self::ZERO
+        }
+    }
+
+    pub fn max_value(signed: bool) -> Self {
+        if signed {
+            let mut limbs = [u64::MAX; N];
+            limbs[N - 1] = u64::MAX >> 1;
+            Uint(limbs)
+        } else {
+            Self::MAX
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0.iter().all(|&limb| limb == 0)
+    }
+
+    pub fn is_negative(&self, signed: bool) -> bool {
+        signed && self.0[N - 1] >> 63 == 1
+    }
+
+    pub fn cmp(&self, other: &Self, signed: bool) -> Ordering {
+        match (self.is_negative(signed), other.is_negative(signed)) {
+            (true, false) => return Ordering::Less,
+            (false, true) => return Ordering::Greater,
+            _ => {}
+        }
+        self.0.iter().rev().cmp(other.0.iter().rev())
+    }
+
+    pub fn not(self) -> Self {
+        Uint(self.0.map(|limb| !limb))
+    }
+
+    pub fn and(self, other: Self) -> Self {
+        let mut limbs = self.0;
+        limbs.iter_mut().zip(other.0).for_each(|(a, b)| *a &= b);
+        Uint(limbs)
+    }
+
+    pub fn or(self, other: Self) -> Self {
+        let mut limbs = self.0;
+        limbs.iter_mut().zip(other.0).for_each(|(a, b)| *a |= b);
+        Uint(limbs)
+    }
+
+    pub fn xor(self, other: Self) -> Self {
+        let mut limbs = self.0;
+        limbs.iter_mut().zip(other.0).for_each(|(a, b)| *a ^= b);
+        Uint(limbs)
+    }
+
+    pub fn count_ones(&self) -> u32 {
+        self.0.iter().map(|limb| limb.count_ones()).sum()
+    }
+
+    pub fn leading_zeros(&self) -> u32 {
+        let mut zeros = 0;
+        for limb in self.0.iter().rev() {
+            zeros += limb.leading_zeros();
+            if *limb != 0 {
+                break;
+            }
+        }
+        zeros
+    }
+
+    pub fn trailing_zeros(&self) -> u32 {
+        let mut zeros = 0;
+        for limb in self.0.iter() {
+            zeros += limb.trailing_zeros();
+            if *limb != 0 {
+                break;
+            }
+        }
+        zeros
+    }
+
+    /// Number of bits needed to represent the magnitude, like `int.bit_length`
+    pub fn bit_length(&self, signed: bool) -> u32 {
+        let magnitude = if self.is_negative(signed) { self.wrapping_neg() } else { *self };
+        Self::BITS - magnitude.leading_zeros()
+    }
+
+    /// Shift left by `shift < BITS` bits
+    pub fn shl(self, shift: u32) -> Self {
+        let (limb_shift, bit_shift) = ((shift / 64) as usize, shift % 64);
+        let mut limbs = [0; N];
+        for i in (limb_shift..N).rev() {
+            limbs[i] = self.0[i - limb_shift] << bit_shift;
+            if bit_shift > 0 && i > limb_shift {
+                limbs[i] |= self.0[i - limb_shift - 1] >> (64 - bit_shift);
+            }
+        }
+        Uint(limbs)
+    }
+
+    /// Shift right by `shift < BITS` bits, arithmetic when `signed`
+    pub fn shr(self, shift: u32, signed: bool) -> Self {
+        let fill = if self.is_negative(signed) { u64::MAX } else { 0 };
+        let (limb_shift, bit_shift) = ((shift / 64) as usize, shift % 64);
+        let mut limbs = [fill; N];
+        for (i, limb) in limbs.iter_mut().enumerate().take(N - limb_shift) {
+            *limb = self.0[i + limb_shift] >> bit_shift;
+            if bit_shift > 0 {
+                let next = if i + limb_shift + 1 < N { self.0[i + limb_shift + 1] } else { fill };
+                *limb |= next << (64 - bit_shift);
+            }
+        }
+        Uint(limbs)
+    }
+
+    pub fn rotate_left(self, n: u32) -> Self {
+        let n = n % Self::BITS;
+        if n == 0 {
+            return self;
+        }
+        self.shl(n).or(self.shr(Self::BITS - n, false))
+    }
+
+    pub fn rotate_right(self, n: u32) -> Self {
+        self.rotate_left(Self::BITS - n % Self::BITS)
+    }
+
+    pub fn swap_bytes(self) -> Self {
+        let mut limbs = self.0;
+        limbs.reverse();
+        Uint(limbs.map(u64::swap_bytes))
+    }
+
+    pub fn wrapping_neg(self) -> Self {
+        self.not().add(Self::one(), false).0
+    }
+
+    pub fn neg(self, signed: bool) -> (Self, bool) {
+        let overflow = if signed { self == Self::min_value(true) } else { !self.is_zero() };
+        (self.wrapping_neg(), overflow)
+    }
+
+    pub fn abs(self, signed: bool) -> (Self, bool) {
+        if self.is_negative(signed) {
+            self.neg(signed)
+        } else {
+            (self, false)
+        }
+    }
+
+    pub fn add(self, other: Self, signed: bool) -> (Self, bool) {
+        let mut limbs = [0; N];
+        let mut carry = false;
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let (sum, c1) = self.0[i].overflowing_add(other.0[i]);
+            let (sum, c2) = sum.overflowing_add(carry as u64);
+            *limb = sum;
+            carry = c1 || c2;
+        }
+        let result = Uint(limbs);
+        let overflow = if signed {
+            self.is_negative(true) == other.is_negative(true) && result.is_negative(true) != self.is_negative(true)
+        } else {
+            carry
+        };
+        (result, overflow)
+    }
+
+    pub fn sub(self, other: Self, signed: bool) -> (Self, bool) {
+        let mut limbs = [0; N];
+        let mut borrow = false;
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let (diff, b1) = self.0[i].overflowing_sub(other.0[i]);
+            let (diff, b2) = diff.overflowing_sub(borrow as u64);
+            *limb = diff;
+            borrow = b1 || b2;
+        }
+        let result = Uint(limbs);
+        let overflow = if signed {
+            self.is_negative(true) != other.is_negative(true) && result.is_negative(true) != self.is_negative(true)
+        } else {
+            borrow
+        };
+        (result, overflow)
+    }
+
+    /// Truncated product and whether the unsigned product exceeded `N` limbs
+    fn mul_unsigned(self, other: Self) -> (Self, bool) {
+        let mut limbs = [0; N];
+        let mut overflow = false;
+        for i in 0..N {
+            if self.0[i] == 0 {
+                continue;
+            }
+            let mut carry = 0u128;
+            for j in 0..N - i {
+                let t = self.0[i] as u128 * other.0[j] as u128 + limbs[i + j] as u128 + carry;
+                limbs[i + j] = t as u64;
+                carry = t >> 64;
+            }
+            overflow |= carry != 0 || other.0[N - i..].iter().any(|&limb| limb != 0);
+        }
+        (Uint(limbs), overflow)
+    }
+
+    pub fn mul(self, other: Self, signed: bool) -> (Self, bool) {
+        if !signed {
+            return self.mul_unsigned(other);
+        }
+        let negative = self.is_negative(true) != other.is_negative(true);
+        let (magnitude, overflow) = self.abs(true).0.mul_unsigned(other.abs(true).0);
+        let limit = if negative { Self::min_value(true) } else { Self::max_value(true) };
+        let overflow = overflow || magnitude.cmp(&limit, false) == Ordering::Greater;
+        let result = if negative { magnitude.wrapping_neg() } else { magnitude };
+        (result, overflow)
+    }
+
+    fn div_rem_unsigned(self, divisor: Self) -> (Self, Self) {
+        let mut quotient = Self::ZERO;
+        let mut remainder = Self::ZERO;
+        let bits = Self::BITS - self.leading_zeros();
+        for bit in (0..bits).rev() {
+            remainder = remainder.shl(1);
+            remainder.0[0] |= (self.0[(bit / 64) as usize] >> (bit % 64)) & 1;
+            if remainder.cmp(&divisor, false) != Ordering::Less {
+                remainder = remainder.sub(divisor, false).0;
+                quotient.0[(bit / 64) as usize] |= 1 << (bit % 64);
+            }
+        }
+        (quotient, remainder)
+    }
+
+    /// Truncating division; `None` on division by zero. Overflows only for
+    /// `MIN / -1`, where the wrapped quotient is `MIN` and the remainder zero
+    pub fn div_rem(self, divisor: Self, signed: bool) -> Option<(Self, Self, bool)> {
+        if divisor.is_zero() {
+            return None;
+        }
+        let (quotient, remainder) = self.abs(signed).0.div_rem_unsigned(divisor.abs(signed).0);
+        let quotient_negative = self.is_negative(signed) != divisor.is_negative(signed);
+        let quotient = if quotient_negative { quotient.wrapping_neg() } else { quotient };
+        let remainder = if self.is_negative(signed) { remainder.wrapping_neg() } else { remainder };
+        let overflow = signed && self == Self::min_value(true) && divisor == Self::MAX;
+        Some((quotient, remainder, overflow))
+    }
+
+    /// Floor division and modulo with Python semantics
+    pub fn div_mod_floor(self, divisor: Self, signed: bool) -> Option<(Self, Self, bool)> {
+        let (quotient, remainder, overflow) = self.div_rem(divisor, signed)?;
+        if !remainder.is_zero() && remainder.is_negative(signed) != divisor.is_negative(signed) {
+            let quotient = quotient.sub(Self::one(), false).0;
+            let remainder = remainder.add(divisor, false).0;
+            return Some((quotient, remainder, overflow));
+        }
+        Some((quotient, remainder, overflow))
+    }
+
+    pub fn pow(self, mut exponent: u32, signed: bool) -> (Self, bool) {
+        let mut base = self;
+        let mut acc = Self::one();
+        let mut overflow = false;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                let (product, o) = acc.mul(base, signed);
+                acc = product;
+                overflow |= o;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                let (square, o) = base.mul(base, signed);
+                base = square;
+                overflow |= o;
+            }
+        }
+        (acc, overflow)
+    }
+
+    pub fn to_integer(self, signed: bool) -> Integer {
+        let value = Integer::from_digits(&self.0, Order::Lsf);
+        if self.is_negative(signed) {
+            value - (Integer::from(1) << Self::BITS)
+        } else {
+            value
+        }
+    }
+
+    /// Exact conversion; `None` when `value` does not fit
+    pub fn from_integer(value: &Integer, signed: bool) -> Option<Self> {
+        let fits = if signed {
+            value.signed_bits() <= Self::BITS
+        } else {
+            value.cmp0() != Ordering::Less && value.significant_bits() <= Self::BITS
+        };
+        fits.then(|| Self::wrapping_from_integer(value))
+    }
+
+    /// Keep the low `BITS` bits of `value` in two's complement
+    pub fn wrapping_from_integer(value: &Integer) -> Self {
+        let value = Integer::from(value.keep_bits_ref(Self::BITS));
+        let mut limbs = [0; N];
+        let digits = value.to_digits::<u64>(Order::Lsf);
+        limbs[..digits.len()].copy_from_slice(&digits);
+        Uint(limbs)
+    }
+
+    pub fn to_be_bytes(self) -> Vec<u8> {
+        self.0.iter().rev().flat_map(|limb| limb.to_be_bytes()).collect()
+    }
+
+    pub fn to_le_bytes(self) -> Vec<u8> {
+        self.0.iter().flat_map(|limb| limb.to_le_bytes()).collect()
+    }
+
+    /// `None` unless `bytes` is exactly `8 * N` long
+    pub fn from_be_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut bytes = bytes.to_vec();
+        bytes.reverse();
+        Self::from_le_bytes(&bytes)
+    }
+
+    pub fn from_le_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != 8 * N {
+            return None;
+        }
+        let mut limbs = [0; N];
+        for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks_exact(8)) {
+            *limb = u64::from_le_bytes(chunk.try_into().unwrap());
+        }
+        Some(Uint(limbs))
+    }
+
+    /// Zero-padded two's-complement hex digits without prefix
+    pub fn to_hex(self) -> String {
+        self.0.iter().rev().map(|limb| format!("{:016x}", limb)).collect()
+    }
+}
+
+/// Define a Python class for a wide integer backed by `Uint<$limbs>`.
+///
+/// Arithmetic operators and the `checked_` methods raise `OverflowError`, and
+/// the `wrapping_`, `overflowing_` and `saturating_` methods mirror Rust's
+/// integer API. Floor division and `%` follow Python semantics, `*_rem`
+/// truncates like Rust.
+macro_rules! wide_int {
+    ($name:ident, $py_name:literal, $limbs:literal, $signed:literal) => {
+        use pyo3::class::basic::CompareOp;
+        use pyo3::exceptions::{PyOverflowError, PyValueError, PyZeroDivisionError};
+        use pyo3::types::{PyBytes, PyInt, PyString};
+        use pyo3::{IntoPyObjectExt, PyTypeInfo};
+        use rug::{Integer, Rational};
+
+        use super::int::{integer_hash, integer_to_py, py_any_to_integer};
+        use super::rational::rational_to_f64;
+        use super::wide::Uint;
+
+        fn py_any_to_wide(obj: &Bound<'_, PyAny>) -> PyResult<Uint<$limbs>> {
+            if let Ok(i) = obj.downcast::<$name>() {
+                return Ok(i.borrow().value);
+            }
+            let value = py_any_to_integer(obj)?;
+            Uint::from_integer(&value, $signed)
+                .ok_or_else(|| PyOverflowError::new_err(format!("{} out of range for {}", value, $py_name)))
+        }
+
+        #[pyclass(name=$py_name, frozen)]
+        #[derive(Clone)]
+        pub struct $name {
+            pub(crate) value: Uint<$limbs>,
+        }
+
+        impl $name {
+            fn checked(result: (Uint<$limbs>, bool), operation: &str) -> PyResult<Self> {
+                match result {
+                    (value, false) => Ok($name { value }),
+                    _ => Err(PyOverflowError::new_err(format!("Overflow occurred during {}", operation))),
+                }
+            }
+
+            fn saturated(result: (Uint<$limbs>, bool), negative: bool) -> Self {
+                match result {
+                    (value, false) => $name { value },
+                    _ if negative => $name { value: Uint::min_value($signed) },
+                    _ => $name { value: Uint::max_value($signed) },
+                }
+            }
+
+            fn shift(other: Uint<$limbs>) -> Option<u32> {
+                if other.is_negative($signed) {
+                    return None;
+                }
+                other.to_integer(false).to_u32().filter(|&shift| shift < Uint::<$limbs>::BITS)
+            }
+
+            fn divisor(other: Uint<$limbs>) -> PyResult<Uint<$limbs>> {
+                if other.is_zero() {
+                    return Err(PyZeroDivisionError::new_err("Division by zero"));
+                }
+                Ok(other)
+            }
+        }
+
+        #[pymethods]
+        impl $name {
+            #[new]
+            #[pyo3(signature = (value=None))]
+            pub fn new(value: Option<&Bound<'_, PyAny>>) -> PyResult<Self> {
+                let value = match value {
+                    None => Uint::ZERO,
+                    Some(value) if value.is_instance_of::<PyString>() => {
+                        let parsed = PyInt::type_object(value.py()).call1((value, 0))?;
+                        py_any_to_wide(&parsed)?
+                    }
+                    Some(value) => py_any_to_wide(value)?,
+                };
+                Ok($name { value })
+            }
+
+            /// Keep the low bits of any integer, wrapping like an `as` cast
+            #[staticmethod]
+            pub fn wrapping_from(#[pyo3(from_py_with = "py_any_to_integer")] value: Integer) -> Self {
+                $name { value: Uint::wrapping_from_integer(&value) }
+            }
+
+            #[getter]
+            pub fn value(&self, py: Python) -> PyResult<PyObject> {
+                integer_to_py(py, &self.value.to_integer($signed))
+            }
+
+            pub fn __repr__(&self) -> String {
+                format!("{}({})", $py_name, self.value.to_integer($signed))
+            }
+
+            pub fn __str__(&self) -> String {
+                self.value.to_integer($signed).to_string()
+            }
+
+            /// Same format specs as Python `int`, e.g. `f"{x:#066x}"`
+            pub fn __format__(&self, py: Python, spec: &str) -> PyResult<String> {
+                self.value(py)?.bind(py).call_method1("__format__", (spec,))?.extract()
+            }
+
+            /// Fixed-width two's-complement hex with `0x` prefix
+            pub fn to_hex(&self) -> String {
+                format!("0x{}", self.value.to_hex())
+            }
+
+            #[staticmethod]
+            pub fn from_hex(s: &str) -> PyResult<Self> {
+                let digits = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")).unwrap_or(s);
+                if digits.is_empty() || digits.len() > 16 * $limbs {
+                    return Err(PyValueError::new_err(format!("Invalid hex literal for {}: {:?}", $py_name, s)));
+                }
+                let value = Integer::from_str_radix(digits, 16)
+                    .map_err(|_| PyValueError::new_err(format!("Invalid hex literal for {}: {:?}", $py_name, s)))?;
+                Ok($name { value: Uint::wrapping_from_integer(&value) })
+            }
+
+            pub fn __hash__(&self) -> isize {
+                integer_hash(&self.value.to_integer($signed))
+            }
+
+            pub fn __bool__(&self) -> bool {
+                !self.value.is_zero()
+            }
+
+            pub fn __int__(&self, py: Python) -> PyResult<PyObject> {
+                self.value(py)
+            }
+
+            pub fn __index__(&self, py: Python) -> PyResult<PyObject> {
+                self.value(py)
+            }
+
+            /// Correctly rounded to the nearest `float`
+            pub fn __float__(&self) -> f64 {
+                rational_to_f64(&Rational::from(self.value.to_integer($signed)))
+            }
+
+            pub fn __richcmp__(&self, py: Python, other: &Bound<'_, PyAny>, op: CompareOp) -> PyResult<PyObject> {
+                let ordering = if let Ok(other) = other.downcast::<$name>() {
+                    self.value.cmp(&other.get().value, $signed)
+                } else if let Ok(other) = py_any_to_integer(other) {
+                    self.value.to_integer($signed).cmp(&other)
+                } else {
+                    return Ok(py.NotImplemented());
+                };
+                op.matches(ordering).into_py_any(py)
+            }
+
+            pub fn __add__(&self, #[pyo3(from_py_with = "py_any_to_wide")] other: Uint<$limbs>) -> PyResult<Self> {
+                Self::checked(self.value.add(other, $signed), "addition")
+            }
+
+            pub fn __radd__(&self, #[pyo3(from_py_with = "py_any_to_wide")] other: Uint<$limbs>) -> PyResult<Self> {
+                Self::checked(other.add(self.value, $signed), "addition")
+            }
+
+            pub fn checked_add(&self, #[pyo3(from_py_with = "py_any_to_wide")] other: Uint<$limbs>) -> PyResult<Self> {
+                Self::checked(self.value.add(other, $signed), "addition")
+            }
+
+            pub fn wrapping_add(&self, #[pyo3(from_py_with = "py_any_to_wide")] other: Uint<$limbs>) -> Self {
+                $name { value: self.value.add(other, $signed).0 }
+            }
+
+            pub fn overflowing_add(&self, #[pyo3(from_py_with = "py_any_to_wide")] other: Uint<$limbs>) -> (Self, bool) {
+                let (value, overflow) = self.value.add(other, $signed);
+                ($name { value }, overflow)
+            }
+
+            pub fn saturating_add(&self, #[pyo3(from_py_with = "py_any_to_wide")] other: Uint<$limbs>) -> Self {
+                Self::saturated(self.value.add(other, $signed), other.is_negative($signed))
+            }
+
+            pub fn __sub__(&self, #[pyo3(from_py_with = "py_any_to_wide")] other: Uint<$limbs>) -> PyResult<Self> {
+                Self::checked(self.value.sub(other, $signed), "subtraction")
+            }
+
+            pub fn __rsub__(&self, #[pyo3(from_py_with = "py_any_to_wide")] other: Uint<$limbs>) -> PyResult<Self> {
+                Self::checked(other.sub(self.value, $signed), "subtraction")
+            }
+
+            pub fn checked_sub(&self, #[pyo3(from_py_with = "py_any_to_wide")] other: Uint<$limbs>) -> PyResult<Self> {
+                Self::checked(self.value.sub(other, $signed), "subtraction")
+            }
+
+            pub fn wrapping_sub(&self, #[pyo3(from_py_with = "py_any_to_wide")] other: Uint<$limbs>) -> Self {
+                $name { value: self.value.sub(other, $signed).0 }
+            }
+
+            pub fn overflowing_sub(&self, #[pyo3(from_py_with = "py_any_to_wide")] other: Uint<$limbs>) -> (Self, bool) {
+                let (value, overflow) = self.value.sub(other, $signed);
+                ($name { value }, overflow)
+            }
+
+            pub fn saturating_sub(&self, #[pyo3(from_py_with = "py_any_to_wide")] other: Uint<$limbs>) -> Self {
+                let negative = !$signed || !other.is_negative($signed);
+                Self::saturated(self.value.sub(other, $signed), negative)
+            }
+
+            pub fn __mul__(&self, #[pyo3(from_py_with = "py_any_to_wide")] other: Uint<$limbs>) -> PyResult<Self> {
+                Self::checked(self.value.mul(other, $signed), "multiplication")
+            }
+
+            pub fn __rmul__(&self, #[pyo3(from_py_with = "py_any_to_wide")] other: Uint<$limbs>) -> PyResult<Self> {
+                self.__mul__(other)
+            }
+
+            pub fn checked_mul(&self, #[pyo3(from_py_with = "py_any_to_wide")] other: Uint<$limbs>) -> PyResult<Self> {
+                Self::checked(self.value.mul(other, $signed), "multiplication")
+            }
+
+            pub fn wrapping_mul(&self, #[pyo3(from_py_with = "py_any_to_wide")] other: Uint<$limbs>) -> Self {
+                $name { value: self.value.mul(other, $signed).0 }
+            }
+
+            pub fn overflowing_mul(&self, #[pyo3(from_py_with = "py_any_to_wide")] other: Uint<$limbs>) -> (Self, bool) {
+                let (value, overflow) = self.value.mul(other, $signed);
+                ($name { value }, overflow)
+            }
+
+            pub fn saturating_mul(&self, #[pyo3(from_py_with = "py_any_to_wide")] other: Uint<$limbs>) -> Self {
+                let negative = self.value.is_negative($signed) != other.is_negative($signed);
+                Self::saturated(self.value.mul(other, $signed), negative)
+            }
+
+            pub fn __pow__(&self, exponent: u32, modulo: Option<&Bound<'_, PyAny>>) -> PyResult<Self> {
+                if modulo.is_some_and(|m| !m.is_none()) {
+                    return Err(PyValueError::new_err(concat!("Modular pow is not supported for ", $py_name)));
+                }
+                Self::checked(self.value.pow(exponent, $signed), "exponentiation")
+            }
+
+            pub fn __rpow__(&self, #[pyo3(from_py_with = "py_any_to_wide")] other: Uint<$limbs>, modulo: Option<&Bound<'_, PyAny>>) -> PyResult<Self> {
+                if self.value.is_negative($signed) {
+                    return Err(PyValueError::new_err("Negative exponent"));
+                }
+                let exponent = self.value.to_integer(false).to_u32()
+                    .ok_or_else(|| PyOverflowError::new_err("Overflow occurred during exponentiation"))?;
+                $name { value: other }.__pow__(exponent, modulo)
+            }
+
+            pub fn checked_pow(&self, exponent: u32) -> PyResult<Self> {
+                Self::checked(self.value.pow(exponent, $signed), "exponentiation")
+            }
+
+            pub fn wrapping_pow(&self, exponent: u32) -> Self {
+                $name { value: self.value.pow(exponent, $signed).0 }
+            }
+
+            pub fn overflowing_pow(&self, exponent: u32) -> (Self, bool) {
+                let (value, overflow) = self.value.pow(exponent, $signed);
+                ($name { value }, overflow)
+            }
+
+            pub fn saturating_pow(&self, exponent: u32) -> Self {
+                let negative = self.value.is_negative($signed) && exponent % 2 == 1;
+                Self::saturated(self.value.pow(exponent, $signed), negative)
+            }
+
+            /// Exact quotient rounded once to the nearest `float`
+            pub fn __truediv__(&self, #[pyo3(from_py_with = "py_any_to_wide")] other: Uint<$limbs>) -> PyResult<f64> {
+                let divisor = Self::divisor(other)?.to_integer($signed);
+                Ok(rational_to_f64(&Rational::from((self.value.to_integer($signed), divisor))))
+            }
+
+            pub fn __rtruediv__(&self, #[pyo3(from_py_with = "py_any_to_wide")] other: Uint<$limbs>) -> PyResult<f64> {
+                $name { value: other }.__truediv__(self.value)
+            }
+
+            pub fn __floordiv__(&self, #[pyo3(from_py_with = "py_any_to_wide")] other: Uint<$limbs>) -> PyResult<Self> {
+                let (quotient, _, overflow) = self.value.div_mod_floor(Self::divisor(other)?, $signed).unwrap();
+                Self::checked((quotient, overflow), "floor division")
+            }
+
+            pub fn __rfloordiv__(&self, #[pyo3(from_py_with = "py_any_to_wide")] other: Uint<$limbs>) -> PyResult<Self> {
+                $name { value: other }.__floordiv__(self.value)
+            }
+
+            pub fn checked_floordiv(&self, #[pyo3(from_py_with = "py_any_to_wide")] other: Uint<$limbs>) -> PyResult<Self> {
+                self.__floordiv__(other)
+            }
+
+            pub fn wrapping_floordiv(&self, #[pyo3(from_py_with = "py_any_to_wide")] other: Uint<$limbs>) -> PyResult<Self> {
+                let (value, _, _) = self.value.div_mod_floor(Self::divisor(other)?, $signed).unwrap();
+                Ok($name { value })
+            }
+
+            pub fn overflowing_floordiv(&self, #[pyo3(from_py_with = "py_any_to_wide")] other: Uint<$limbs>) -> PyResult<(Self, bool)> {
+                let (value, _, overflow) = self.value.div_mod_floor(Self::divisor(other)?, $signed).unwrap();
+                Ok(($name { value }, overflow))
+            }
+
+            pub fn __mod__(&self, #[pyo3(from_py_with = "py_any_to_wide")] other: Uint<$limbs>) -> PyResult<Self> {
+                let (_, value, _) = self.value.div_mod_floor(Self::divisor(other)?, $signed).unwrap();
+                Ok($name { value })
+            }
+
+            pub fn __rmod__(&self, #[pyo3(from_py_with = "py_any_to_wide")] other: Uint<$limbs>) -> PyResult<Self> {
+                $name { value: other }.__mod__(self.value)
+            }
+
+            pub fn __divmod__(&self, #[pyo3(from_py_with = "py_any_to_wide")] other: Uint<$limbs>) -> PyResult<(Self, Self)> {
+                let (quotient, remainder, overflow) = self.value.div_mod_floor(Self::divisor(other)?, $signed).unwrap();
+                Ok((Self::checked((quotient, overflow), "floor division")?, $name { value: remainder }))
+            }
+
+            pub fn __rdivmod__(&self, #[pyo3(from_py_with = "py_any_to_wide")] other: Uint<$limbs>) -> PyResult<(Self, Self)> {
+                $name { value: other }.__divmod__(self.value)
+            }
+
+            pub fn checked_rem(&self, #[pyo3(from_py_with = "py_any_to_wide")] other: Uint<$limbs>) -> PyResult<Self> {
+                let (_, value, overflow) = self.value.div_rem(Self::divisor(other)?, $signed).unwrap();
+                Self::checked((value, overflow), "remainder")
+            }
+
+            pub fn wrapping_rem(&self, #[pyo3(from_py_with = "py_any_to_wide")] other: Uint<$limbs>) -> PyResult<Self> {
+                let (_, value, _) = self.value.div_rem(Self::divisor(other)?, $signed).unwrap();
+                Ok($name { value })
+            }
+
+            pub fn overflowing_rem(&self, #[pyo3(from_py_with = "py_any_to_wide")] other: Uint<$limbs>) -> PyResult<(Self, bool)> {
+                let (_, value, overflow) = self.value.div_rem(Self::divisor(other)?, $signed).unwrap();
+                Ok(($name { value }, overflow))
+            }
+
+            pub fn __neg__(&self) -> PyResult<Self> {
+                Self::checked(self.value.neg($signed), "negation")
+            }
+
+            pub fn checked_neg(&self) -> PyResult<Self> {
+                Self::checked(self.value.neg($signed), "negation")
+            }
+
+            pub fn wrapping_neg(&self) -> Self {
+                $name { value: self.value.wrapping_neg() }
+            }
+
+            pub fn overflowing_neg(&self) -> (Self, bool) {
+                let (value, overflow) = self.value.neg($signed);
+                ($name { value }, overflow)
+            }
+
+            pub fn __pos__(&self) -> Self {
+                self.clone()
+            }
+
+            pub fn __abs__(&self) -> PyResult<Self> {
+                Self::checked(self.value.abs($signed), "absolute value")
+            }
+
+            pub fn __invert__(&self) -> Self {
+                $name { value: self.value.not() }
+            }
+
+            pub fn __and__(&self, #[pyo3(from_py_with = "py_any_to_wide")] other: Uint<$limbs>) -> Self {
+                $name { value: self.value.and(other) }
+            }
+
+            pub fn __rand__(&self, #[pyo3(from_py_with = "py_any_to_wide")] other: Uint<$limbs>) -> Self {
+                self.__and__(other)
+            }
+
+            pub fn __or__(&self, #[pyo3(from_py_with = "py_any_to_wide")] other: Uint<$limbs>) -> Self {
+                $name { value: self.value.or(other) }
+            }
+
+            pub fn __ror__(&self, #[pyo3(from_py_with = "py_any_to_wide")] other: Uint<$limbs>) -> Self {
+                self.__or__(other)
+            }
+
+            pub fn __xor__(&self, #[pyo3(from_py_with = "py_any_to_wide")] other: Uint<$limbs>) -> Self {
+                $name { value: self.value.xor(other) }
+            }
+
+            pub fn __rxor__(&self, #[pyo3(from_py_with = "py_any_to_wide")] other: Uint<$limbs>) -> Self {
+                self.__xor__(other)
+            }
+
+            pub fn __lshift__(&self, #[pyo3(from_py_with = "py_any_to_wide")] other: Uint<$limbs>) -> PyResult<Self> {
+                match Self::shift(other) {
+                    Some(shift) => Ok($name { value: self.value.shl(shift) }),
+                    None => Err(PyOverflowError::new_err("Overflow occurred during left shift")),
+                }
+            }
+
+            pub fn __rlshift__(&self, #[pyo3(from_py_with = "py_any_to_wide")] other: Uint<$limbs>) -> PyResult<Self> {
+                $name { value: other }.__lshift__(self.value)
+            }
+
+            pub fn checked_shl(&self, shift: u32) -> PyResult<Self> {
+                if shift >= Uint::<$limbs>::BITS {
+                    return Err(PyOverflowError::new_err("Overflow occurred during left shift"));
+                }
+                Ok($name { value: self.value.shl(shift) })
+            }
+
+            pub fn wrapping_shl(&self, shift: u32) -> Self {
+                $name { value: self.value.shl(shift % Uint::<$limbs>::BITS) }
+            }
+
+            pub fn overflowing_shl(&self, shift: u32) -> (Self, bool) {
+                (self.wrapping_shl(shift), shift >= Uint::<$limbs>::BITS)
+            }
+
+            pub fn __rshift__(&self, #[pyo3(from_py_with = "py_any_to_wide")] other: Uint<$limbs>) -> PyResult<Self> {
+                match Self::shift(other) {
+                    Some(shift) => Ok($name { value: self.value.shr(shift, $signed) }),
+                    None => Err(PyOverflowError::new_err("Overflow occurred during right shift")),
+                }
+            }
+
+            pub fn __rrshift__(&self, #[pyo3(from_py_with = "py_any_to_wide")] other: Uint<$limbs>) -> PyResult<Self> {
+                $name { value: other }.__rshift__(self.value)
+            }
+
+            pub fn checked_shr(&self, shift: u32) -> PyResult<Self> {
+                if shift >= Uint::<$limbs>::BITS {
+                    return Err(PyOverflowError::new_err("Overflow occurred during right shift"));
+                }
+                Ok($name { value: self.value.shr(shift, $signed) })
+            }
+
+            pub fn wrapping_shr(&self, shift: u32) -> Self {
+                $name { value: self.value.shr(shift % Uint::<$limbs>::BITS, $signed) }
+            }
+
+            pub fn overflowing_shr(&self, shift: u32) -> (Self, bool) {
+                (self.wrapping_shr(shift), shift >= Uint::<$limbs>::BITS)
+            }
+
+            pub fn count_ones(&self) -> u32 {
+                self.value.count_ones()
+            }
+
+            pub fn count_zeros(&self) -> u32 {
+                Uint::<$limbs>::BITS - self.value.count_ones()
+            }
+
+            pub fn leading_zeros(&self) -> u32 {
+                self.value.leading_zeros()
+            }
+
+            pub fn trailing_zeros(&self) -> u32 {
+                self.value.trailing_zeros()
+            }
+
+            pub fn bit_length(&self) -> u32 {
+                self.value.bit_length($signed)
+            }
+
+            pub fn rotate_left(&self, n: u32) -> Self {
+                $name { value: self.value.rotate_left(n) }
+            }
+
+            pub fn rotate_right(&self, n: u32) -> Self {
+                $name { value: self.value.rotate_right(n) }
+            }
+
+            pub fn swap_bytes(&self) -> Self {
+                $name { value: self.value.swap_bytes() }
+            }
+
+            pub fn to_be_bytes<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+                PyBytes::new(py, &self.value.to_be_bytes())
+            }
+
+            pub fn to_le_bytes<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+                PyBytes::new(py, &self.value.to_le_bytes())
+            }
+
+            #[staticmethod]
+            pub fn from_be_bytes(bytes: &[u8]) -> PyResult<Self> {
+                Uint::from_be_bytes(bytes)
+                    .map(|value| $name { value })
+                    .ok_or_else(|| PyValueError::new_err(format!("{} requires exactly {} bytes", $py_name, 8 * $limbs)))
+            }
+
+            #[staticmethod]
+            pub fn from_le_bytes(bytes: &[u8]) -> PyResult<Self> {
+                Uint::from_le_bytes(bytes)
+                    .map(|value| $name { value })
+                    .ok_or_else(|| PyValueError::new_err(format!("{} requires exactly {} bytes", $py_name, 8 * $limbs)))
+            }
+
+            /// Like `int.to_bytes`, always `8 * limbs` bytes long
+            #[pyo3(signature = (byteorder="big"))]
+            pub fn to_bytes<'py>(&self, py: Python<'py>, byteorder: &str) -> PyResult<Bound<'py, PyBytes>> {
+                match byteorder {
+                    "big" => Ok(self.to_be_bytes(py)),
+                    "little" => Ok(self.to_le_bytes(py)),
+                    _ => Err(PyValueError::new_err("byteorder must be either 'little' or 'big'")),
+                }
+            }
+
+            #[staticmethod]
+            #[pyo3(signature = (bytes, byteorder="big"))]
+            pub fn from_bytes(bytes: &[u8], byteorder: &str) -> PyResult<Self> {
+                match byteorder {
+                    "big" => Self::from_be_bytes(bytes),
+                    "little" => Self::from_le_bytes(bytes),
+                    _ => Err(PyValueError::new_err("byteorder must be either 'little' or 'big'")),
+                }
+            }
+
+            #[staticmethod]
+            pub fn zero() -> Self {
+                $name { value: Uint::ZERO }
+            }
+
+            #[staticmethod]
+            pub fn one() -> Self {
+                $name { value: Uint::one() }
+            }
+
+            #[staticmethod]
+            pub fn min_value() -> Self {
+                $name { value: Uint::min_value($signed) }
+            }
+
+            #[staticmethod]
+            pub fn max_value() -> Self {
+                $name { value: Uint::max_value($signed) }
+            }
+
+            pub fn is_positive(&self) -> bool {
+                !self.value.is_zero() && !self.value.is_negative($signed)
+            }
+
+            pub fn is_negative(&self) -> bool {
+                self.value.is_negative($signed)
+            }
+        }
+    };
+}
+
+pub(crate) use wide_int;